@@ -0,0 +1,155 @@
+//! # Custom Validation Rules
+//!
+//! User-supplied JSONLogic business rules evaluated against the flattened JSON form of a
+//! message, so institutions can add bespoke checks without touching Rust.
+//!
+//! A rule set is either a JSON array of rules or an object with a `rules` array:
+//!
+//! ```json
+//! {"rules": [{
+//!     "code": "X01",
+//!     "field": "32A",
+//!     "description": "Settlement currency must be USD",
+//!     "logic": {"==": [{"var": "fields.32A.currency"}, "USD"]}
+//! }]}
+//! ```
+//!
+//! Paths follow [`SerializationMode::Flat`](crate::SerializationMode::Flat), e.g.
+//! `fields.50K.name_and_address` or `basic_header.sender_bic`. A rule passes when its
+//! logic evaluates to a truthy value.
+
+use crate::{
+    ParseError, SwiftValidationError, ValidationReport, swift_message::SwiftMessage,
+    traits::SwiftMessageBody,
+};
+use datalogic_rs::DataLogic;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// A single user-supplied business rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomRule {
+    /// Error code reported on violation (e.g. "X01")
+    pub code: String,
+    /// Field the rule applies to, used in the reported error
+    #[serde(default)]
+    pub field: String,
+    /// Human-readable description, used as the error message
+    #[serde(default)]
+    pub description: String,
+    /// JSONLogic expression that must evaluate truthy
+    pub logic: Value,
+}
+
+/// Parse a rule set from a JSON array or an object with a `rules` array
+pub fn parse_rules(rules: Value) -> Result<Vec<CustomRule>, ParseError> {
+    let rules = match rules {
+        Value::Object(mut object) => object.remove("rules").unwrap_or(Value::Null),
+        other => other,
+    };
+    serde_json::from_value(rules).map_err(|e| ParseError::InvalidFormat {
+        message: format!("Invalid custom rule set: {}", e),
+    })
+}
+
+/// JSONLogic truthiness: false, null, 0, "" and [] are falsy
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+impl<T: SwiftMessageBody> SwiftMessage<T> {
+    /// Run custom rules loaded from a JSON file against the message
+    pub fn validate_with_rules_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<ValidationReport, ParseError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| ParseError::IoError {
+            message: format!("Cannot read rule file {}: {}", path.display(), e),
+        })?;
+        let rules = serde_json::from_str(&content).map_err(|e| ParseError::InvalidFormat {
+            message: format!("Rule file {} is not valid JSON: {}", path.display(), e),
+        })?;
+        self.validate_with_rules_value(rules)
+    }
+
+    /// Run custom rules given as JSON against the message
+    ///
+    /// Each violated rule is reported as a business error with the rule's code.
+    /// Malformed rules fail the whole call rather than being skipped.
+    pub fn validate_with_rules_value(&self, rules: Value) -> Result<ValidationReport, ParseError> {
+        let rules = parse_rules(rules)?;
+        let data = serde_json::to_value(self).map_err(|e| ParseError::SerializationError {
+            message: e.to_string(),
+        })?;
+
+        let engine = DataLogic::new();
+        let mut report = ValidationReport::new(T::message_type());
+        for rule in rules {
+            let compiled = engine
+                .compile(&rule.logic)
+                .map_err(|e| ParseError::InvalidFormat {
+                    message: format!("Custom rule {} has invalid logic: {}", rule.code, e),
+                })?;
+            let message = match engine.evaluate_owned(&compiled, data.clone()) {
+                Ok(result) if is_truthy(&result) => continue,
+                Ok(_) => rule.description.clone(),
+                Err(e) => format!("{} (evaluation failed: {})", rule.description, e),
+            };
+            report.errors.push(SwiftValidationError::business_error(
+                &rule.code,
+                &rule.field,
+                vec![],
+                &message,
+                &rule.logic.to_string(),
+            ));
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MT103, SwiftParser};
+    use serde_json::json;
+
+    const MT103_MESSAGE: &str = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315EUR1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+
+    #[test]
+    fn test_custom_rule_currency_is_usd() {
+        let message = SwiftParser::parse::<MT103>(MT103_MESSAGE).unwrap();
+        let rules = json!({"rules": [{
+            "code": "X01",
+            "field": "32A",
+            "description": "Settlement currency must be USD",
+            "logic": {"==": [{"var": "fields.32A.currency"}, "USD"]}
+        }]});
+
+        let report = message.validate_with_rules_value(rules.clone()).unwrap();
+        assert_eq!(report.message_type, "103");
+        assert_eq!(report.codes(), vec!["X01"]);
+
+        let usd = SwiftParser::parse::<MT103>(&MT103_MESSAGE.replace("EUR", "USD")).unwrap();
+        assert!(usd.validate_with_rules_value(rules).unwrap().is_valid());
+
+        assert!(
+            message
+                .validate_with_rules_value(json!({"rules": 1}))
+                .is_err()
+        );
+        assert!(
+            message
+                .validate_with_rules_file("/nonexistent/rules.json")
+                .is_err()
+        );
+    }
+}
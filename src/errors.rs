@@ -25,6 +25,8 @@
 use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for the library
@@ -75,6 +77,85 @@ pub struct ParserConfig {
     pub validate_optional_fields: bool,
     /// If true, collect all errors even for non-critical issues (default: true)
     pub collect_all_errors: bool,
+    /// If true, tolerate whitespace between blocks and lowercase header blocks (default: false)
+    #[serde(default)]
+    pub lenient_block_format: bool,
+    /// If true, lossy fields (amounts, rates) keep their original text for exact re-serialization (default: false)
+    #[serde(default)]
+    pub retain_raw_fields: bool,
+    /// Two-digit years below this pivot map to 20YY, the rest to 19YY (default: 50)
+    #[serde(default = "default_date_pivot_year")]
+    pub date_pivot_year: u8,
+    /// If true, trailing spaces padding fixed-length components (e.g. a `3!a` currency) are
+    /// trimmed before validation instead of being rejected (default: false)
+    #[serde(default)]
+    pub trim_fixed_fields: bool,
+    /// If true, `parse_auto` returns `ParsedSwiftMessage::Generic` for unsupported message types
+    /// instead of an error (default: false)
+    #[serde(default)]
+    pub allow_generic: bool,
+    /// Maximum bytes `SwiftStreamParser` buffers for an incomplete message (default: 1 MiB)
+    #[serde(default = "default_max_stream_buffer")]
+    pub max_stream_buffer: usize,
+    /// If true, block 1 may be 21-28 characters long, with the logical terminal padded or
+    /// truncated to 12 characters; otherwise exactly 25 are required (default: false)
+    #[serde(default)]
+    pub lenient_block1_length: bool,
+    /// If true, non-ASCII characters in block 4 are transliterated into the SWIFT character
+    /// set (see `sanitize_swift_text`) before field parsing instead of being rejected (default: false)
+    #[serde(default)]
+    pub sanitize_text: bool,
+    /// Maximum field 61 statement lines accepted in one MT940, MT942 or MT950 (default: 10000)
+    #[serde(default = "default_max_statement_lines")]
+    pub max_statement_lines: usize,
+    /// How a missing mandatory field is handled (default: `Error`)
+    #[serde(default)]
+    pub missing_mandatory_policy: MissingMandatoryPolicy,
+    /// If true, `'` and space thousands separators are removed from the integer part of
+    /// amounts (`1'234,56`) instead of being rejected (default: false)
+    #[serde(default)]
+    pub strip_thousands_separators: bool,
+    /// Hook notified after each parsed message (default: none; see [`ParserConfig::with_metrics`])
+    #[serde(skip)]
+    pub metrics: Option<Arc<dyn ParseMetrics>>,
+}
+
+/// Receiver of per-message parse measurements, e.g. to feed Prometheus counters
+///
+/// Called by `SwiftParser::parse_message`, `parse_with_errors` and `parse_message_auto`.
+/// Without a hook the parser does not take timings at all.
+pub trait ParseMetrics: Send + Sync + fmt::Debug {
+    /// A message of `message_type` with `field_count` block 4 fields parsed in `duration`
+    fn record_parse(&self, message_type: &str, field_count: usize, duration: Duration);
+
+    /// Parsing failed after `duration` (default: ignored)
+    fn record_failure(&self, _duration: Duration) {}
+}
+
+/// Handling of mandatory fields absent from block 4
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MissingMandatoryPolicy {
+    /// Abort parsing with `ParseError::MissingRequiredField`
+    #[default]
+    Error,
+    /// Parse the field empty (see `SwiftField::empty`) and report the omission as a
+    /// non-critical error in the `ParseResult::PartialSuccess` of `parse_with_errors`
+    ///
+    /// Fields that cannot be empty still abort parsing, and so does any omission in entry
+    /// points that return a plain `Result` (`parse_message`, `parse_message_auto`, ...).
+    Warn,
+}
+
+fn default_date_pivot_year() -> u8 {
+    crate::fields::swift_utils::DEFAULT_DATE_PIVOT_YEAR
+}
+
+fn default_max_statement_lines() -> usize {
+    crate::fields::swift_utils::DEFAULT_MAX_STATEMENT_LINES
+}
+
+fn default_max_stream_buffer() -> usize {
+    1024 * 1024
 }
 
 impl Default for ParserConfig {
@@ -83,10 +164,30 @@ impl Default for ParserConfig {
             fail_fast: false,
             validate_optional_fields: true,
             collect_all_errors: true,
+            lenient_block_format: false,
+            retain_raw_fields: false,
+            date_pivot_year: default_date_pivot_year(),
+            trim_fixed_fields: false,
+            allow_generic: false,
+            max_stream_buffer: default_max_stream_buffer(),
+            lenient_block1_length: false,
+            sanitize_text: false,
+            max_statement_lines: default_max_statement_lines(),
+            missing_mandatory_policy: MissingMandatoryPolicy::Error,
+            strip_thousands_separators: false,
+            metrics: None,
         }
     }
 }
 
+impl ParserConfig {
+    /// Register a [`ParseMetrics`] hook
+    pub fn with_metrics(mut self, metrics: Arc<dyn ParseMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+}
+
 impl Display for ParseErrorCollection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         writeln!(f, "Found {} parsing errors:", self.errors.len())?;
@@ -114,6 +215,9 @@ pub struct InvalidFieldFormatError {
     pub position: Option<usize>,
     /// Inner parsing error (simplified for serialization)
     pub inner_error: String,
+    /// Official SWIFT error code of the failure, when known (e.g., "T50" for a bad date)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
 }
 
 /// Main error type for parsing operations
@@ -197,6 +301,12 @@ pub enum ParseError {
         block: String,
         /// Detailed error message
         message: String,
+        /// Machine-readable cause
+        #[serde(default)]
+        reason: BlockErrorReason,
+        /// Byte offset in the raw message where parsing broke, when known
+        #[serde(default)]
+        offset: Option<usize>,
     },
 
     /// Multiple parsing errors collected during message parsing
@@ -204,6 +314,20 @@ pub enum ParseError {
     MultipleErrors(Vec<ParseError>),
 }
 
+/// Cause of a [`ParseError::InvalidBlockStructure`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockErrorReason {
+    /// Content between blocks where a `{` was expected
+    MissingOpenBrace,
+    /// Block not closed (`}`, or `-}` for block 4)
+    MissingTerminator,
+    /// Block identifier other than 1-5
+    UnexpectedBlockId,
+    /// The block is delimited correctly but its content is malformed
+    #[default]
+    InvalidContent,
+}
+
 /// Validation error for field-level validation
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
 pub enum ValidationError {
@@ -360,6 +484,15 @@ impl From<std::io::Error> for ParseError {
 }
 
 impl ParseError {
+    /// Official SWIFT error code carried by the error, if any
+    pub fn swift_code(&self) -> Option<&str> {
+        match self {
+            ParseError::SwiftValidation(err) => Some(err.code()),
+            ParseError::InvalidFieldFormat(err) => err.code.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Get a detailed debug report for the error
     pub fn debug_report(&self) -> String {
         match self {
@@ -442,7 +575,7 @@ impl ParseError {
                      └─ Hint: Check the field value matches the expected type"
                 )
             }
-            ParseError::InvalidBlockStructure { block, message } => {
+            ParseError::InvalidBlockStructure { block, message, .. } => {
                 format!(
                     "Block Structure Error:\n\
                      ├─ Block: {block}\n\
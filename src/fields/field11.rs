@@ -116,16 +116,21 @@ impl SwiftField for Field11R {
 
         let mut result = format!(":11R:{}{}", self.message_type, date_str);
 
+        // Session number (4!n) and ISN (6!n) are zero-padded to their fixed length
         if let Some(ref session) = self.session_number {
-            result.push_str(session);
+            result.push_str(&format!("{:0>4}", session));
         }
 
         if let Some(ref seq) = self.input_sequence_number {
-            result.push_str(seq);
+            result.push_str(&format!("{:0>6}", seq));
         }
 
         result
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.date]
+    }
 }
 
 /// **Field 11S: MT Reference (Option S)**
@@ -261,16 +266,21 @@ impl SwiftField for Field11S {
 
         let mut result = format!(":11S:{}{}", self.message_type, date_str);
 
+        // Session number (4!n) and ISN (6!n) are zero-padded to their fixed length
         if let Some(ref session) = self.session_number {
-            result.push_str(session);
+            result.push_str(&format!("{:0>4}", session));
         }
 
         if let Some(ref seq) = self.input_sequence_number {
-            result.push_str(seq);
+            result.push_str(&format!("{:0>6}", seq));
         }
 
         result
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.date]
+    }
 }
 
 #[cfg(test)]
@@ -354,6 +364,14 @@ mod tests {
         };
         assert_eq!(field.to_swift_string(), ":11S:1922407191234567890");
 
+        let field = Field11S {
+            message_type: "192".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
+            session_number: Some("123".to_string()),
+            input_sequence_number: Some("42".to_string()),
+        };
+        assert_eq!(field.to_swift_string(), ":11S:1922407190123000042");
+
         let field = Field11S {
             message_type: "292".to_string(),
             date: NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
@@ -439,6 +457,10 @@ impl SwiftField for Field11 {
         );
         format!(":11:{}{}", self.message_type, date_str)
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.date]
+    }
 }
 
 #[cfg(test)]
@@ -304,6 +304,10 @@ impl SwiftField for Field13D {
             self.offset
         )
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.date]
+    }
 }
 
 #[cfg(test)]
@@ -361,6 +365,13 @@ mod tests {
         assert_eq!(field.time.format("%H%M").to_string(), "0000");
         assert_eq!(field.offset_sign, '-');
         assert_eq!(field.offset, "0800");
+
+        // JSON dates keep the 80 century cut-over: 79 -> 2079, 80 -> 1980
+        let json = |date: &str| serde_json::json!({"date": date, "time": "1230", "offset_sign": "+", "offset": "0100"});
+        let field: Field13D = serde_json::from_value(json("791231")).unwrap();
+        assert_eq!(chrono::Datelike::year(&field.date), 2079);
+        let field: Field13D = serde_json::from_value(json("800101")).unwrap();
+        assert_eq!(chrono::Datelike::year(&field.date), 1980);
     }
 
     #[test]
@@ -2,7 +2,9 @@
 //!
 //! Sum of all individual transaction amounts in sequence transactions for reconciliation and validation.
 
-use super::swift_utils::parse_amount;
+use super::Field32B;
+use super::swift_utils::{Amount, format_swift_amount, parse_amount};
+use crate::errors::SwiftValidationError;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
 
@@ -24,27 +26,104 @@ pub struct Field19 {
     pub amount: f64,
 }
 
+impl Field19 {
+    /// Compute the sum of a set of field 32B amounts
+    ///
+    /// Fails with C02 if the amounts are not all in the same currency.
+    pub fn sum_of<'a>(
+        amounts: impl IntoIterator<Item = &'a Field32B>,
+    ) -> Result<Self, SwiftValidationError> {
+        let mut total: Option<Amount> = None;
+
+        for field_32b in amounts {
+            let amount = field_32b.to_amount().and_then(|amount| match &total {
+                Some(sum) => sum.checked_add(&amount),
+                None => Ok(amount),
+            });
+            total = Some(amount.map_err(|e| {
+                SwiftValidationError::content_error(
+                    "C02",
+                    "32B",
+                    &field_32b.currency,
+                    &format!("Cannot compute field 19: {}", e),
+                    "The currency code in field 32B must be the same for all occurrences",
+                )
+            })?);
+        }
+
+        Ok(Field19 {
+            amount: total.map_or(0.0, |sum| sum.to_f64()),
+        })
+    }
+
+    /// Sum of amounts as an [`Amount`] in the given currency, rounded to its decimals
+    pub fn to_amount(&self, currency: &str) -> crate::Result<Amount> {
+        Amount::new(currency, self.amount)
+    }
+}
+
+/// Check that field 19 equals the sum of the transaction amounts (Error code: C01)
+///
+/// Shared by the batch messages (MT104, MT107, MT204). The sum is computed exactly and
+/// compared in minor units of the transactions' currency. Mixed currencies are not
+/// compared; the messages report them with their own currency rule (C02).
+pub fn validate_field_19_tieout<'a>(
+    field_19: &Field19,
+    amounts: impl IntoIterator<Item = &'a Field32B>,
+) -> Option<SwiftValidationError> {
+    let amounts: Vec<&Field32B> = amounts.into_iter().collect();
+    let currency = amounts.first()?.currency.as_str();
+    let sum = Field19::sum_of(amounts.iter().copied()).ok()?;
+
+    if let (Ok(stated), Ok(sum)) = (field_19.to_amount(currency), sum.to_amount(currency))
+        && stated == sum
+    {
+        return None;
+    }
+
+    Some(SwiftValidationError::relation_error(
+        "C01",
+        "19",
+        vec!["32B".to_string()],
+        &format!(
+            "Field 19 amount ({}) must equal the sum of the field 32B amounts ({})",
+            format_swift_amount(field_19.amount, 2),
+            format_swift_amount(sum.amount, 2)
+        ),
+        "The amount in field 19 must equal the sum of the amounts in all occurrences of field 32B",
+    ))
+}
+
 impl SwiftField for Field19 {
     fn parse(input: &str) -> crate::Result<Self>
     where
         Self: Sized,
     {
+        if input.len() > 17 {
+            return Err(crate::errors::ParseError::SwiftValidation(Box::new(
+                SwiftValidationError::format_error(
+                    "T40",
+                    "19",
+                    input,
+                    "17d",
+                    &format!(
+                        "Field 19 allows at most 17 characters including the decimal comma, found {}",
+                        input.len()
+                    ),
+                ),
+            )));
+        }
+
         let amount = parse_amount(input)?;
 
         Ok(Field19 { amount })
     }
 
     fn to_swift_string(&self) -> String {
-        format!(":19:{}", format_swift_amount(self.amount))
+        format!(":19:{}", format_swift_amount(self.amount, 2))
     }
 }
 
-/// Format amount for SWIFT output with comma as decimal separator
-fn format_swift_amount(amount: f64) -> String {
-    let formatted = format!("{:.2}", amount);
-    formatted.replace('.', ",")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,9 +149,48 @@ mod tests {
         assert_eq!(field.to_swift_string(), ":19:1000,00");
     }
 
+    #[test]
+    fn test_field19_sum_of() {
+        let amounts = [
+            Field32B::parse("EUR0,10").unwrap(),
+            Field32B::parse("EUR0,20").unwrap(),
+        ];
+        assert_eq!(Field19::sum_of(&amounts).unwrap().amount, 0.3);
+
+        let mixed = [
+            Field32B::parse("EUR100,").unwrap(),
+            Field32B::parse("USD100,").unwrap(),
+        ];
+        assert_eq!(Field19::sum_of(&mixed).unwrap_err().code(), "C02");
+    }
+
     #[test]
     fn test_field19_parse_invalid() {
         assert!(Field19::parse("abc").is_err());
         assert!(Field19::parse("").is_err());
     }
+
+    #[test]
+    fn test_field19_tieout() {
+        let amounts = [
+            Field32B::parse("EUR1000,50").unwrap(),
+            Field32B::parse("EUR2500,05").unwrap(),
+        ];
+        let field = Field19::parse("3500,55").unwrap();
+        assert!(validate_field_19_tieout(&field, &amounts).is_none());
+
+        let field = Field19::parse("3500,56").unwrap();
+        let error = validate_field_19_tieout(&field, &amounts).unwrap();
+        assert_eq!(error.code(), "C01");
+        assert!(matches!(error, SwiftValidationError::Relation(_)));
+
+        let mixed = [
+            Field32B::parse("EUR100,").unwrap(),
+            Field32B::parse("USD100,").unwrap(),
+        ];
+        assert!(validate_field_19_tieout(&field, &mixed).is_none());
+
+        assert!(Field19::parse("12345678901234567,").is_err());
+        assert!(Field19::parse("1234567890123456,").is_ok());
+    }
 }
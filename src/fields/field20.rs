@@ -1,4 +1,4 @@
-use super::swift_utils::{parse_max_length, parse_swift_chars};
+use super::swift_utils::{parse_max_length, parse_x_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
@@ -31,7 +31,7 @@ impl SwiftField for Field20 {
         let reference = parse_max_length(input, 16, "Field 20 reference")?;
 
         // Validate SWIFT character set
-        parse_swift_chars(&reference, "Field 20 reference")?;
+        parse_x_chars(&reference, "Field 20 reference")?;
 
         // Additional validation: no leading/trailing slashes
         if reference.starts_with('/') || reference.ends_with('/') {
@@ -53,6 +53,12 @@ impl SwiftField for Field20 {
     fn to_swift_string(&self) -> String {
         format!(":20:{}", self.reference)
     }
+
+    fn empty() -> Option<Self> {
+        Some(Field20 {
+            reference: String::new(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
-use super::swift_utils::{parse_max_length, parse_swift_chars};
-use crate::errors::ParseError;
+use super::swift_utils::{parse_max_length, parse_x_chars};
+use crate::errors::{ParseError, SwiftValidationError};
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// **Format:** `16x` (max 16 chars)
 /// **Constraints:** No leading/trailing slashes, no consecutive slashes
+/// **Special value:** `NONREF` when the related message carries no reference
 ///
 /// **Example:**
 /// ```text
@@ -21,6 +22,38 @@ pub struct Field21NoOption {
     pub reference: String,
 }
 
+impl Field21NoOption {
+    /// Value used when there is no related reference
+    pub const NONREF: &'static str = "NONREF";
+
+    /// Field 21 stating that there is no related reference
+    pub fn nonref() -> Self {
+        Self {
+            reference: Self::NONREF.to_string(),
+        }
+    }
+
+    /// Check if the field holds `NONREF` rather than an actual reference
+    pub fn is_nonref(&self) -> bool {
+        self.reference == Self::NONREF
+    }
+
+    /// T14 error when a mandatory field 21 is empty (e.g. built from JSON)
+    pub(crate) fn validate_present(&self) -> Option<SwiftValidationError> {
+        if !self.reference.trim().is_empty() {
+            return None;
+        }
+
+        Some(SwiftValidationError::content_error(
+            "T14",
+            "21",
+            "",
+            "Field 21 (Related Reference) is mandatory",
+            "Field 21 must carry the related reference, or NONREF when there is none",
+        ))
+    }
+}
+
 impl SwiftField for Field21NoOption {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -30,7 +63,7 @@ impl SwiftField for Field21NoOption {
         let reference = parse_max_length(input, 16, "Field 21 reference")?;
 
         // Validate SWIFT character set
-        parse_swift_chars(&reference, "Field 21 reference")?;
+        parse_x_chars(&reference, "Field 21 reference")?;
 
         // Additional validation: no leading/trailing slashes
         if reference.starts_with('/') || reference.ends_with('/') {
@@ -52,6 +85,12 @@ impl SwiftField for Field21NoOption {
     fn to_swift_string(&self) -> String {
         format!(":21:{}", self.reference)
     }
+
+    fn empty() -> Option<Self> {
+        Some(Field21NoOption {
+            reference: String::new(),
+        })
+    }
 }
 
 /// **Field 21C: Customer-Specific Reference**
@@ -72,7 +111,7 @@ impl SwiftField for Field21C {
         Self: Sized,
     {
         let reference = parse_max_length(input, 35, "Field 21C reference")?;
-        parse_swift_chars(&reference, "Field 21C reference")?;
+        parse_x_chars(&reference, "Field 21C reference")?;
 
         if reference.starts_with('/') || reference.ends_with('/') {
             return Err(ParseError::InvalidFormat {
@@ -112,7 +151,7 @@ impl SwiftField for Field21D {
         Self: Sized,
     {
         let reference = parse_max_length(input, 35, "Field 21D reference")?;
-        parse_swift_chars(&reference, "Field 21D reference")?;
+        parse_x_chars(&reference, "Field 21D reference")?;
 
         if reference.starts_with('/') || reference.ends_with('/') {
             return Err(ParseError::InvalidFormat {
@@ -152,7 +191,7 @@ impl SwiftField for Field21E {
         Self: Sized,
     {
         let reference = parse_max_length(input, 35, "Field 21E reference")?;
-        parse_swift_chars(&reference, "Field 21E reference")?;
+        parse_x_chars(&reference, "Field 21E reference")?;
 
         if reference.starts_with('/') || reference.ends_with('/') {
             return Err(ParseError::InvalidFormat {
@@ -192,7 +231,7 @@ impl SwiftField for Field21F {
         Self: Sized,
     {
         let reference = parse_max_length(input, 16, "Field 21F reference")?;
-        parse_swift_chars(&reference, "Field 21F reference")?;
+        parse_x_chars(&reference, "Field 21F reference")?;
 
         if reference.starts_with('/') || reference.ends_with('/') {
             return Err(ParseError::InvalidFormat {
@@ -232,7 +271,7 @@ impl SwiftField for Field21R {
         Self: Sized,
     {
         let reference = parse_max_length(input, 16, "Field 21R reference")?;
-        parse_swift_chars(&reference, "Field 21R reference")?;
+        parse_x_chars(&reference, "Field 21R reference")?;
 
         if reference.starts_with('/') || reference.ends_with('/') {
             return Err(ParseError::InvalidFormat {
@@ -258,6 +297,24 @@ impl SwiftField for Field21R {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_field21_nonref() {
+        let field = Field21NoOption::parse("NONREF").unwrap();
+        assert!(field.is_nonref());
+        assert_eq!(field, Field21NoOption::nonref());
+        assert!(field.validate_present().is_none());
+
+        let field = Field21NoOption::parse("REF/2024/001").unwrap();
+        assert!(!field.is_nonref());
+
+        assert!(Field21NoOption::parse("/REF").is_err());
+
+        let empty = Field21NoOption {
+            reference: String::new(),
+        };
+        assert_eq!(empty.validate_present().unwrap().code(), "T14");
+    }
+
     #[test]
     fn test_field21_no_option() {
         let field = Field21NoOption::parse("REF20240719001").unwrap();
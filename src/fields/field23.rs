@@ -122,6 +122,91 @@ impl SwiftField for Field23 {
     }
 }
 
+/// **Field 23: Further Identification (MT935)**
+///
+/// Identifies the rate being changed in an MT935 rate change sequence.
+///
+/// **Format:** `3!a[2!n]11x` (currency + optional number of days + function)
+/// **Function codes:** BASE, CALL, COMMERCIAL, CURRENT, DEPOSIT, NOTICE, PRIME
+/// **Number of days:** Only with NOTICE (1-99)
+///
+/// **Example:**
+/// ```text
+/// :23:USDBASE
+/// :23:EUR07NOTICE
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field23CurrencyFunction {
+    /// Currency code (ISO 4217)
+    pub currency: String,
+    /// Number of days (1-99, only for NOTICE)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    /// Function (max 11 chars, e.g., BASE, NOTICE)
+    pub function: String,
+}
+
+impl SwiftField for Field23CurrencyFunction {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        if input.len() < 4 || !input.is_ascii() {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Field 23 must be a currency followed by a function, found '{}'",
+                    input
+                ),
+            });
+        }
+
+        let currency = parse_exact_length(&input[0..3], 3, "Field 23 currency")?;
+        parse_uppercase(&currency, "Field 23 currency")?;
+
+        // Number of days is present when the two characters after the currency are digits
+        let rest = &input[3..];
+        let (days, function) = match rest.get(..2) {
+            Some(digits) if digits.chars().all(|c| c.is_ascii_digit()) => {
+                let days_value = digits.parse::<u32>().unwrap_or_default();
+                if days_value == 0 {
+                    return Err(ParseError::InvalidFormat {
+                        message: "Field 23 number of days must be between 1 and 99, found 0"
+                            .to_string(),
+                    });
+                }
+                (Some(days_value), &rest[2..])
+            }
+            _ => (None, rest),
+        };
+
+        if function.is_empty() || function.len() > 11 {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Field 23 function must be 1 to 11 characters, found '{}'",
+                    function
+                ),
+            });
+        }
+        parse_swift_chars(function, "Field 23 function")?;
+
+        Ok(Field23CurrencyFunction {
+            currency,
+            days,
+            function: function.to_string(),
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        let mut result = format!(":23:{}", self.currency);
+        if let Some(days) = self.days {
+            result.push_str(&format!("{:02}", days));
+        }
+        result.push_str(&self.function);
+        result
+    }
+}
+
 /// **Field 23B: Bank Operation Code**
 ///
 /// Service level and processing type for payment instructions.
@@ -175,6 +260,12 @@ impl SwiftField for Field23B {
     fn to_swift_string(&self) -> String {
         format!(":23B:{}", self.instruction_code)
     }
+
+    fn empty() -> Option<Self> {
+        Some(Field23B {
+            instruction_code: String::new(),
+        })
+    }
 }
 
 /// **Field 23E: Instruction Code**
@@ -284,6 +375,26 @@ mod tests {
         assert_eq!(field.to_swift_string(), ":23:NOT15REF123");
     }
 
+    #[test]
+    fn test_field23_currency_function() {
+        let field = Field23CurrencyFunction::parse("EUR07NOTICE").unwrap();
+        assert_eq!(field.currency, "EUR");
+        assert_eq!(field.days, Some(7));
+        assert_eq!(field.function, "NOTICE");
+        assert_eq!(field.to_swift_string(), ":23:EUR07NOTICE");
+
+        let field = Field23CurrencyFunction::parse("USDBASE").unwrap();
+        assert_eq!(field.currency, "USD");
+        assert_eq!(field.days, None);
+        assert_eq!(field.function, "BASE");
+        assert_eq!(field.to_swift_string(), ":23:USDBASE");
+
+        assert!(Field23CurrencyFunction::parse("EUR").is_err());
+        assert!(Field23CurrencyFunction::parse("eurBASE").is_err());
+        assert!(Field23CurrencyFunction::parse("EUR00NOTICE").is_err());
+        assert!(Field23CurrencyFunction::parse("EURCOMMERCIAL12").is_err());
+    }
+
     #[test]
     fn test_field23b() {
         // Test valid codes
@@ -222,6 +222,13 @@ impl SwiftField for Field25AccountIdentification {
             Field25AccountIdentification::P(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field25AccountIdentification::NoOption(_) => None,
+            Field25AccountIdentification::P(_) => Some("P"),
+        }
+    }
 }
 
 // Type alias for backward compatibility
@@ -1,4 +1,4 @@
-use super::swift_utils::parse_date_yymmdd;
+use super::swift_utils::{parse_date_yymmdd, parse_date_yyyymmdd};
 use crate::traits::SwiftField;
 use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
@@ -40,6 +40,106 @@ impl SwiftField for Field30 {
             self.execution_date.day()
         )
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.execution_date]
+    }
+}
+
+/// **Field 30T: Trade Date**
+///
+/// Date the deal was agreed (MT3xx).
+///
+/// **Format:** `8!n` (YYYYMMDD)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field30T {
+    /// Trade date (YYYYMMDD)
+    #[cfg_attr(feature = "jsonschema", schemars(with = "String"))]
+    pub trade_date: NaiveDate,
+}
+
+impl SwiftField for Field30T {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Field30T {
+            trade_date: parse_date_yyyymmdd(input)?,
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        format!(":30T:{}", self.trade_date.format("%Y%m%d"))
+    }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.trade_date]
+    }
+}
+
+/// **Field 30V: Value Date**
+///
+/// Date on which the deal or payment becomes effective.
+///
+/// **Format:** `8!n` (YYYYMMDD)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field30V {
+    /// Value date (YYYYMMDD)
+    #[cfg_attr(feature = "jsonschema", schemars(with = "String"))]
+    pub value_date: NaiveDate,
+}
+
+impl SwiftField for Field30V {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Field30V {
+            value_date: parse_date_yyyymmdd(input)?,
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        format!(":30V:{}", self.value_date.format("%Y%m%d"))
+    }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
+}
+
+/// **Field 30P: Maturity Date**
+///
+/// Date on which a loan/deposit or other term deal matures (MT3xx).
+///
+/// **Format:** `8!n` (YYYYMMDD)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field30P {
+    /// Maturity date (YYYYMMDD)
+    #[cfg_attr(feature = "jsonschema", schemars(with = "String"))]
+    pub maturity_date: NaiveDate,
+}
+
+impl SwiftField for Field30P {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Field30P {
+            maturity_date: parse_date_yyyymmdd(input)?,
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        format!(":30P:{}", self.maturity_date.format("%Y%m%d"))
+    }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.maturity_date]
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +189,27 @@ mod tests {
         // Non-numeric
         assert!(Field30::parse("24071a").is_err());
     }
+
+    #[test]
+    fn test_field30_date_variants() {
+        let field = Field30T::parse("20240315").unwrap();
+        assert_eq!(
+            field.trade_date,
+            NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()
+        );
+        assert_eq!(field.to_swift_string(), ":30T:20240315");
+        assert!(Field30T::parse("240315").is_err());
+
+        let field = Field30V::parse("20240319").unwrap();
+        assert_eq!(
+            field.value_date,
+            NaiveDate::from_ymd_opt(2024, 3, 19).unwrap()
+        );
+        assert_eq!(field.to_swift_string(), ":30V:20240319");
+        assert!(Field30V::parse("240319").is_err());
+
+        let field = Field30P::parse("20250915").unwrap();
+        assert_eq!(field.to_swift_string(), ":30P:20250915");
+        assert!(Field30P::parse("20250231").is_err());
+    }
 }
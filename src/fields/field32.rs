@@ -7,6 +7,7 @@
 //! - **32B:** Currency + Amount (3!a + 15d)
 //! - **32C:** Date + Currency + Credit Amount (MT n90 messages)
 //! - **32D:** Date + Currency + Debit Amount (MT n90 messages)
+//! - **32H:** Sign + Currency + Amount (MT3xx settlement amounts)
 //!
 //! **Example:**
 //! ```text
@@ -15,8 +16,9 @@
 //! ```
 
 use super::swift_utils::{
-    format_swift_amount_for_currency, parse_amount_with_currency, parse_currency_non_commodity,
-    parse_date_yymmdd,
+    RetainedRaw, format_signed_currency_amount, format_swift_amount_for_currency,
+    parse_amount_with_currency, parse_currency_non_commodity, parse_date_yymmdd,
+    parse_signed_currency_amount, retained_raw,
 };
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -27,7 +29,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Settlement information with value date.
 /// Format: `6!n3!a15d` (YYMMDD + currency + amount)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Field32A {
     /// Value date (YYMMDD)
@@ -36,8 +38,29 @@ pub struct Field32A {
     pub value_date: NaiveDate,
     /// ISO 4217 currency code
     pub currency: String,
-    /// Settlement amount
+    /// Settlement amount (always positive, `15d` carries no sign)
     pub amount: f64,
+    /// Original field text, kept when `ParserConfig::retain_raw_fields` is set
+    #[doc(hidden)]
+    #[serde(skip)]
+    #[cfg_attr(feature = "jsonschema", schemars(skip))]
+    pub _raw: Option<RetainedRaw<(NaiveDate, String, f64)>>,
+}
+
+/// Compares the typed values only; retained raw text is ignored
+impl PartialEq for Field32A {
+    fn eq(&self, other: &Self) -> bool {
+        self.value_date == other.value_date
+            && self.currency == other.currency
+            && self.amount == other.amount
+    }
+}
+
+impl Field32A {
+    /// Currency and amount as an [`Amount`](super::swift_utils::Amount), rounded to the currency's decimals
+    pub fn to_amount(&self) -> crate::Result<super::swift_utils::Amount> {
+        super::swift_utils::Amount::new(&self.currency, self.amount)
+    }
 }
 
 impl SwiftField for Field32A {
@@ -46,40 +69,10 @@ impl SwiftField for Field32A {
         Self: Sized,
     {
         // Field32A format: 6!n3!a15d (date + currency + amount)
-        if input.len() < 10 {
-            // Minimum: 6 digits date + 3 chars currency + 1 digit amount
-            return Err(ParseError::InvalidFormat {
-                message: format!(
-                    "Field 32A must be at least 10 characters, found {}",
-                    input.len()
-                ),
-            });
-        }
-
-        // Parse value date (first 6 characters) - T50 validation
-        let value_date = parse_date_yymmdd(&input[0..6])?;
-
-        // Parse currency code (next 3 characters) - T52 + C08 validation
-        let currency = parse_currency_non_commodity(&input[6..9])?;
-
-        // Parse amount (remaining characters) - T40/T43 + C03 validation
-        let amount_str = &input[9..];
-        if amount_str.is_empty() {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32A amount cannot be empty".to_string(),
-            });
-        }
-
-        let amount = parse_amount_with_currency(amount_str, &currency)?;
-
-        // Amount must be positive
-        if amount <= 0.0 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32A amount must be greater than zero".to_string(),
-            });
-        }
+        let (value_date, currency, amount) = parse_dated_currency_amount(input, "32A")?;
 
         Ok(Field32A {
+            _raw: retained_raw(input, (value_date, currency.clone(), amount)),
             value_date,
             currency,
             amount,
@@ -87,26 +80,57 @@ impl SwiftField for Field32A {
     }
 
     fn to_swift_string(&self) -> String {
+        if let Some(raw) = self.raw() {
+            return format!(":32A:{}", raw);
+        }
         format!(
-            ":32A:{}{}{}",
+            ":32A:{}{}",
             self.value_date.format("%y%m%d"),
-            self.currency,
-            format_swift_amount_for_currency(self.amount, &self.currency)
+            format_currency_amount(&self.currency, self.amount)
         )
     }
+
+    fn raw(&self) -> Option<&str> {
+        self._raw
+            .as_ref()
+            .and_then(|raw| raw.get(&(self.value_date, self.currency.clone(), self.amount)))
+    }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
 }
 
 /// **Field 32B: Currency, Amount**
 ///
 /// Currency and amount without value date.
 /// Format: `3!a15d` (currency + amount)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Field32B {
     /// ISO 4217 currency code
     pub currency: String,
-    /// Amount
+    /// Amount (always positive, `15d` carries no sign)
     pub amount: f64,
+    /// Original field text, kept when `ParserConfig::retain_raw_fields` is set
+    #[doc(hidden)]
+    #[serde(skip)]
+    #[cfg_attr(feature = "jsonschema", schemars(skip))]
+    pub _raw: Option<RetainedRaw<(String, f64)>>,
+}
+
+/// Compares the typed values only; retained raw text is ignored
+impl PartialEq for Field32B {
+    fn eq(&self, other: &Self) -> bool {
+        self.currency == other.currency && self.amount == other.amount
+    }
+}
+
+impl Field32B {
+    /// Currency and amount as an [`Amount`](super::swift_utils::Amount), rounded to the currency's decimals
+    pub fn to_amount(&self) -> crate::Result<super::swift_utils::Amount> {
+        super::swift_utils::Amount::new(&self.currency, self.amount)
+    }
 }
 
 impl SwiftField for Field32B {
@@ -115,46 +139,30 @@ impl SwiftField for Field32B {
         Self: Sized,
     {
         // Field32B format: 3!a15d (currency + amount)
-        if input.len() < 4 {
-            // Minimum: 3 chars currency + 1 digit amount
-            return Err(ParseError::InvalidFormat {
-                message: format!(
-                    "Field 32B must be at least 4 characters, found {}",
-                    input.len()
-                ),
-            });
-        }
+        let (currency, amount) = parse_currency_amount(input, "32B")?;
 
-        // Parse currency code (first 3 characters) - T52 + C08 validation
-        let currency = parse_currency_non_commodity(&input[0..3])?;
-
-        // Parse amount (remaining characters) - T40/T43 + C03 validation
-        let amount_str = &input[3..];
-        if amount_str.is_empty() {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32B amount cannot be empty".to_string(),
-            });
-        }
-
-        let amount = parse_amount_with_currency(amount_str, &currency)?;
-
-        // Amount must be positive
-        if amount <= 0.0 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32B amount must be greater than zero".to_string(),
-            });
-        }
-
-        Ok(Field32B { currency, amount })
+        Ok(Field32B {
+            _raw: retained_raw(input, (currency.clone(), amount)),
+            currency,
+            amount,
+        })
     }
 
     fn to_swift_string(&self) -> String {
+        if let Some(raw) = self.raw() {
+            return format!(":32B:{}", raw);
+        }
         format!(
-            ":32B:{}{}",
-            self.currency,
-            format_swift_amount_for_currency(self.amount, &self.currency)
+            ":32B:{}",
+            format_currency_amount(&self.currency, self.amount)
         )
     }
+
+    fn raw(&self) -> Option<&str> {
+        self._raw
+            .as_ref()
+            .and_then(|raw| raw.get(&(self.currency.clone(), self.amount)))
+    }
 }
 
 /// **Field 32C: Value Date, Currency, Credit Amount**
@@ -201,32 +209,7 @@ impl SwiftField for Field32C {
         Self: Sized,
     {
         // Same format as Field32A
-        if input.len() < 10 {
-            return Err(ParseError::InvalidFormat {
-                message: format!(
-                    "Field 32C must be at least 10 characters, found {}",
-                    input.len()
-                ),
-            });
-        }
-
-        let value_date = parse_date_yymmdd(&input[0..6])?;
-        let currency = parse_currency_non_commodity(&input[6..9])?;
-        let amount_str = &input[9..];
-
-        if amount_str.is_empty() {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32C amount cannot be empty".to_string(),
-            });
-        }
-
-        let amount = parse_amount_with_currency(amount_str, &currency)?;
-
-        if amount <= 0.0 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32C amount must be greater than zero".to_string(),
-            });
-        }
+        let (value_date, currency, amount) = parse_dated_currency_amount(input, "32C")?;
 
         Ok(Field32C {
             value_date,
@@ -237,12 +220,15 @@ impl SwiftField for Field32C {
 
     fn to_swift_string(&self) -> String {
         format!(
-            ":32C:{}{}{}",
+            ":32C:{}{}",
             self.value_date.format("%y%m%d"),
-            self.currency,
-            format_swift_amount_for_currency(self.amount, &self.currency)
+            format_currency_amount(&self.currency, self.amount)
         )
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
 }
 
 /// **Field 32D: Value Date, Currency, Debit Amount**
@@ -268,35 +254,91 @@ impl SwiftField for Field32D {
         Self: Sized,
     {
         // Same format as Field32A
-        if input.len() < 10 {
-            return Err(ParseError::InvalidFormat {
-                message: format!(
-                    "Field 32D must be at least 10 characters, found {}",
-                    input.len()
-                ),
-            });
-        }
+        let (value_date, currency, amount) = parse_dated_currency_amount(input, "32D")?;
 
-        let value_date = parse_date_yymmdd(&input[0..6])?;
-        let currency = parse_currency_non_commodity(&input[6..9])?;
-        let amount_str = &input[9..];
+        Ok(Field32D {
+            value_date,
+            currency,
+            amount,
+        })
+    }
 
-        if amount_str.is_empty() {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32D amount cannot be empty".to_string(),
-            });
-        }
+    fn to_swift_string(&self) -> String {
+        format!(
+            ":32D:{}{}",
+            self.value_date.format("%y%m%d"),
+            format_currency_amount(&self.currency, self.amount)
+        )
+    }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
+}
+
+/// Parse the `3!a15d` currency and amount shared by all field 32 options
+///
+/// Validates the currency (T52, C08), the amount and its precision (T40, T43, C03),
+/// and that the amount is positive.
+fn parse_currency_amount(input: &str, tag: &str) -> crate::Result<(String, f64)> {
+    if input.len() < 4 {
+        // Minimum: 3 chars currency + 1 digit amount
+        return Err(ParseError::InvalidFormat {
+            message: format!(
+                "Field {} must be at least 4 characters, found {}",
+                tag,
+                input.len()
+            ),
+        });
+    }
+
+    let currency = parse_currency_non_commodity(&input[0..3])?;
+    let amount = parse_amount_with_currency(&input[3..], &currency)?;
+
+    if amount <= 0.0 {
+        return Err(ParseError::InvalidFormat {
+            message: format!("Field {} amount must be greater than zero", tag),
+        });
+    }
 
-        let amount = parse_amount_with_currency(amount_str, &currency)?;
+    Ok((currency, amount))
+}
+
+/// **Field 32H: Amount to be Settled**
+///
+/// Signed settlement amount in treasury messages (MT3xx).
+/// Format: `[N]3!a15d` (optional `N` for a negative amount + currency + amount)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field32H {
+    /// Whether the `N` sign is present, i.e. the amount is negative
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub negative: bool,
+    /// ISO 4217 currency code
+    pub currency: String,
+    /// Unsigned amount, the sign is carried by `negative`
+    pub amount: f64,
+}
 
-        if amount <= 0.0 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 32D amount must be greater than zero".to_string(),
-            });
+impl Field32H {
+    /// Amount with the `N` sign applied
+    pub fn signed_amount(&self) -> f64 {
+        if self.negative {
+            -self.amount
+        } else {
+            self.amount
         }
+    }
+}
 
-        Ok(Field32D {
-            value_date,
+impl SwiftField for Field32H {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let (negative, currency, amount) = parse_signed_currency_amount(input, "Field 32H")?;
+        Ok(Field32H {
+            negative,
             currency,
             amount,
         })
@@ -304,14 +346,41 @@ impl SwiftField for Field32D {
 
     fn to_swift_string(&self) -> String {
         format!(
-            ":32D:{}{}{}",
-            self.value_date.format("%y%m%d"),
-            self.currency,
-            format_swift_amount_for_currency(self.amount, &self.currency)
+            ":32H:{}",
+            format_signed_currency_amount(self.negative, &self.currency, self.amount)
         )
     }
 }
 
+/// Parse the `6!n3!a15d` value date, currency and amount of options A, C and D
+fn parse_dated_currency_amount(input: &str, tag: &str) -> crate::Result<(NaiveDate, String, f64)> {
+    if input.len() < 10 {
+        // Minimum: 6 digits date + 3 chars currency + 1 digit amount
+        return Err(ParseError::InvalidFormat {
+            message: format!(
+                "Field {} must be at least 10 characters, found {}",
+                tag,
+                input.len()
+            ),
+        });
+    }
+
+    // T50 validation on the date, the rest as for option B
+    let value_date = parse_date_yymmdd(&input[0..6])?;
+    let (currency, amount) = parse_currency_amount(&input[6..], tag)?;
+
+    Ok((value_date, currency, amount))
+}
+
+/// Format currency and amount with currency-specific decimal places
+fn format_currency_amount(currency: &str, amount: f64) -> String {
+    format!(
+        "{}{}",
+        currency,
+        format_swift_amount_for_currency(amount, currency)
+    )
+}
+
 /// **Field 32: Settlement Amount Variants**
 ///
 /// Enum wrapper for Field 32 variants (A/B/C/D).
@@ -361,6 +430,23 @@ impl SwiftField for Field32 {
         }
     }
 
+    fn parse_with_variant(
+        value: &str,
+        variant: Option<&str>,
+        _field_tag: Option<&str>,
+    ) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        match variant {
+            Some("A") => Ok(Field32::A(Field32A::parse(value)?)),
+            Some("B") => Ok(Field32::B(Field32B::parse(value)?)),
+            Some("C") => Ok(Field32::C(Field32C::parse(value)?)),
+            Some("D") => Ok(Field32::D(Field32D::parse(value)?)),
+            _ => Self::parse(value),
+        }
+    }
+
     fn to_swift_string(&self) -> String {
         match self {
             Field32::A(field) => field.to_swift_string(),
@@ -369,6 +455,24 @@ impl SwiftField for Field32 {
             Field32::D(field) => field.to_swift_string(),
         }
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        match self {
+            Field32::A(field) => field.dates(),
+            Field32::B(field) => field.dates(),
+            Field32::C(field) => field.dates(),
+            Field32::D(field) => field.dates(),
+        }
+    }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field32::A(_) => Some("A"),
+            Field32::B(_) => Some("B"),
+            Field32::C(_) => Some("C"),
+            Field32::D(_) => Some("D"),
+        }
+    }
 }
 
 /// **Field32AB: Options A or B only**
@@ -384,6 +488,16 @@ pub enum Field32AB {
     B(Field32B),
 }
 
+impl Field32AB {
+    /// Currency code of whichever option is present
+    pub fn currency(&self) -> &str {
+        match self {
+            Field32AB::A(f) => &f.currency,
+            Field32AB::B(f) => &f.currency,
+        }
+    }
+}
+
 impl SwiftField for Field32AB {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -404,12 +518,41 @@ impl SwiftField for Field32AB {
         })
     }
 
+    fn parse_with_variant(
+        value: &str,
+        variant: Option<&str>,
+        _field_tag: Option<&str>,
+    ) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        match variant {
+            Some("A") => Ok(Field32AB::A(Field32A::parse(value)?)),
+            Some("B") => Ok(Field32AB::B(Field32B::parse(value)?)),
+            _ => Self::parse(value),
+        }
+    }
+
     fn to_swift_string(&self) -> String {
         match self {
             Field32AB::A(field) => field.to_swift_string(),
             Field32AB::B(field) => field.to_swift_string(),
         }
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        match self {
+            Field32AB::A(field) => field.dates(),
+            Field32AB::B(field) => field.dates(),
+        }
+    }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field32AB::A(_) => Some("A"),
+            Field32AB::B(_) => Some("B"),
+        }
+    }
 }
 
 /// **Field32AmountCD: Credit or Debit**
@@ -425,6 +568,16 @@ pub enum Field32AmountCD {
     D(Field32D),
 }
 
+impl Field32AmountCD {
+    /// Currency code of whichever option is present
+    pub fn currency(&self) -> &str {
+        match self {
+            Field32AmountCD::C(f) => &f.currency,
+            Field32AmountCD::D(f) => &f.currency,
+        }
+    }
+}
+
 impl SwiftField for Field32AmountCD {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -477,6 +630,20 @@ impl SwiftField for Field32AmountCD {
             Field32AmountCD::D(field) => field.to_swift_string(),
         }
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        match self {
+            Field32AmountCD::C(field) => field.dates(),
+            Field32AmountCD::D(field) => field.dates(),
+        }
+    }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field32AmountCD::C(_) => Some("C"),
+            Field32AmountCD::D(_) => Some("D"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -511,6 +678,11 @@ mod tests {
 
         // Invalid currency
         assert!(Field32A::parse("240719EU1100").is_err());
+
+        // Negative literal amount
+        let err = Field32A::parse("240719EUR-100,00").unwrap_err();
+        assert_eq!(err.swift_code(), Some("T40"));
+        assert!(Field32B::parse("EUR-100,00").is_err());
         assert!(Field32A::parse("2407191UR100").is_err());
 
         // Zero amount
@@ -535,6 +707,23 @@ mod tests {
         assert_eq!(field.amount, 100.0);
     }
 
+    #[test]
+    fn test_field32b_round_trip_has_no_date() {
+        let field = Field32B::parse("USD1000,00").unwrap();
+        assert_eq!(field.currency, "USD");
+        assert_eq!(field.amount, 1000.0);
+        assert_eq!(field.to_swift_string(), ":32B:USD1000,00");
+
+        // Option letter decides the variant, so 32B stays dateless and 32A requires a date
+        let field = Field32AB::parse_with_variant("USD1000,00", Some("B"), Some("32")).unwrap();
+        assert_eq!(field.to_swift_string(), ":32B:USD1000,00");
+        assert!(Field32AB::parse_with_variant("USD1000,00", Some("A"), Some("32")).is_err());
+
+        let field = Field32::parse_with_variant("USD1000,00", Some("B"), Some("32")).unwrap();
+        assert_eq!(field.to_swift_string(), ":32B:USD1000,00");
+        assert!(Field32B::parse("240719USD1000,00").is_err());
+    }
+
     #[test]
     fn test_field32b_invalid() {
         // Invalid currency
@@ -620,6 +809,7 @@ mod tests {
             value_date: NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
             currency: "EUR".to_string(),
             amount: 500.25,
+            _raw: None,
         });
         assert_eq!(field_a.to_swift_string(), ":32A:240719EUR500,25");
 
@@ -627,6 +817,7 @@ mod tests {
         let field_b = Field32AB::B(Field32B {
             currency: "USD".to_string(),
             amount: 1000.00,
+            _raw: None,
         });
         assert_eq!(field_b.to_swift_string(), ":32B:USD1000,00");
     }
@@ -715,6 +906,7 @@ mod tests {
             value_date: NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
             currency: "USD".to_string(),
             amount: 1000.50,
+            _raw: None,
         };
         assert_eq!(field_usd.to_swift_string(), ":32A:240719USD1000,50");
 
@@ -722,6 +914,7 @@ mod tests {
             value_date: NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
             currency: "JPY".to_string(),
             amount: 1500000.0,
+            _raw: None,
         };
         assert_eq!(field_jpy.to_swift_string(), ":32A:240719JPY1500000");
 
@@ -729,7 +922,34 @@ mod tests {
             value_date: NaiveDate::from_ymd_opt(2024, 7, 19).unwrap(),
             currency: "BHD".to_string(),
             amount: 123.456,
+            _raw: None,
         };
         assert_eq!(field_bhd.to_swift_string(), ":32A:240719BHD123,456");
     }
+
+    #[test]
+    fn test_field32h_sign_round_trip() {
+        let field = Field32H::parse("NEUR1250,5").unwrap();
+        assert!(field.negative);
+        assert_eq!(field.signed_amount(), -1250.5);
+        assert_eq!(field.to_swift_string(), ":32H:NEUR1250,50");
+
+        let field = Field32H::parse("JPY0").unwrap();
+        assert!(!field.negative);
+        assert_eq!(field.to_swift_string(), ":32H:JPY0");
+
+        // A leading N of a currency code is not a sign
+        let field = Field32H::parse("NOK1000,").unwrap();
+        assert!(!field.negative);
+        assert_eq!(field.currency, "NOK");
+        assert_eq!(field.to_swift_string(), ":32H:NOK1000,00");
+
+        let field = Field32H::parse("NNOK1000,").unwrap();
+        assert!(field.negative);
+        assert_eq!(field.currency, "NOK");
+
+        assert!(Field32H::parse("-EUR100,").is_err());
+        assert!(Field32H::parse("NEUR-100,").is_err());
+        assert!(Field32H::parse("N").is_err());
+    }
 }
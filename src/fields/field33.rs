@@ -1,5 +1,6 @@
 use super::swift_utils::{
-    format_swift_amount_for_currency, parse_amount_with_currency, parse_currency_non_commodity,
+    RetainedRaw, format_swift_amount_for_currency, parse_amount_with_currency,
+    parse_currency_non_commodity, retained_raw,
 };
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -18,13 +19,32 @@ use serde::{Deserialize, Serialize};
 /// :33B:USD1250,00
 /// :33B:JPY125000
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Field33B {
     /// ISO 4217 currency code (e.g., USD, EUR, GBP)
     pub currency: String,
     /// Original instructed amount (precision follows currency rules)
     pub amount: f64,
+    /// Original field text, kept when `ParserConfig::retain_raw_fields` is set
+    #[doc(hidden)]
+    #[serde(skip)]
+    #[cfg_attr(feature = "jsonschema", schemars(skip))]
+    pub _raw: Option<RetainedRaw<(String, f64)>>,
+}
+
+/// Compares the typed values only; retained raw text is ignored
+impl PartialEq for Field33B {
+    fn eq(&self, other: &Self) -> bool {
+        self.currency == other.currency && self.amount == other.amount
+    }
+}
+
+impl Field33B {
+    /// Currency and amount as an [`Amount`](super::swift_utils::Amount), rounded to the currency's decimals
+    pub fn to_amount(&self) -> crate::Result<super::swift_utils::Amount> {
+        super::swift_utils::Amount::new(&self.currency, self.amount)
+    }
 }
 
 impl SwiftField for Field33B {
@@ -63,16 +83,29 @@ impl SwiftField for Field33B {
             });
         }
 
-        Ok(Field33B { currency, amount })
+        Ok(Field33B {
+            _raw: retained_raw(input, (currency.clone(), amount)),
+            currency,
+            amount,
+        })
     }
 
     fn to_swift_string(&self) -> String {
+        if let Some(raw) = self.raw() {
+            return format!(":33B:{}", raw);
+        }
         format!(
             ":33B:{}{}",
             self.currency,
             format_swift_amount_for_currency(self.amount, &self.currency)
         )
     }
+
+    fn raw(&self) -> Option<&str> {
+        self._raw
+            .as_ref()
+            .and_then(|raw| raw.get(&(self.currency.clone(), self.amount)))
+    }
 }
 
 #[cfg(test)]
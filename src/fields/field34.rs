@@ -1,4 +1,6 @@
-use super::swift_utils::{parse_amount, parse_currency};
+use super::swift_utils::{
+    format_signed_currency_amount, parse_amount, parse_currency, parse_signed_currency_amount,
+};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
@@ -94,10 +96,94 @@ impl SwiftField for Field34F {
     }
 }
 
+/// **Field 34E: Currency and Interest Amount**
+///
+/// Signed interest amount in treasury messages (MT3xx).
+///
+/// **Format:** `[N]3!a15d` (optional `N` for a negative amount + currency + amount)
+///
+/// **Example:**
+/// ```text
+/// :34E:EUR1250,00
+/// :34E:NEUR1250,00
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field34E {
+    /// Whether the `N` sign is present, i.e. the amount is negative
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub negative: bool,
+
+    /// ISO 4217 currency code
+    pub currency: String,
+
+    /// Unsigned interest amount, the sign is carried by `negative`
+    pub amount: f64,
+}
+
+impl Field34E {
+    /// Amount with the `N` sign applied
+    pub fn signed_amount(&self) -> f64 {
+        if self.negative {
+            -self.amount
+        } else {
+            self.amount
+        }
+    }
+}
+
+impl SwiftField for Field34E {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let (negative, currency, amount) = parse_signed_currency_amount(input, "Field 34E")?;
+        Ok(Field34E {
+            negative,
+            currency,
+            amount,
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        format!(
+            ":34E:{}",
+            format_signed_currency_amount(self.negative, &self.currency, self.amount)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_field34e_signed_and_unsigned() {
+        let field = Field34E::parse("EUR1250,").unwrap();
+        assert!(!field.negative);
+        assert_eq!(field.currency, "EUR");
+        assert_eq!(field.signed_amount(), 1250.0);
+        assert_eq!(field.to_swift_string(), ":34E:EUR1250,00");
+
+        let field = Field34E::parse("NEUR1250,75").unwrap();
+        assert!(field.negative);
+        assert_eq!(field.signed_amount(), -1250.75);
+        assert_eq!(field.to_swift_string(), ":34E:NEUR1250,75");
+        assert_eq!(
+            Field34E::parse(&field.to_swift_string()[5..]).unwrap(),
+            field
+        );
+
+        let field = Field34E::parse("NOK500,").unwrap();
+        assert!(!field.negative);
+        assert_eq!(field.currency, "NOK");
+        assert_eq!(field.signed_amount(), 500.0);
+
+        assert!(Field34E::parse("XEUR100,").is_err());
+        assert!(Field34E::parse("EUR-100,").is_err());
+        assert!(Field34E::parse("NJPY100,5").is_err());
+    }
+
     #[test]
     fn test_field34f_valid() {
         // Without indicator
@@ -1,4 +1,4 @@
-use super::swift_utils::parse_amount;
+use super::swift_utils::{RetainedRaw, parse_amount, retained_raw};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
@@ -8,18 +8,30 @@ use serde::{Deserialize, Serialize};
 /// Specifies the exchange rate used to convert instructed currency to settlement currency.
 /// Used when Field 33B currency differs from Field 32A currency.
 ///
-/// **Format:** `12d` (decimal rate with comma separator)
+/// **Format:** `12d` (decimal rate with mandatory comma separator, max 12 characters)
 /// **Constraints:** Positive rate, within reasonable market range (0.0001 to 100000)
 ///
 /// **Example:**
 /// ```text
 /// :36:1,2500
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Field36 {
     /// Exchange rate (from Field 33B currency to Field 32A currency)
     pub rate: f64,
+    /// Original field text, kept when `ParserConfig::retain_raw_fields` is set
+    #[doc(hidden)]
+    #[serde(skip)]
+    #[cfg_attr(feature = "jsonschema", schemars(skip))]
+    pub _raw: Option<RetainedRaw<f64>>,
+}
+
+/// Compares the rate only; retained raw text is ignored
+impl PartialEq for Field36 {
+    fn eq(&self, other: &Self) -> bool {
+        self.rate == other.rate
+    }
 }
 
 impl SwiftField for Field36 {
@@ -43,6 +55,27 @@ impl SwiftField for Field36 {
             });
         }
 
+        // Rate must be digits with exactly one decimal comma and a leading digit
+        let (integer_part, _) = input
+            .split_once(',')
+            .ok_or_else(|| ParseError::InvalidFormat {
+                message: format!(
+                    "Field 36 exchange rate must use a decimal comma, found '{}'",
+                    input
+                ),
+            })?;
+        if integer_part.is_empty()
+            || input.matches(',').count() > 1
+            || !input.chars().all(|c| c.is_ascii_digit() || c == ',')
+        {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Field 36 exchange rate must be digits with one decimal comma, found '{}'",
+                    input
+                ),
+            });
+        }
+
         let rate = parse_amount(input)?;
 
         // Rate must be positive
@@ -63,12 +96,48 @@ impl SwiftField for Field36 {
             });
         }
 
-        Ok(Field36 { rate })
+        Ok(Field36 {
+            rate,
+            _raw: retained_raw(input, rate),
+        })
     }
 
     fn to_swift_string(&self) -> String {
-        // Format with comma as decimal separator
-        format!(":36:{}", self.rate.to_string().replace('.', ","))
+        if let Some(raw) = self.raw() {
+            return format!(":36:{}", raw);
+        }
+        format!(":36:{}", format_rate(self.rate))
+    }
+
+    fn raw(&self) -> Option<&str> {
+        self._raw.as_ref().and_then(|raw| raw.get(&self.rate))
+    }
+}
+
+/// Format a rate as `12d`: decimal comma, at least one fractional digit where it fits, max 12 characters
+fn format_rate(rate: f64) -> String {
+    let plain = rate.to_string();
+    let mut integer_len = plain.split('.').next().unwrap_or_default().len();
+
+    loop {
+        // Keep as many fractional digits as fit in 12 characters (integer + comma + fraction)
+        let max_fraction = 11usize.saturating_sub(integer_len);
+        let rounded = format!("{:.*}", max_fraction, rate);
+        let (integer_part, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+
+        // A rounding carry (9,99.. -> 10,0) lengthens the integer part; retry with fewer digits
+        if integer_part.len() > integer_len {
+            integer_len = integer_part.len();
+            continue;
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        let fraction = if fraction.is_empty() && integer_part.len() < 11 {
+            "0"
+        } else {
+            fraction
+        };
+        return format!("{},{}", integer_part, fraction);
     }
 }
 
@@ -88,14 +157,15 @@ mod tests {
         let field = Field36::parse("110,2500").unwrap();
         assert_eq!(field.rate, 110.25);
 
-        let field = Field36::parse("1").unwrap();
+        let field = Field36::parse("1,").unwrap();
         assert_eq!(field.rate, 1.0);
+        assert_eq!(field.to_swift_string(), ":36:1,0");
 
         // Edge cases within reasonable range
         let field = Field36::parse("0,0001").unwrap();
         assert_eq!(field.rate, 0.0001);
 
-        let field = Field36::parse("99999").unwrap();
+        let field = Field36::parse("99999,").unwrap();
         assert_eq!(field.rate, 99999.0);
     }
 
@@ -118,6 +188,53 @@ mod tests {
         assert!(Field36::parse("0,00001").is_err());
 
         // Unreasonably large rate
-        assert!(Field36::parse("999999").is_err());
+        assert!(Field36::parse("999999,").is_err());
+    }
+
+    #[test]
+    fn test_field36_format() {
+        // Dot separator and missing comma are rejected
+        assert!(Field36::parse("1.5").is_err());
+        assert!(Field36::parse("15").is_err());
+        assert!(Field36::parse(",5").is_err());
+        assert!(Field36::parse("1,2,5").is_err());
+
+        // Over-length rate (13 characters)
+        assert!(Field36::parse("1,23456789012").is_err());
+        assert!(Field36::parse("1,2345678901").is_ok());
+    }
+
+    #[test]
+    fn test_field36_to_swift_string_fits_12d() {
+        let field = Field36 {
+            rate: 1.234567890123456,
+            _raw: None,
+        };
+        assert_eq!(field.to_swift_string(), ":36:1,2345678901");
+
+        let field = Field36 {
+            rate: 0.0001,
+            _raw: None,
+        };
+        assert_eq!(field.to_swift_string(), ":36:0,0001");
+
+        let field = Field36 {
+            rate: 1234.5,
+            _raw: None,
+        };
+        assert_eq!(field.to_swift_string(), ":36:1234,5");
+
+        // Rounding carries into the integer part must not push the rate past 12 characters
+        let field = Field36 {
+            rate: 9.999999999999,
+            _raw: None,
+        };
+        assert_eq!(field.to_swift_string(), ":36:10,0");
+
+        let field = Field36 {
+            rate: 9999999999.96,
+            _raw: None,
+        };
+        assert_eq!(field.to_swift_string(), ":36:10000000000,");
     }
 }
@@ -20,12 +20,13 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Field37H {
     /// Rate type indicator: 'C' (Credit) or 'D' (Debit)
-    pub rate_indicator: char,
+    pub indicator: char,
 
-    /// Negative rate indicator (Some(true) if rate is negative)
-    pub is_negative: Option<bool>,
+    /// Sign: 'N' when the rate is negative
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign: Option<char>,
 
-    /// Interest rate value (e.g., 2.5000 = 2.5%)
+    /// Interest rate value, negative when signed (e.g., -0.25 = -0.25%)
     pub rate: f64,
 }
 
@@ -34,58 +35,64 @@ impl SwiftField for Field37H {
     where
         Self: Sized,
     {
-        let mut remaining = input;
-
         // Parse rate indicator (1!a)
-        if remaining.is_empty() {
+        let mut chars = input.chars();
+        let indicator = chars.next().ok_or_else(|| ParseError::InvalidFormat {
+            message: "Field 37H requires rate indicator".to_string(),
+        })?;
+        if indicator != 'C' && indicator != 'D' {
             return Err(ParseError::InvalidFormat {
-                message: "Field37H requires rate indicator".to_string(),
+                message: format!(
+                    "Field 37H rate indicator must be 'C' or 'D', found '{}'",
+                    indicator
+                ),
             });
         }
-
-        let rate_indicator = remaining.chars().next().unwrap();
-        if rate_indicator != 'C' && rate_indicator != 'D' {
+        let mut remaining = chars.as_str();
+
+        // Parse optional sign ([1!a])
+        let sign = remaining.strip_prefix('N').map(|rest| {
+            remaining = rest;
+            'N'
+        });
+
+        // Parse rate value (12d): digits with exactly one decimal comma and a leading digit
+        if remaining.is_empty()
+            || remaining.len() > 12
+            || remaining.starts_with(',')
+            || remaining.matches(',').count() != 1
+            || !remaining.chars().all(|c| c.is_ascii_digit() || c == ',')
+        {
             return Err(ParseError::InvalidFormat {
-                message: "Field37H rate indicator must be 'C' or 'D'".to_string(),
+                message: format!(
+                    "Field 37H rate must be up to 12 digits with one decimal comma, found '{}'",
+                    remaining
+                ),
             });
         }
-        remaining = &remaining[1..];
 
-        // Parse optional negative indicator ([1!a])
-        let is_negative = if remaining.starts_with('N') {
-            remaining = &remaining[1..];
-            Some(true)
-        } else {
-            None
-        };
-
-        // Parse rate value (12d)
-        if remaining.is_empty() {
+        let value = parse_amount(remaining)?;
+        if sign.is_some() && value == 0.0 {
             return Err(ParseError::InvalidFormat {
-                message: "Field37H requires rate value".to_string(),
+                message: "Field 37H sign must not be used when the rate is zero".to_string(),
             });
         }
 
-        let rate = if is_negative.is_some() {
-            -parse_amount(remaining)?
-        } else {
-            parse_amount(remaining)?
-        };
-
         Ok(Field37H {
-            rate_indicator,
-            is_negative,
-            rate,
+            indicator,
+            sign,
+            rate: if sign.is_some() { -value } else { value },
         })
     }
 
     fn to_swift_string(&self) -> String {
-        let negative_indicator = if self.is_negative.is_some() { "N" } else { "" };
+        let sign = if self.sign.is_some() || self.rate < 0.0 {
+            "N"
+        } else {
+            ""
+        };
         let rate_str = format!("{:.4}", self.rate.abs()).replace('.', ",");
-        format!(
-            ":37H:{}{}{}",
-            self.rate_indicator, negative_indicator, rate_str
-        )
+        format!(":37H:{}{}{}", self.indicator, sign, rate_str)
     }
 }
 
@@ -97,42 +104,44 @@ mod tests {
     fn test_field37h_parse() {
         // Test positive credit rate
         let field = Field37H::parse("C2,5000").unwrap();
-        assert_eq!(field.rate_indicator, 'C');
-        assert_eq!(field.is_negative, None);
+        assert_eq!(field.indicator, 'C');
+        assert_eq!(field.sign, None);
         assert_eq!(field.rate, 2.5);
+        assert_eq!(field.to_swift_string(), ":37H:C2,5000");
 
         // Test positive debit rate
         let field = Field37H::parse("D3,7500").unwrap();
-        assert_eq!(field.rate_indicator, 'D');
-        assert_eq!(field.is_negative, None);
+        assert_eq!(field.indicator, 'D');
+        assert_eq!(field.sign, None);
         assert_eq!(field.rate, 3.75);
 
         // Test negative credit rate
         let field = Field37H::parse("CN0,2500").unwrap();
-        assert_eq!(field.rate_indicator, 'C');
-        assert_eq!(field.is_negative, Some(true));
+        assert_eq!(field.indicator, 'C');
+        assert_eq!(field.sign, Some('N'));
         assert_eq!(field.rate, -0.25);
+        assert_eq!(field.to_swift_string(), ":37H:CN0,2500");
     }
 
     #[test]
     fn test_field37h_to_swift_string() {
         let field = Field37H {
-            rate_indicator: 'C',
-            is_negative: None,
+            indicator: 'C',
+            sign: None,
             rate: 2.5,
         };
         assert_eq!(field.to_swift_string(), ":37H:C2,5000");
 
         let field = Field37H {
-            rate_indicator: 'D',
-            is_negative: None,
+            indicator: 'D',
+            sign: None,
             rate: 3.75,
         };
         assert_eq!(field.to_swift_string(), ":37H:D3,7500");
 
         let field = Field37H {
-            rate_indicator: 'C',
-            is_negative: Some(true),
+            indicator: 'C',
+            sign: Some('N'),
             rate: -0.25,
         };
         assert_eq!(field.to_swift_string(), ":37H:CN0,2500");
@@ -151,5 +160,14 @@ mod tests {
 
         // Empty input
         assert!(Field37H::parse("").is_err());
+
+        // Rate must be 12d with a decimal comma
+        assert!(Field37H::parse("C2.5").is_err());
+        assert!(Field37H::parse("C25").is_err());
+        assert!(Field37H::parse("C,25").is_err());
+        assert!(Field37H::parse("C1234567890,12").is_err());
+
+        // Sign is not allowed on a zero rate
+        assert!(Field37H::parse("CN0,").is_err());
     }
 }
@@ -20,6 +20,7 @@
 //! 123 MAIN STREET
 //! ```
 
+use super::field_utils::AccountLine;
 use super::swift_utils::{parse_bic, parse_swift_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -108,15 +109,8 @@ impl SwiftField for Field50A {
         let mut start_index = 0;
 
         // Check if first line is party identifier
-        if lines[0].starts_with('/') {
-            let identifier = &lines[0][1..];
-            if identifier.len() > 34 {
-                return Err(ParseError::InvalidFormat {
-                    message: "Field 50A party identifier exceeds 34 characters".to_string(),
-                });
-            }
-            parse_swift_chars(identifier, "Field 50A party identifier")?;
-            party_identifier = Some(identifier.to_string());
+        if let Some(line) = AccountLine::parse(lines[0], "Field 50A party identifier")? {
+            party_identifier = Some(line.identifier());
             start_index = 1;
         }
 
@@ -326,16 +320,9 @@ impl SwiftField for Field50K {
         let mut start_index = 0;
 
         // Check if first line is account (with leading slash in MT format)
-        if lines[0].starts_with('/') {
-            let acc = &lines[0][1..];
-            if acc.len() > 34 {
-                return Err(ParseError::InvalidFormat {
-                    message: "Field 50K account exceeds 34 characters".to_string(),
-                });
-            }
-            parse_swift_chars(acc, "Field 50K account")?;
+        if let Some(line) = AccountLine::parse(lines[0], "Field 50K account")? {
             // Store account without the slash
-            account = Some(acc.to_string());
+            account = Some(line.identifier());
             start_index = 1;
         }
 
@@ -649,6 +636,13 @@ impl SwiftField for Field50InstructingParty {
             Field50InstructingParty::L(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field50InstructingParty::C(_) => Some("C"),
+            Field50InstructingParty::L(_) => Some("L"),
+        }
+    }
 }
 
 /// Enum for Field50 Ordering Customer variants (F, G, H)
@@ -736,9 +730,20 @@ impl SwiftField for Field50OrderingCustomerFGH {
             Field50OrderingCustomerFGH::H(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field50OrderingCustomerFGH::F(_) => Some("F"),
+            Field50OrderingCustomerFGH::G(_) => Some("G"),
+            Field50OrderingCustomerFGH::H(_) => Some("H"),
+        }
+    }
 }
 
 /// Enum for Field50 Ordering Customer variants (A, F, K)
+///
+/// Also accepts the legacy `:50:` form without option letter, kept as `NoOption`
+/// so it serializes back as `:50:`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub enum Field50OrderingCustomerAFK {
@@ -748,6 +753,20 @@ pub enum Field50OrderingCustomerAFK {
     F(Field50F),
     #[serde(rename = "50K")]
     K(Field50K),
+    #[serde(rename = "50")]
+    NoOption(Field50NoOption),
+}
+
+impl Field50OrderingCustomerAFK {
+    /// Account (or party identifier) and BIC identifying the ordering customer, where present
+    pub fn account_and_bic(&self) -> (Option<&str>, Option<&str>) {
+        match self {
+            Field50OrderingCustomerAFK::A(field) => (field.party_identifier.as_deref(), None),
+            Field50OrderingCustomerAFK::F(field) => (Some(&field.account), Some(&field.bic)),
+            Field50OrderingCustomerAFK::K(field) => (field.account.as_deref(), None),
+            Field50OrderingCustomerAFK::NoOption(_) => (None, None),
+        }
+    }
 }
 
 impl SwiftField for Field50OrderingCustomerAFK {
@@ -815,6 +834,10 @@ impl SwiftField for Field50OrderingCustomerAFK {
                 let field = Field50K::parse(value)?;
                 Ok(Field50OrderingCustomerAFK::K(field))
             }
+            Some("") => {
+                let field = Field50NoOption::parse(value)?;
+                Ok(Field50OrderingCustomerAFK::NoOption(field))
+            }
             _ => {
                 // No variant specified, fall back to default parse behavior
                 Self::parse(value)
@@ -827,6 +850,7 @@ impl SwiftField for Field50OrderingCustomerAFK {
             Field50OrderingCustomerAFK::A(field) => field.to_swift_string(),
             Field50OrderingCustomerAFK::F(field) => field.to_swift_string(),
             Field50OrderingCustomerAFK::K(field) => field.to_swift_string(),
+            Field50OrderingCustomerAFK::NoOption(field) => field.to_swift_string(),
         }
     }
 
@@ -835,8 +859,15 @@ impl SwiftField for Field50OrderingCustomerAFK {
             Field50OrderingCustomerAFK::A(_) => Some("A"),
             Field50OrderingCustomerAFK::F(_) => Some("F"),
             Field50OrderingCustomerAFK::K(_) => Some("K"),
+            Field50OrderingCustomerAFK::NoOption(_) => None,
         }
     }
+
+    fn empty() -> Option<Self> {
+        Some(Field50OrderingCustomerAFK::NoOption(Field50NoOption {
+            name_and_address: Vec::new(),
+        }))
+    }
 }
 
 /// Enum for Field50 Ordering Customer variants (No Option, C, F)
@@ -920,6 +951,14 @@ impl SwiftField for Field50OrderingCustomerNCF {
             Field50OrderingCustomerNCF::F(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field50OrderingCustomerNCF::NoOption(_) => None,
+            Field50OrderingCustomerNCF::C(_) => Some("C"),
+            Field50OrderingCustomerNCF::F(_) => Some("F"),
+        }
+    }
 }
 
 /// Enum for Field50 Creditor variants (A, K)
@@ -1051,6 +1090,12 @@ mod tests {
         assert_eq!(field.account, Some("DE89370400440532013000".to_string()));
         assert_eq!(field.name_and_address[0], "JOHN DOE");
         assert_eq!(field.name_and_address.len(), 3);
+
+        let field = Field50K::parse("/12345\nJOHN DOE").unwrap();
+        assert_eq!(field.account, Some("12345".to_string()));
+        let field = Field50K::parse("JOHN DOE\nNEW YORK").unwrap();
+        assert_eq!(field.account, None);
+        assert!(Field50K::parse(&format!("/{}\nJOHN DOE", "1".repeat(35))).is_err());
     }
 
     #[test]
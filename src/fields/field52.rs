@@ -1,4 +1,4 @@
-use super::field_utils::{parse_name_and_address, parse_party_identifier};
+use super::field_utils::{InstitutionRef, parse_name_and_address, parse_party_identifier};
 use super::swift_utils::{parse_bic, parse_swift_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -100,28 +100,9 @@ impl SwiftField for Field52B {
         let mut current_idx = 0;
 
         // Check for party identifier
-        if !lines.is_empty() && lines[0].starts_with('/') {
-            let line = &lines[0][1..]; // Remove leading /
-
-            // Check if it's /1!a/34x format
-            if let Some(slash_pos) = line.find('/') {
-                let code = &line[..slash_pos];
-                let id = &line[slash_pos + 1..];
-
-                if code.len() == 1
-                    && code.chars().all(|c| c.is_ascii_alphabetic())
-                    && id.len() <= 34
-                {
-                    parse_swift_chars(id, "Field 52B party identifier")?;
-                    party_identifier = Some(format!("{}/{}", code, id));
-                    current_idx = 1;
-                }
-            } else if line.len() <= 34 {
-                // Just /34x format
-                parse_swift_chars(line, "Field 52B party identifier")?;
-                party_identifier = Some(line.to_string());
-                current_idx = 1;
-            }
+        if let Some(party_id) = parse_party_identifier(lines[0])? {
+            party_identifier = Some(party_id);
+            current_idx = 1;
         }
 
         // Check for location
@@ -336,6 +317,13 @@ impl SwiftField for Field52AccountServicingInstitution {
             Field52AccountServicingInstitution::C(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field52AccountServicingInstitution::A(_) => Some("A"),
+            Field52AccountServicingInstitution::C(_) => Some("C"),
+        }
+    }
 }
 
 /// Enum for Field52 Ordering Institution variants (A, D)
@@ -348,6 +336,19 @@ pub enum Field52OrderingInstitution {
     D(Field52D),
 }
 
+impl Field52OrderingInstitution {
+    /// Option tag and identification of the institution
+    pub fn institution(&self) -> (&'static str, InstitutionRef) {
+        match self {
+            Field52OrderingInstitution::A(field) => ("52A", InstitutionRef::Bic(field.bic.clone())),
+            Field52OrderingInstitution::D(field) => (
+                "52D",
+                InstitutionRef::NameAndAddress(field.name_and_address.clone()),
+            ),
+        }
+    }
+}
+
 impl SwiftField for Field52OrderingInstitution {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -483,6 +484,14 @@ impl SwiftField for Field52CreditorBank {
             Field52CreditorBank::D(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field52CreditorBank::A(_) => Some("A"),
+            Field52CreditorBank::C(_) => Some("C"),
+            Field52CreditorBank::D(_) => Some("D"),
+        }
+    }
 }
 
 /// Enum for Field52 Drawer Bank variants (A, B, D)
@@ -557,6 +566,14 @@ impl SwiftField for Field52DrawerBank {
             Field52DrawerBank::D(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field52DrawerBank::A(_) => Some("A"),
+            Field52DrawerBank::B(_) => Some("B"),
+            Field52DrawerBank::D(_) => Some("D"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -621,4 +638,40 @@ mod tests {
         // Too many lines in 52D
         assert!(Field52D::parse("LINE1\nLINE2\nLINE3\nLINE4\nLINE5").is_err());
     }
+
+    #[test]
+    fn test_field52_option_round_trip() {
+        let cases = [
+            ("C", "/ACCT123"),
+            ("C", "//FW021000018"),
+            ("C", "/CH123456"),
+            ("D", "/ACCT123\nBANK NAME\nCITY"),
+            ("D", "BANK NAME\nCITY"),
+            ("D", "/D/ACCT\nBANK"),
+            ("D", "//FW123\nBANK"),
+        ];
+        for (variant, input) in cases {
+            let field =
+                Field52CreditorBank::parse_with_variant(input, Some(variant), Some("52")).unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":52{variant}:{input}"));
+        }
+    }
+
+    #[test]
+    fn test_field52b_round_trip() {
+        let cases = [
+            ("B", "/ACCT123\nLONDON"),
+            ("B", "LONDON"),
+            ("B", "/ACCT123"),
+            ("B", "/D/ACCT\nLONDON"),
+            ("B", "//FW123\nNEW YORK"),
+        ];
+        for (variant, input) in cases {
+            let field =
+                Field52DrawerBank::parse_with_variant(input, Some(variant), Some("52")).unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":52{variant}:{input}"));
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::field_utils::parse_party_identifier;
+use super::field_utils::{InstitutionRef, parse_name_and_address, parse_party_identifier};
 use super::swift_utils::{parse_bic, parse_max_length};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -197,24 +197,7 @@ impl SwiftField for Field53D {
         }
 
         // Parse remaining lines as name and address (max 4 lines, max 35 chars each)
-        let mut name_and_address = Vec::new();
-        for (i, line) in lines.iter().enumerate() {
-            if i >= 4 {
-                break;
-            }
-            if line.len() > 35 {
-                return Err(ParseError::InvalidFormat {
-                    message: format!("Field 53D line {} exceeds 35 characters", i + 1),
-                });
-            }
-            name_and_address.push(line.to_string());
-        }
-
-        if name_and_address.is_empty() {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 53D must contain name and address information".to_string(),
-            });
-        }
+        let name_and_address = parse_name_and_address(&lines, 0, "Field 53D")?;
 
         Ok(Field53D {
             party_identifier,
@@ -249,6 +232,23 @@ pub enum Field53SenderCorrespondent {
     D(Field53D),
 }
 
+impl Field53SenderCorrespondent {
+    /// Option tag and identification of the institution
+    pub fn institution(&self) -> (&'static str, InstitutionRef) {
+        match self {
+            Field53SenderCorrespondent::A(field) => ("53A", InstitutionRef::Bic(field.bic.clone())),
+            Field53SenderCorrespondent::B(field) => (
+                "53B",
+                InstitutionRef::from_location(&field.location, &field.party_identifier),
+            ),
+            Field53SenderCorrespondent::D(field) => (
+                "53D",
+                InstitutionRef::NameAndAddress(field.name_and_address.clone()),
+            ),
+        }
+    }
+}
+
 impl SwiftField for Field53SenderCorrespondent {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -415,4 +415,32 @@ mod tests {
         let field = Field53SenderCorrespondent::parse("BANK NAME\nADDRESS LINE 1\nCITY").unwrap();
         assert!(matches!(field, Field53SenderCorrespondent::D(_)));
     }
+
+    #[test]
+    fn test_field53d_line_limits() {
+        assert!(Field53D::parse("BANK NAME\nSTREET\nCITY\nREGION\nCOUNTRY").is_err());
+        assert!(Field53D::parse("BANK NAME WITH A LINE FAR LONGER THAN ALLOWED").is_err());
+    }
+
+    #[test]
+    fn test_field53_option_round_trip() {
+        let cases = [
+            ("B", "/ACCT123\nLONDON"),
+            ("B", "LONDON"),
+            ("B", "/ACCT123"),
+            ("B", "/D/ACCT\nLONDON"),
+            ("B", "//FW123\nNEW YORK"),
+            ("D", "/ACCT123\nBANK NAME\nCITY"),
+            ("D", "BANK NAME\nCITY"),
+            ("D", "/D/ACCT\nBANK"),
+            ("D", "//FW123\nBANK"),
+        ];
+        for (variant, input) in cases {
+            let field =
+                Field53SenderCorrespondent::parse_with_variant(input, Some(variant), Some("53"))
+                    .unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":53{variant}:{input}"));
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use super::field_utils::{parse_name_and_address, parse_party_identifier};
+use super::field_utils::{InstitutionRef, parse_name_and_address, parse_party_identifier};
 use super::swift_utils::{parse_bic, parse_max_length};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -202,6 +202,25 @@ pub enum Field54ReceiverCorrespondent {
     D(Field54D),
 }
 
+impl Field54ReceiverCorrespondent {
+    /// Option tag and identification of the institution
+    pub fn institution(&self) -> (&'static str, InstitutionRef) {
+        match self {
+            Field54ReceiverCorrespondent::A(field) => {
+                ("54A", InstitutionRef::Bic(field.bic.clone()))
+            }
+            Field54ReceiverCorrespondent::B(field) => (
+                "54B",
+                InstitutionRef::from_location(&field.location, &field.party_identifier),
+            ),
+            Field54ReceiverCorrespondent::D(field) => (
+                "54D",
+                InstitutionRef::NameAndAddress(field.name_and_address.clone()),
+            ),
+        }
+    }
+}
+
 impl SwiftField for Field54ReceiverCorrespondent {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -253,6 +272,34 @@ impl SwiftField for Field54ReceiverCorrespondent {
         })
     }
 
+    fn parse_with_variant(
+        value: &str,
+        variant: Option<&str>,
+        _field_tag: Option<&str>,
+    ) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        match variant {
+            Some("A") => {
+                let field = Field54A::parse(value)?;
+                Ok(Field54ReceiverCorrespondent::A(field))
+            }
+            Some("B") => {
+                let field = Field54B::parse(value)?;
+                Ok(Field54ReceiverCorrespondent::B(field))
+            }
+            Some("D") => {
+                let field = Field54D::parse(value)?;
+                Ok(Field54ReceiverCorrespondent::D(field))
+            }
+            _ => {
+                // No variant specified, fall back to default parse behavior
+                Self::parse(value)
+            }
+        }
+    }
+
     fn to_swift_string(&self) -> String {
         match self {
             Field54ReceiverCorrespondent::A(field) => field.to_swift_string(),
@@ -340,4 +387,26 @@ mod tests {
         let field = Field54ReceiverCorrespondent::parse("BANK NAME\nADDRESS LINE 1\nCITY").unwrap();
         assert!(matches!(field, Field54ReceiverCorrespondent::D(_)));
     }
+
+    #[test]
+    fn test_field54_option_round_trip() {
+        let cases = [
+            ("B", "/ACCT123\nLONDON"),
+            ("B", "LONDON"),
+            ("B", "/ACCT123"),
+            ("B", "/D/ACCT\nLONDON"),
+            ("B", "//FW123\nNEW YORK"),
+            ("D", "/ACCT123\nBANK NAME\nCITY"),
+            ("D", "BANK NAME\nCITY"),
+            ("D", "/D/ACCT\nBANK"),
+            ("D", "//FW123\nBANK"),
+        ];
+        for (variant, input) in cases {
+            let field =
+                Field54ReceiverCorrespondent::parse_with_variant(input, Some(variant), Some("54"))
+                    .unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":54{variant}:{input}"));
+        }
+    }
 }
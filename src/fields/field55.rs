@@ -366,4 +366,29 @@ mod tests {
             Field55ThirdReimbursementInstitution::parse("BANK NAME\nADDRESS LINE 1\nCITY").unwrap();
         assert!(matches!(field, Field55ThirdReimbursementInstitution::D(_)));
     }
+
+    #[test]
+    fn test_field55_option_round_trip() {
+        let cases = [
+            ("B", "/ACCT123\nLONDON"),
+            ("B", "LONDON"),
+            ("B", "/ACCT123"),
+            ("B", "/D/ACCT\nLONDON"),
+            ("B", "//FW123\nNEW YORK"),
+            ("D", "/ACCT123\nBANK NAME\nCITY"),
+            ("D", "BANK NAME\nCITY"),
+            ("D", "/D/ACCT\nBANK"),
+            ("D", "//FW123\nBANK"),
+        ];
+        for (variant, input) in cases {
+            let field = Field55ThirdReimbursementInstitution::parse_with_variant(
+                input,
+                Some(variant),
+                Some("55"),
+            )
+            .unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":55{variant}:{input}"));
+        }
+    }
 }
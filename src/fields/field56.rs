@@ -1,4 +1,4 @@
-use super::field_utils::{parse_name_and_address, parse_party_identifier};
+use super::field_utils::{InstitutionRef, parse_name_and_address, parse_party_identifier};
 use super::swift_utils::{parse_bic, parse_swift_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -197,6 +197,23 @@ impl SwiftField for Field56D {
     }
 }
 
+impl Field56Intermediary {
+    /// Option tag and identification of the institution
+    pub fn institution(&self) -> (&'static str, InstitutionRef) {
+        match self {
+            Field56Intermediary::A(field) => ("56A", InstitutionRef::Bic(field.bic.clone())),
+            Field56Intermediary::C(field) => (
+                "56C",
+                InstitutionRef::PartyIdentifier(field.party_identifier.clone()),
+            ),
+            Field56Intermediary::D(field) => (
+                "56D",
+                InstitutionRef::NameAndAddress(field.name_and_address.clone()),
+            ),
+        }
+    }
+}
+
 impl SwiftField for Field56Intermediary {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -320,6 +337,13 @@ impl SwiftField for Field56IntermediaryAD {
             Field56IntermediaryAD::D(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field56IntermediaryAD::A(_) => Some("A"),
+            Field56IntermediaryAD::D(_) => Some("D"),
+        }
+    }
 }
 
 // Type aliases for backward compatibility and simplicity
@@ -379,4 +403,23 @@ mod tests {
         // Too many lines in 56D
         assert!(Field56D::parse("LINE1\nLINE2\nLINE3\nLINE4\nLINE5").is_err());
     }
+
+    #[test]
+    fn test_field56_option_round_trip() {
+        let cases = [
+            ("C", "/ACCT123"),
+            ("C", "//FW021000018"),
+            ("C", "/CH123456"),
+            ("D", "/ACCT123\nBANK NAME\nCITY"),
+            ("D", "BANK NAME\nCITY"),
+            ("D", "/D/ACCT\nBANK"),
+            ("D", "//FW123\nBANK"),
+        ];
+        for (variant, input) in cases {
+            let field =
+                Field56Intermediary::parse_with_variant(input, Some(variant), Some("56")).unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":56{variant}:{input}"));
+        }
+    }
 }
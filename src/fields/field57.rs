@@ -1,4 +1,4 @@
-use super::field_utils::{parse_name_and_address, parse_party_identifier};
+use super::field_utils::{InstitutionRef, parse_name_and_address, parse_party_identifier};
 use super::swift_utils::{parse_bic, parse_swift_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -262,6 +262,27 @@ pub enum Field57 {
     D(Field57D),
 }
 
+impl Field57 {
+    /// Option tag and identification of the institution
+    pub fn institution(&self) -> (&'static str, InstitutionRef) {
+        match self {
+            Field57::A(field) => ("57A", InstitutionRef::Bic(field.bic.clone())),
+            Field57::B(field) => (
+                "57B",
+                InstitutionRef::from_location(&field.location, &field.party_identifier),
+            ),
+            Field57::C(field) => (
+                "57C",
+                InstitutionRef::PartyIdentifier(field.party_identifier.clone()),
+            ),
+            Field57::D(field) => (
+                "57D",
+                InstitutionRef::NameAndAddress(field.name_and_address.clone()),
+            ),
+        }
+    }
+}
+
 impl SwiftField for Field57 {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -402,6 +423,29 @@ mod tests {
         assert_eq!(field.name_and_address.len(), 2);
     }
 
+    #[test]
+    fn test_field57d_four_line_address() {
+        let input = "/DE89370400440532013000\nBENEFICIARY BANK AG\nHAUPTSTRASSE 1\n60311 FRANKFURT\nGERMANY";
+        let field = Field57D::parse(input).unwrap();
+        assert_eq!(
+            field.party_identifier,
+            Some("DE89370400440532013000".to_string())
+        );
+        assert_eq!(
+            field.name_and_address,
+            vec![
+                "BENEFICIARY BANK AG",
+                "HAUPTSTRASSE 1",
+                "60311 FRANKFURT",
+                "GERMANY"
+            ]
+        );
+        assert_eq!(field.to_swift_string(), format!(":57D:{}", input));
+
+        // Over-length address line (36 characters)
+        assert!(Field57D::parse("BENEFICIARY BANK\nABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789").is_err());
+    }
+
     #[test]
     fn test_field57_payment_method_codes() {
         // Fedwire code
@@ -432,6 +476,29 @@ mod tests {
         // Too many lines in 57D
         assert!(Field57D::parse("LINE1\nLINE2\nLINE3\nLINE4\nLINE5").is_err());
     }
+
+    #[test]
+    fn test_field57_option_round_trip() {
+        let cases = [
+            ("B", "/ACCT123\nLONDON"),
+            ("B", "LONDON"),
+            ("B", "/ACCT123"),
+            ("B", "/D/ACCT\nLONDON"),
+            ("B", "//FW123\nNEW YORK"),
+            ("C", "/ACCT123"),
+            ("C", "//FW021000018"),
+            ("C", "/CH123456"),
+            ("D", "/ACCT123\nBANK NAME\nCITY"),
+            ("D", "BANK NAME\nCITY"),
+            ("D", "/D/ACCT\nBANK"),
+            ("D", "//FW123\nBANK"),
+        ];
+        for (variant, input) in cases {
+            let field = Field57::parse_with_variant(input, Some(variant), Some("57")).unwrap();
+            assert_eq!(field.get_variant_tag(), Some(variant));
+            assert_eq!(field.to_swift_string(), format!(":57{variant}:{input}"));
+        }
+    }
 }
 
 // Type aliases for backward compatibility
@@ -513,6 +580,14 @@ impl SwiftField for Field57DebtInstitution {
             Field57DebtInstitution::D(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field57DebtInstitution::A(_) => Some("A"),
+            Field57DebtInstitution::B(_) => Some("B"),
+            Field57DebtInstitution::D(_) => Some("D"),
+        }
+    }
 }
 
 /// Field57AccountWithABD: Account With Institution for MT291 and similar messages
@@ -1,4 +1,4 @@
-use super::field_utils::{parse_name_and_address, parse_party_identifier};
+use super::field_utils::{AccountLine, parse_name_and_address, parse_party_identifier};
 use super::swift_utils::{parse_bic, parse_swift_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
@@ -186,15 +186,9 @@ impl SwiftField for Field59A {
         let bic_line_idx;
 
         // Check if first line is account (/...)
-        if lines[0].starts_with('/') {
-            let identifier = &lines[0][1..];
-            if identifier.len() <= 34 {
-                parse_swift_chars(identifier, "Field 59A account")?;
-                account = Some(identifier.to_string());
-                bic_line_idx = 1;
-            } else {
-                bic_line_idx = 0;
-            }
+        if let Some(line) = AccountLine::parse(lines[0], "Field 59A account")? {
+            account = Some(line.identifier());
+            bic_line_idx = 1;
         } else {
             bic_line_idx = 0;
         }
@@ -240,13 +234,9 @@ impl SwiftField for Field59NoOption {
         let mut start_idx = 0;
 
         // Check for account
-        if lines[0].starts_with('/') {
-            let identifier = &lines[0][1..];
-            if identifier.len() <= 34 {
-                parse_swift_chars(identifier, "Field 59 account")?;
-                account = Some(identifier.to_string());
-                start_idx = 1;
-            }
+        if let Some(line) = AccountLine::parse(lines[0], "Field 59 account")? {
+            account = Some(line.identifier());
+            start_idx = 1;
         }
 
         // Parse remaining lines as name and address
@@ -276,6 +266,17 @@ impl SwiftField for Field59NoOption {
     }
 }
 
+impl Field59 {
+    /// Account (or party identifier) and BIC identifying the beneficiary, where present
+    pub fn account_and_bic(&self) -> (Option<&str>, Option<&str>) {
+        match self {
+            Field59::A(field) => (field.account.as_deref(), Some(&field.bic)),
+            Field59::F(field) => (field.party_identifier.as_deref(), None),
+            Field59::NoOption(field) => (field.account.as_deref(), None),
+        }
+    }
+}
+
 impl SwiftField for Field59 {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -358,6 +359,13 @@ impl SwiftField for Field59 {
             Field59::NoOption(_) => None, // No option doesn't have a variant letter
         }
     }
+
+    fn empty() -> Option<Self> {
+        Some(Field59::NoOption(Field59NoOption {
+            account: None,
+            name_and_address: Vec::new(),
+        }))
+    }
 }
 
 impl SwiftField for Field59Debtor {
@@ -410,6 +418,13 @@ impl SwiftField for Field59Debtor {
             Field59Debtor::NoOption(field) => field.to_swift_string(),
         }
     }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field59Debtor::A(_) => Some("A"),
+            Field59Debtor::NoOption(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -454,6 +469,16 @@ mod tests {
         let field = Field59A::parse("CHASUS33XXX").unwrap();
         assert_eq!(field.account, None);
         assert_eq!(field.bic, "CHASUS33XXX");
+
+        // Account line with credit/debit indicator
+        let field = Field59A::parse("/C/12345\nCHASUS33XXX").unwrap();
+        assert_eq!(field.account, Some("C/12345".to_string()));
+        let line = AccountLine::from_identifier(field.account.as_deref().unwrap());
+        assert_eq!(
+            (line.indicator, line.account.as_str()),
+            (Some('C'), "12345")
+        );
+        assert_eq!(field.to_swift_string(), ":59A:/C/12345\nCHASUS33XXX");
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use super::swift_utils::{parse_amount, parse_currency, parse_date_yymmdd, parse_exact_length};
+use super::swift_utils::{format_swift_amount, parse_balance};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
 use chrono::NaiveDate;
@@ -69,31 +69,7 @@ impl SwiftField for Field60F {
         Self: Sized,
     {
         // Format: 1!a6!n3!a15d - DebitCredit + Date + Currency + Amount
-        if input.len() < 10 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 60F must be at least 10 characters long".to_string(),
-            });
-        }
-
-        // Parse debit/credit mark (1 character)
-        let debit_credit_mark = parse_exact_length(&input[0..1], 1, "Field 60F debit/credit mark")?;
-        if debit_credit_mark != "D" && debit_credit_mark != "C" {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 60F debit/credit mark must be 'D' or 'C'".to_string(),
-            });
-        }
-
-        // Parse value date (6 digits)
-        let date_str = parse_exact_length(&input[1..7], 6, "Field 60F value date")?;
-        let value_date = parse_date_yymmdd(&date_str)?;
-
-        // Parse currency (3 characters)
-        let currency = parse_exact_length(&input[7..10], 3, "Field 60F currency")?;
-        let currency = parse_currency(&currency)?;
-
-        // Parse amount (remaining characters)
-        let amount_str = &input[10..];
-        let amount = parse_amount(amount_str)?;
+        let (debit_credit_mark, value_date, currency, amount) = parse_balance(input, "Field 60F")?;
 
         Ok(Field60F {
             debit_credit_mark,
@@ -109,9 +85,13 @@ impl SwiftField for Field60F {
             self.debit_credit_mark,
             self.value_date.format("%y%m%d"),
             self.currency,
-            format!("{:.2}", self.amount).replace('.', ",")
+            format_swift_amount(self.amount, 2)
         )
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
 }
 
 impl SwiftField for Field60M {
@@ -120,31 +100,7 @@ impl SwiftField for Field60M {
         Self: Sized,
     {
         // Format: 1!a6!n3!a15d - DebitCredit + Date + Currency + Amount
-        if input.len() < 10 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 60M must be at least 10 characters long".to_string(),
-            });
-        }
-
-        // Parse debit/credit mark (1 character)
-        let debit_credit_mark = parse_exact_length(&input[0..1], 1, "Field 60M debit/credit mark")?;
-        if debit_credit_mark != "D" && debit_credit_mark != "C" {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 60M debit/credit mark must be 'D' or 'C'".to_string(),
-            });
-        }
-
-        // Parse value date (6 digits)
-        let date_str = parse_exact_length(&input[1..7], 6, "Field 60M value date")?;
-        let value_date = parse_date_yymmdd(&date_str)?;
-
-        // Parse currency (3 characters)
-        let currency = parse_exact_length(&input[7..10], 3, "Field 60M currency")?;
-        let currency = parse_currency(&currency)?;
-
-        // Parse amount (remaining characters)
-        let amount_str = &input[10..];
-        let amount = parse_amount(amount_str)?;
+        let (debit_credit_mark, value_date, currency, amount) = parse_balance(input, "Field 60M")?;
 
         Ok(Field60M {
             debit_credit_mark,
@@ -160,9 +116,13 @@ impl SwiftField for Field60M {
             self.debit_credit_mark,
             self.value_date.format("%y%m%d"),
             self.currency,
-            format!("{:.2}", self.amount).replace('.', ",")
+            format_swift_amount(self.amount, 2)
         )
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
 }
 
 impl SwiftField for Field60 {
@@ -209,6 +169,20 @@ impl SwiftField for Field60 {
             Field60::M(field) => field.to_swift_string(),
         }
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        match self {
+            Field60::F(field) => field.dates(),
+            Field60::M(field) => field.dates(),
+        }
+    }
+
+    fn get_variant_tag(&self) -> Option<&'static str> {
+        match self {
+            Field60::F(_) => Some("F"),
+            Field60::M(_) => Some("M"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use super::swift_utils::{parse_amount, parse_date_yymmdd, parse_swift_chars};
+use super::swift_utils::{format_swift_amount, parse_amount, parse_date_yymmdd, parse_swift_chars};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
 use chrono::NaiveDate;
@@ -47,6 +47,36 @@ pub struct Field61 {
     pub supplementary_details: Option<String>,
 }
 
+impl Field61 {
+    /// Whether the entry reverses an earlier one (mark RD or RC)
+    pub fn is_reversal(&self) -> bool {
+        self.debit_credit_mark.starts_with('R')
+    }
+
+    /// Amount with the sign implied by the mark: credits (C, RD) positive, debits (D, RC) negative
+    pub fn signed_amount(&self) -> f64 {
+        match self.debit_credit_mark.as_str() {
+            "C" | "RD" => self.amount,
+            _ => -self.amount,
+        }
+    }
+
+    /// Entry date (MMDD) placed in the year that puts it closest to the value date
+    ///
+    /// Entries booked around year end may fall in the year before or after the value date.
+    pub fn entry_date_resolved(&self) -> Option<NaiveDate> {
+        use chrono::Datelike;
+
+        let entry = self.entry_date.as_deref()?;
+        let month = entry.get(..2)?.parse().ok()?;
+        let day = entry.get(2..4)?.parse().ok()?;
+        let year = self.value_date.year();
+        (year - 1..=year + 1)
+            .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+            .min_by_key(|date| (*date - self.value_date).num_days().abs())
+    }
+}
+
 impl SwiftField for Field61 {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -238,7 +268,7 @@ impl SwiftField for Field61 {
             result.push(funds_code);
         }
 
-        result.push_str(&format!("{:.2}", self.amount).replace('.', ","));
+        result.push_str(&format_swift_amount(self.amount, 2));
         result.push_str(&self.transaction_type);
         result.push_str(&self.customer_reference);
 
@@ -258,6 +288,12 @@ impl SwiftField for Field61 {
 
         result
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        std::iter::once(self.value_date)
+            .chain(self.entry_date_resolved())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -290,6 +326,18 @@ mod tests {
             NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()
         );
         assert_eq!(field.entry_date, Some("1226".to_string()));
+        assert_eq!(
+            field.dates(),
+            vec![
+                NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 12, 26).unwrap()
+            ]
+        );
+        let year_end = Field61::parse("2312310102C500,00NTRFREF789").unwrap();
+        assert_eq!(
+            year_end.entry_date_resolved(),
+            NaiveDate::from_ymd_opt(2024, 1, 2)
+        );
         assert_eq!(field.debit_credit_mark, "C");
         assert_eq!(field.funds_code, None);
         assert_eq!(field.amount, 500.00);
@@ -311,6 +359,14 @@ mod tests {
         let field = Field61::parse("231225RD1000,00NTRFREVREF123").unwrap();
         assert_eq!(field.debit_credit_mark, "RD");
         assert_eq!(field.amount, 1000.00);
+        assert!(field.is_reversal());
+        assert_eq!(field.signed_amount(), 1000.00);
+
+        let field = Field61::parse("231225RC1000,00NTRFREVREF123").unwrap();
+        assert_eq!(field.signed_amount(), -1000.00);
+
+        // The sign belongs in the mark, never in the amount
+        assert!(Field61::parse("231225D-1000,00NTRFREF123").is_err());
     }
 
     #[test]
@@ -1,5 +1,4 @@
-use super::swift_utils::{parse_amount, parse_currency, parse_date_yymmdd, parse_exact_length};
-use crate::errors::ParseError;
+use super::swift_utils::{format_swift_amount, parse_balance};
 use crate::traits::SwiftField;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
@@ -37,31 +36,7 @@ impl SwiftField for Field65 {
         Self: Sized,
     {
         // Format: 1!a6!n3!a15d - DebitCredit + Date + Currency + Amount
-        if input.len() < 10 {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 65 must be at least 10 characters long".to_string(),
-            });
-        }
-
-        // Parse debit/credit mark (1 character)
-        let debit_credit_mark = parse_exact_length(&input[0..1], 1, "Field 65 debit/credit mark")?;
-        if debit_credit_mark != "D" && debit_credit_mark != "C" {
-            return Err(ParseError::InvalidFormat {
-                message: "Field 65 debit/credit mark must be 'D' or 'C'".to_string(),
-            });
-        }
-
-        // Parse value date (6 digits)
-        let date_str = parse_exact_length(&input[1..7], 6, "Field 65 value date")?;
-        let value_date = parse_date_yymmdd(&date_str)?;
-
-        // Parse currency (3 characters)
-        let currency = parse_exact_length(&input[7..10], 3, "Field 65 currency")?;
-        let currency = parse_currency(&currency)?;
-
-        // Parse amount (remaining characters)
-        let amount_str = &input[10..];
-        let amount = parse_amount(amount_str)?;
+        let (debit_credit_mark, value_date, currency, amount) = parse_balance(input, "Field 65")?;
 
         Ok(Field65 {
             debit_credit_mark,
@@ -77,9 +52,13 @@ impl SwiftField for Field65 {
             self.debit_credit_mark,
             self.value_date.format("%y%m%d"),
             self.currency,
-            format!("{:.2}", self.amount).replace('.', ",")
+            format_swift_amount(self.amount, 2)
         )
     }
+
+    fn dates(&self) -> Vec<NaiveDate> {
+        vec![self.value_date]
+    }
 }
 
 #[cfg(test)]
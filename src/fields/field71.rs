@@ -1,8 +1,8 @@
 use super::swift_utils::{
     format_swift_amount_for_currency, parse_amount_with_currency, parse_currency_non_commodity,
-    parse_exact_length, parse_uppercase,
 };
-use crate::errors::ParseError;
+use crate::errors::{ParseError, SwiftValidationError};
+use crate::swift_error_codes::t_series;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
 
@@ -21,7 +21,37 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Field71A {
     /// Charge code: BEN, OUR, or SHA
-    pub code: String,
+    pub code: ChargeBearer,
+}
+
+/// Party bearing the transaction charges (Field 71A code)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChargeBearer {
+    /// `BEN`: all charges borne by the beneficiary customer
+    Ben,
+    /// `OUR`: all charges borne by the ordering customer
+    Our,
+    /// `SHA`: charges shared between ordering and beneficiary customer
+    Sha,
+}
+
+impl ChargeBearer {
+    /// SWIFT code for this charge bearer
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChargeBearer::Ben => "BEN",
+            ChargeBearer::Our => "OUR",
+            ChargeBearer::Sha => "SHA",
+        }
+    }
+}
+
+impl std::fmt::Display for ChargeBearer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl SwiftField for Field71A {
@@ -29,22 +59,22 @@ impl SwiftField for Field71A {
     where
         Self: Sized,
     {
-        // Must be exactly 3 characters
-        let code = parse_exact_length(input, 3, "Field 71A code")?;
-
-        // Must be uppercase alphabetic
-        parse_uppercase(&code, "Field 71A code")?;
-
-        // Validate against known codes
-        const VALID_CODES: &[&str] = &["BEN", "OUR", "SHA"];
-        if !VALID_CODES.contains(&code.as_str()) {
-            return Err(ParseError::InvalidFormat {
-                message: format!(
-                    "Field 71A code must be one of {:?}, found {}",
-                    VALID_CODES, code
-                ),
-            });
-        }
+        let code = match input {
+            "BEN" => ChargeBearer::Ben,
+            "OUR" => ChargeBearer::Our,
+            "SHA" => ChargeBearer::Sha,
+            _ => {
+                return Err(ParseError::SwiftValidation(Box::new(
+                    SwiftValidationError::format_error(
+                        t_series::T08,
+                        "71A",
+                        input,
+                        "BEN, OUR or SHA",
+                        &format!("Field 71A code must be BEN, OUR or SHA, found '{}'", input),
+                    ),
+                )));
+            }
+        };
 
         Ok(Field71A { code })
     }
@@ -73,6 +103,13 @@ pub struct Field71F {
     pub amount: f64,
 }
 
+impl Field71F {
+    /// Currency and amount as an [`Amount`](super::swift_utils::Amount), rounded to the currency's decimals
+    pub fn to_amount(&self) -> crate::Result<super::swift_utils::Amount> {
+        super::swift_utils::Amount::new(&self.currency, self.amount)
+    }
+}
+
 impl SwiftField for Field71F {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -131,6 +168,13 @@ pub struct Field71G {
     pub amount: f64,
 }
 
+impl Field71G {
+    /// Currency and amount as an [`Amount`](super::swift_utils::Amount), rounded to the currency's decimals
+    pub fn to_amount(&self) -> crate::Result<super::swift_utils::Amount> {
+        super::swift_utils::Amount::new(&self.currency, self.amount)
+    }
+}
+
 impl SwiftField for Field71G {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -221,29 +265,44 @@ mod tests {
     fn test_field71a() {
         // Test valid codes
         let field = Field71A::parse("BEN").unwrap();
-        assert_eq!(field.code, "BEN");
+        assert_eq!(field.code, ChargeBearer::Ben);
+        assert_eq!(field.to_swift_string(), ":71A:BEN");
 
         let field = Field71A::parse("OUR").unwrap();
-        assert_eq!(field.code, "OUR");
+        assert_eq!(field.code, ChargeBearer::Our);
+        assert_eq!(field.to_swift_string(), ":71A:OUR");
 
         let field = Field71A::parse("SHA").unwrap();
-        assert_eq!(field.code, "SHA");
+        assert_eq!(field.code, ChargeBearer::Sha);
+        assert_eq!(field.to_swift_string(), ":71A:SHA");
 
         // Test invalid length
         assert!(Field71A::parse("BE").is_err());
         assert!(Field71A::parse("BENE").is_err());
 
         // Test invalid code
-        assert!(Field71A::parse("XXX").is_err());
+        match Field71A::parse("XYZ").unwrap_err() {
+            ParseError::SwiftValidation(error) => {
+                assert_eq!(error.code(), "T08");
+                assert_eq!(error.field(), "71A");
+            }
+            other => panic!("Expected SwiftValidation error, got {other:?}"),
+        }
 
         // Test lowercase (should fail)
         assert!(Field71A::parse("ben").is_err());
 
-        // Test to_swift_string
+        // Serializes to the exact code
         let field = Field71A {
-            code: "SHA".to_string(),
+            code: ChargeBearer::Sha,
         };
-        assert_eq!(field.to_swift_string(), ":71A:SHA");
+        assert_eq!(serde_json::to_string(&field).unwrap(), r#"{"code":"SHA"}"#);
+        assert_eq!(
+            serde_json::from_str::<Field71A>(r#"{"code":"BEN"}"#).unwrap(),
+            Field71A {
+                code: ChargeBearer::Ben
+            }
+        );
     }
 
     #[test]
@@ -1,8 +1,145 @@
 use super::field_utils::parse_multiline_text;
-use crate::errors::ParseError;
+use super::swift_utils::parse_bic;
+use crate::ValidationResult;
+use crate::errors::{ParseError, SwiftValidationError};
+use crate::swift_error_codes::t_series;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
 
+/// Known field 72 code words with the maximum length of their narrative
+///
+/// The narrative is the text after `/CODE/` plus any `//` continuation lines.
+/// `/INS/` must carry a BIC; phone and telecom codes allow a single line only.
+pub const FIELD72_CODE_WORDS: &[(&str, usize)] = &[
+    ("ACC", 195),
+    ("BNF", 195),
+    ("INS", 11),
+    ("INT", 195),
+    ("PHON", 30),
+    ("PHONBEN", 30),
+    ("PHONIBK", 30),
+    ("REC", 195),
+    ("REJT", 195),
+    ("RETN", 195),
+    ("TELE", 30),
+    ("TELEBEN", 30),
+    ("TELEIBK", 30),
+    ("TSU", 195),
+];
+
+/// A `/CODE/narrative` entry of field 72, with its `//` continuation lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field72CodeWord {
+    /// Code word without slashes (e.g. "ACC")
+    pub code: String,
+    /// Narrative of the first line followed by each continuation line
+    pub narrative: Vec<String>,
+}
+
+/// Declares [`ReturnReasonCode`] from `Variant => "CODE", "description";` entries
+macro_rules! return_reason_codes {
+    ($($variant:ident => $code:literal, $description:literal;)*) => {
+        /// ISO 20022 external return reason code carried by `/RETN/` and `/REJT/` in field 72
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum ReturnReasonCode {
+            $(
+                #[doc = $description]
+                $variant,
+            )*
+            /// Code outside the known set, kept verbatim
+            Other(String),
+        }
+
+        impl ReturnReasonCode {
+            /// Decode a four-character reason code; unknown codes become `Other`
+            pub fn from_code(code: &str) -> Self {
+                match code {
+                    $($code => ReturnReasonCode::$variant,)*
+                    other => ReturnReasonCode::Other(other.to_string()),
+                }
+            }
+
+            /// The reason code as it appears in the message (e.g. "AC04")
+            pub fn code(&self) -> &str {
+                match self {
+                    $(ReturnReasonCode::$variant => $code,)*
+                    ReturnReasonCode::Other(code) => code,
+                }
+            }
+
+            /// Human-readable description; `None` for unknown codes
+            pub fn description(&self) -> Option<&'static str> {
+                match self {
+                    $(ReturnReasonCode::$variant => Some($description),)*
+                    ReturnReasonCode::Other(_) => None,
+                }
+            }
+        }
+    };
+}
+
+return_reason_codes! {
+    IncorrectAccountNumber => "AC01", "Format of the account number specified is not correct";
+    InvalidDebtorAccountNumber => "AC02", "Debtor account number invalid or missing";
+    InvalidCreditorAccountNumber => "AC03", "Creditor account number invalid or missing";
+    ClosedAccountNumber => "AC04", "Account number specified has been closed on the bank of account's books";
+    ClosedDebtorAccountNumber => "AC05", "Debtor account number closed";
+    BlockedAccount => "AC06", "Account specified is blocked, prohibiting posting of transactions against it";
+    ClosedCreditorAccountNumber => "AC07", "Creditor account number closed";
+    InvalidDebtorAccountType => "AC13", "Debtor account type is missing or invalid";
+    InvalidAgent => "AC14", "An agent in the payment chain is invalid";
+    AccountDetailsChanged => "AC15", "Account details have changed";
+    CardNumberInvalid => "AC16", "Card number is invalid";
+    TransactionForbidden => "AG01", "Transaction forbidden on this type of account";
+    InvalidBankOperationCode => "AG02", "Bank operation code specified in the message is not valid for receiver";
+    TransactionNotSupported => "AG03", "Transaction type not supported or authorized on this account";
+    ZeroAmount => "AM01", "Specified message amount is equal to zero";
+    NotAllowedAmount => "AM02", "Specific transaction or message amount is greater than allowed maximum";
+    NotAllowedCurrency => "AM03", "Specified message amount is in a non processable currency outside of existing agreement";
+    InsufficientFunds => "AM04", "Amount of funds available to cover specified message amount is insufficient";
+    Duplication => "AM05", "Duplication";
+    TooLowAmount => "AM06", "Specified transaction amount is less than agreed minimum";
+    BlockedAmount => "AM07", "Amount specified in message has been blocked by regulatory authorities";
+    WrongAmount => "AM09", "Amount received is not the amount agreed or expected";
+    InvalidControlSum => "AM10", "Sum of instructed amounts does not equal the control sum";
+    InconsistentWithEndCustomer => "BE01", "Identification of end customer is not consistent with associated account number";
+    MissingCreditorAddress => "BE04", "Specification of creditor's address, which is required for payment, is missing or not correct";
+    UnrecognisedInitiatingParty => "BE05", "Party who initiated the message is not recognised by the end customer";
+    UnknownEndCustomer => "BE06", "End customer specified is not known at associated Sort/National Bank Code or does no longer exist in the books";
+    MissingDebtorAddress => "BE07", "Specification of debtor's address, which is required for payment, is missing or not correct";
+    RequestedByCustomer => "CUST", "Cancellation requested by the debtor";
+    InvalidDate => "DT01", "Invalid date (eg, wrong or missing settlement date)";
+    SettlementFailed => "ED05", "Settlement of the transaction has failed";
+    InvalidFileFormat => "FF01", "File format incomplete or invalid";
+    FollowingCancellationRequest => "FOCR", "Return following a cancellation request";
+    Fraud => "FR01", "Returned as a result of fraud";
+    NoMandate => "MD01", "No mandate";
+    RefundRequestByEndCustomer => "MD06", "Return of funds requested by end customer";
+    EndCustomerDeceased => "MD07", "End customer is deceased";
+    NotSpecifiedReasonCustomerGenerated => "MS02", "Reason has not been specified by end customer";
+    NotSpecifiedReasonAgentGenerated => "MS03", "Reason has not been specified by agent";
+    Narrative => "NARR", "Reason is provided as narrative information in the additional reason information";
+    NoAnswerFromCustomer => "NOAS", "No response from beneficiary";
+    NoOriginalTransactionReceived => "NOOR", "Original transaction (subject to cancellation) never received";
+    BankIdentifierIncorrect => "RC01", "Bank identifier code specified in the message has an incorrect format";
+    MissingDebtorAccountOrIdentification => "RR01", "Specification of the debtor's account or unique identification needed for reasons of regulatory requirements is insufficient or missing";
+    MissingDebtorNameOrAddress => "RR02", "Specification of the debtor's name and/or address needed for regulatory requirements is insufficient or missing";
+    MissingCreditorNameOrAddress => "RR03", "Specification of the creditor's name and/or address needed for regulatory requirements is insufficient or missing";
+    RegulatoryReason => "RR04", "Regulatory reason";
+    SpecificServiceOfferedByDebtorAgent => "SL01", "Due to specific service offered by the debtor agent";
+    CutOffTime => "TM01", "Associated message was received after agreed processing cut-off time";
+    UnduePayment => "UPAY", "Payment is not justified";
+}
+
+/// Reason decoded from a `/RETN/` or `/REJT/` code word of field 72
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field72ReturnReason {
+    /// "RETN" (return) or "REJT" (reject)
+    pub kind: String,
+    /// The reason code
+    pub reason: ReturnReasonCode,
+}
+
 /// **Field 72: Sender to Receiver Information**
 ///
 /// Additional information for receiver or other parties in financial messages,
@@ -23,6 +160,188 @@ pub struct Field72 {
     pub information: Vec<String>,
 }
 
+impl Field72 {
+    /// Code words in order of appearance; lines before the first code word are skipped
+    pub fn code_words(&self) -> Vec<Field72CodeWord> {
+        let mut words: Vec<Field72CodeWord> = Vec::new();
+        for line in &self.information {
+            if let Some(continuation) = line.strip_prefix("//") {
+                if let Some(word) = words.last_mut() {
+                    word.narrative.push(continuation.to_string());
+                }
+            } else if let Some((code, narrative)) =
+                line.strip_prefix('/').and_then(|rest| rest.split_once('/'))
+            {
+                words.push(Field72CodeWord {
+                    code: code.to_string(),
+                    narrative: vec![narrative.to_string()],
+                });
+            }
+        }
+        words
+    }
+
+    /// Build the field from lines, wrapping them as described in [`Field72::wrapped_lines`]
+    ///
+    /// Fails when the wrapped text exceeds the 6-line limit.
+    pub fn new(information: Vec<String>) -> crate::Result<Self> {
+        let field = Field72 { information };
+        field.check_line_limit()?;
+        Ok(Field72 {
+            information: field.wrapped_lines(),
+        })
+    }
+
+    /// Build the field from code words, wrapping each narrative as described in
+    /// [`Field72::wrapped_lines`]
+    ///
+    /// Fails when the wrapped text exceeds the 6-line limit.
+    pub fn from_code_words(words: &[Field72CodeWord]) -> crate::Result<Self> {
+        Self::new(
+            words
+                .iter()
+                .map(|word| format!("/{}/{}", word.code, word.narrative.concat()))
+                .collect(),
+        )
+    }
+
+    /// Lines split at 35 characters, the overflow of each going to `//` continuation lines
+    ///
+    /// The result may exceed the 6-line limit; `to_swift_string` writes the first 6 lines only.
+    pub fn wrapped_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for line in &self.information {
+            let mut rest: Vec<char> = line.chars().collect();
+            let mut width = 35;
+            let mut prefix = "";
+            loop {
+                let split = rest.len().min(width);
+                let tail = rest.split_off(split);
+                lines.push(format!("{prefix}{}", rest.iter().collect::<String>()));
+                if tail.is_empty() {
+                    break;
+                }
+                rest = tail;
+                prefix = "//";
+                width = 33;
+            }
+        }
+        lines
+    }
+
+    /// Fail when the wrapped lines exceed the 6-line limit
+    fn check_line_limit(&self) -> crate::Result<()> {
+        let lines = self.wrapped_lines().len();
+        if lines > 6 {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Field 72 needs {} lines after wrapping at 35 characters, maximum is 6",
+                    lines
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Decode the reason of the first `/RETN/` or `/REJT/` code word
+    ///
+    /// The reason is read from the start of the code word's narrative (`/RETN/AC04`) or,
+    /// per the reject/return guidelines, from the next code word (`/RETN/59` then `/AC04/`).
+    /// A known code is preferred; otherwise the first candidate is returned as `Other`.
+    pub fn return_reason(&self) -> Option<Field72ReturnReason> {
+        let words = self.code_words();
+        let position = words
+            .iter()
+            .position(|word| word.code == "RETN" || word.code == "REJT")?;
+        let word = &words[position];
+
+        let first_token = word.narrative[0]
+            .split(['/', ' '])
+            .next()
+            .unwrap_or_default();
+        let candidates: Vec<&str> = std::iter::once(first_token)
+            .chain(words.get(position + 1).map(|next| next.code.as_str()))
+            .filter(|code| code.len() == 4)
+            .collect();
+
+        let reason = candidates
+            .iter()
+            .map(|code| ReturnReasonCode::from_code(code))
+            .find(|reason| reason.description().is_some())
+            .or_else(|| {
+                candidates
+                    .first()
+                    .map(|code| ReturnReasonCode::from_code(code))
+            })?;
+
+        Some(Field72ReturnReason {
+            kind: word.code.clone(),
+            reason,
+        })
+    }
+
+    /// Validate code words against [`FIELD72_CODE_WORDS`] and the codes `allowed` in the message
+    ///
+    /// A known code that is not allowed, or whose narrative breaks its spec, is an error (T25,
+    /// T27 for a bad `/INS/` BIC). Unknown codes are kept and reported as warnings.
+    pub fn validate_code_words(&self, allowed: &[&str]) -> ValidationResult {
+        let mut result = ValidationResult::valid();
+        let mut error = |code: &str, value: &str, message: String| {
+            result.is_valid = false;
+            result.errors.push(
+                SwiftValidationError::format_error(code, "72", value, "/8c/[narrative]", &message)
+                    .into(),
+            );
+        };
+
+        for word in self.code_words() {
+            let narrative = word.narrative.concat();
+            let Some(&(_, max_narrative)) = FIELD72_CODE_WORDS
+                .iter()
+                .find(|(code, _)| *code == word.code)
+            else {
+                result
+                    .warnings
+                    .push(format!("Unknown code word /{}/ in field 72", word.code));
+                continue;
+            };
+
+            if !allowed.contains(&word.code.as_str()) {
+                error(
+                    t_series::T25,
+                    &word.code,
+                    format!(
+                        "Code word /{}/ is not allowed in field 72 of this message",
+                        word.code
+                    ),
+                );
+            } else if narrative.len() > max_narrative {
+                error(
+                    t_series::T25,
+                    &narrative,
+                    format!(
+                        "Narrative of code word /{}/ is {} characters, maximum is {}",
+                        word.code,
+                        narrative.len(),
+                        max_narrative
+                    ),
+                );
+            } else if word.code == "INS" && parse_bic(&narrative).is_err() {
+                error(
+                    t_series::T27,
+                    &narrative,
+                    format!(
+                        "Code word /INS/ must be followed by a BIC, found '{}'",
+                        narrative
+                    ),
+                );
+            }
+        }
+
+        result
+    }
+}
+
 impl SwiftField for Field72 {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -40,8 +359,15 @@ impl SwiftField for Field72 {
         Ok(Field72 { information })
     }
 
+    /// Wrapped lines, cut off after the sixth
     fn to_swift_string(&self) -> String {
-        format!(":72:{}", self.information.join("\n"))
+        let mut lines = self.wrapped_lines();
+        lines.truncate(6);
+        format!(":72:{}", lines.join("\n"))
+    }
+
+    fn check_serialization(&self) -> crate::Result<()> {
+        self.check_line_limit()
     }
 }
 
@@ -49,6 +375,66 @@ impl SwiftField for Field72 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_field72_return_reason() {
+        let field = Field72::parse("/RETN/AC04").unwrap();
+        let reason = field.return_reason().unwrap();
+        assert_eq!(reason.kind, "RETN");
+        assert_eq!(reason.reason, ReturnReasonCode::ClosedAccountNumber);
+        assert_eq!(reason.reason.code(), "AC04");
+        assert_eq!(
+            reason.reason.description(),
+            Some("Account number specified has been closed on the bank of account's books")
+        );
+
+        // Guideline layout: field reference first, reason as the next code word
+        let field = Field72::parse("/REJT/59\n/AM05/\n/MREF/REF123").unwrap();
+        let reason = field.return_reason().unwrap();
+        assert_eq!(reason.kind, "REJT");
+        assert_eq!(reason.reason, ReturnReasonCode::Duplication);
+
+        let field = Field72::parse("/RETN/XY99").unwrap();
+        assert_eq!(
+            field.return_reason().unwrap().reason,
+            ReturnReasonCode::Other("XY99".to_string())
+        );
+        assert!(
+            Field72::parse("/ACC/INFO")
+                .unwrap()
+                .return_reason()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_field72_code_words() {
+        let all: Vec<&str> = FIELD72_CODE_WORDS.iter().map(|(code, _)| *code).collect();
+
+        let field = Field72::parse("/ACC/RELEASE UPON RECEIPT\n//OF COVER\n/INS/DEUTDEFF").unwrap();
+        let words = field.code_words();
+        assert_eq!(words[0].code, "ACC");
+        assert_eq!(words[0].narrative, vec!["RELEASE UPON RECEIPT", "OF COVER"]);
+        assert_eq!(words[1].code, "INS");
+        let result = field.validate_code_words(&all);
+        assert!(result.is_valid && result.warnings.is_empty());
+
+        // Phone instructions allow a single line of narrative
+        let field = Field72::parse("/PHON/CALL BEFORE PAYMENT\n//MR SMITH EXT 1234").unwrap();
+        let result = field.validate_code_words(&all);
+        assert!(!result.is_valid);
+        assert!(result.errors[0].to_string().contains("maximum is 30"));
+
+        // Known but not allowed in the message
+        assert!(!field.validate_code_words(&["ACC"]).is_valid);
+
+        // Unknown code words are kept and flagged
+        let field = Field72::parse("/XYZ/SOMETHING\n/INS/NOTABIC").unwrap();
+        let result = field.validate_code_words(&all);
+        assert_eq!(result.warnings, vec!["Unknown code word /XYZ/ in field 72"]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(field.to_swift_string(), ":72:/XYZ/SOMETHING\n/INS/NOTABIC");
+    }
+
     #[test]
     fn test_field72_single_line() {
         let field = Field72::parse("/BNF/BENEFICIARY DETAILS").unwrap();
@@ -106,4 +492,73 @@ mod tests {
         let too_long = "A".repeat(36);
         assert!(Field72::parse(&too_long).is_err());
     }
+
+    #[test]
+    fn test_field72_wrapping() {
+        let words = [
+            Field72CodeWord {
+                code: "ACC".to_string(),
+                narrative: vec!["CREDIT ACCOUNT 1234567890 OF THE BENEFICIARY TODAY".to_string()],
+            },
+            Field72CodeWord {
+                code: "INS".to_string(),
+                narrative: vec!["BANKDEFFXXX".to_string()],
+            },
+        ];
+        let field = Field72::from_code_words(&words).unwrap();
+        assert_eq!(
+            field.information,
+            vec![
+                "/ACC/CREDIT ACCOUNT 1234567890 OF T",
+                "//HE BENEFICIARY TODAY",
+                "/INS/BANKDEFFXXX",
+            ]
+        );
+        assert_eq!(
+            field.code_words()[0].narrative.concat(),
+            words[0].narrative[0]
+        );
+        assert_eq!(
+            Field72::parse(&field.to_swift_string()[4..]).unwrap(),
+            field
+        );
+
+        // Over-long lines set directly are wrapped on serialization
+        let field = Field72 {
+            information: vec![format!("/BNF/{}", "X".repeat(40))],
+        };
+        assert_eq!(
+            field.to_swift_string(),
+            format!(":72:/BNF/{}\n//{}", "X".repeat(30), "X".repeat(10))
+        );
+
+        // Free text overflows onto a `//` line as well
+        let field = Field72 {
+            information: vec!["Y".repeat(40)],
+        };
+        assert_eq!(
+            field.to_swift_string(),
+            format!(":72:{}\n//{}", "Y".repeat(35), "Y".repeat(5))
+        );
+
+        let too_long = Field72CodeWord {
+            code: "ACC".to_string(),
+            narrative: vec!["X".repeat(196)],
+        };
+        assert!(Field72::from_code_words(&[too_long]).is_err());
+        assert!(Field72::new(vec!["Z".repeat(36); 4]).is_err());
+    }
+
+    #[test]
+    fn test_field72_serialization_keeps_line_limit() {
+        let field = Field72 {
+            information: vec![format!("/ACC/{}", "RELEASE UPON RECEIPT OF COVER ".repeat(2)); 4],
+        };
+        assert_eq!(field.wrapped_lines().len(), 8);
+        assert!(field.check_serialization().is_err());
+
+        let serialized = field.to_swift_string();
+        assert_eq!(serialized.lines().count(), 6);
+        assert!(Field72::parse(&serialized[4..]).is_ok());
+    }
 }
@@ -45,6 +45,94 @@ impl SwiftField for Field77T {
     }
 }
 
+/// **Field 77E: Proprietary Message**
+///
+/// Envelope for proprietary content (e.g. MT798), kept as raw text so any embedded
+/// sub-message structure survives a round trip unchanged.
+///
+/// **Format:** `73z[$n*78z]` (first line max 73 characters, further lines max 78)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field77E {
+    /// Envelope content, including embedded line breaks
+    pub envelope_content: String,
+}
+
+impl SwiftField for Field77E {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        if input.is_empty() {
+            return Err(ParseError::InvalidFormat {
+                message: "Field 77E cannot be empty".to_string(),
+            });
+        }
+
+        for (index, line) in input.lines().enumerate() {
+            let max = if index == 0 { 73 } else { 78 };
+            let length = line.chars().count();
+            if length > max {
+                return Err(ParseError::InvalidFormat {
+                    message: format!(
+                        "Field 77E line {} exceeds {} characters, found {}",
+                        index + 1,
+                        max,
+                        length
+                    ),
+                });
+            }
+        }
+
+        Ok(Field77E {
+            envelope_content: input.to_string(),
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        format!(":77E:{}", self.envelope_content)
+    }
+}
+
+/// **Field 77F: Envelope Contents**
+///
+/// Free-form envelope for proprietary or trade message content, kept as raw text.
+///
+/// **Format:** `9000z` (max 9000 characters, any character including spaces/newlines)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Field77F {
+    /// Envelope content, including embedded line breaks
+    pub envelope_content: String,
+}
+
+impl SwiftField for Field77F {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        if input.is_empty() {
+            return Err(ParseError::InvalidFormat {
+                message: "Field 77F cannot be empty".to_string(),
+            });
+        }
+
+        if input.len() > 9000 {
+            return Err(ParseError::InvalidFormat {
+                message: format!("Field 77F exceeds 9000 characters, found {}", input.len()),
+            });
+        }
+
+        Ok(Field77F {
+            envelope_content: input.to_string(),
+        })
+    }
+
+    fn to_swift_string(&self) -> String {
+        format!(":77F:{}", self.envelope_content)
+    }
+}
+
 /// **Field 77A: Extended Narrative**
 ///
 /// Extended narrative information for detailed documentation.
@@ -72,6 +160,17 @@ impl SwiftField for Field77A {
     }
 }
 
+/// A `/CODE/CC//narrative` regulatory reporting entry of field 77B
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Field77BEntry {
+    /// Code word without slashes (e.g. "ORDERRES", "BENEFRES")
+    pub code: String,
+    /// ISO country code following the code word, if present
+    pub country: Option<String>,
+    /// Narrative after `//` followed by each continuation line
+    pub narrative: Vec<String>,
+}
+
 /// **Field 77B: Short Narrative**
 ///
 /// Short narrative information for concise documentation.
@@ -84,6 +183,34 @@ pub struct Field77B {
     pub narrative: Vec<String>,
 }
 
+impl Field77B {
+    /// Regulatory reporting entries in order of appearance; free text before the first
+    /// code word is skipped
+    pub fn entries(&self) -> Vec<Field77BEntry> {
+        let mut entries: Vec<Field77BEntry> = Vec::new();
+        for line in &self.narrative {
+            if let Some(continuation) = line.strip_prefix("//") {
+                if let Some(entry) = entries.last_mut() {
+                    entry.narrative.push(continuation.to_string());
+                }
+            } else if let Some((code, rest)) =
+                line.strip_prefix('/').and_then(|rest| rest.split_once('/'))
+            {
+                let (country, narrative) = match rest.split_once("//") {
+                    Some((country, narrative)) => (country, vec![narrative.to_string()]),
+                    None => (rest, Vec::new()),
+                };
+                entries.push(Field77BEntry {
+                    code: code.to_string(),
+                    country: (!country.is_empty()).then(|| country.to_string()),
+                    narrative,
+                });
+            }
+        }
+        entries
+    }
+}
+
 impl SwiftField for Field77B {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -126,6 +253,25 @@ mod tests {
         assert!(Field77T::parse(&content).is_err());
     }
 
+    #[test]
+    fn test_field77e_round_trip() {
+        let content = "TRADE ENVELOPE\n:27A:1/2\n:21A:TRADE REF 001\n:21P:CUSTREF\n:77J:CONTENT WITH : AND / KEPT AS IS";
+        let field = Field77E::parse(content).unwrap();
+        assert_eq!(field.envelope_content, content);
+        assert_eq!(field.to_swift_string(), format!(":77E:{}", content));
+        assert_eq!(
+            Field77E::parse(&field.to_swift_string()[5..]).unwrap(),
+            field
+        );
+
+        assert!(Field77E::parse("").is_err());
+        assert!(Field77E::parse(&"X".repeat(74)).is_err());
+        assert!(Field77E::parse(&format!("FIRST\n{}", "X".repeat(78))).is_ok());
+
+        let field = Field77F::parse("LINE ONE\nLINE TWO").unwrap();
+        assert_eq!(field.to_swift_string(), ":77F:LINE ONE\nLINE TWO");
+    }
+
     #[test]
     fn test_field77a_valid() {
         let field = Field77A::parse("LINE 1\nLINE 2\nLINE 3").unwrap();
@@ -191,4 +337,24 @@ mod tests {
             Field77B::parse("THIS LINE IS TOO LONG AND EXCEEDS THE 35 CHARACTER LIMIT").is_err()
         );
     }
+
+    #[test]
+    fn test_field77b_entries() {
+        let field = Field77B::parse(
+            "/ORDERRES/BE//MEILAAN 1
+//9000 GENT
+/BENEFRES/US",
+        )
+        .unwrap();
+        let entries = field.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].code, "ORDERRES");
+        assert_eq!(entries[0].country.as_deref(), Some("BE"));
+        assert_eq!(entries[0].narrative, vec!["MEILAAN 1", "9000 GENT"]);
+        assert_eq!(entries[1].code, "BENEFRES");
+        assert_eq!(entries[1].country.as_deref(), Some("US"));
+        assert!(entries[1].narrative.is_empty());
+
+        assert!(Field77B::parse("FREE TEXT").unwrap().entries().is_empty());
+    }
 }
@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 /// Extended narrative information for detailed transaction descriptions and explanations.
 ///
 /// **Format:** `35*50x` (max 35 lines, 50 chars each, total 1750 chars)
-/// **Used in:** MT 199, 299 (free format messages), MT 196, 296 (answer messages)
+/// **Used in:** MT 199, 299 (free format messages), MT 196, 296 (answer messages),
+/// MT 192, 292 (cancellation requests, may carry a copy of the original message fields)
 ///
 /// **Example:**
 /// ```text
@@ -25,6 +26,44 @@ pub struct Field79 {
     pub information: Vec<String>,
 }
 
+impl Field79 {
+    /// Append lines (e.g. fields copied from an n92 original), keeping the `35*50x` limits
+    pub fn extend_lines(&mut self, lines: Vec<String>) -> crate::Result<()> {
+        let mut combined = self.information.clone();
+        combined.extend(lines);
+        *self = Field79::parse(&combined.join("\n"))?;
+        Ok(())
+    }
+
+    /// Extract `:TAG:value` lines copied from an original message (n92 cancellations)
+    ///
+    /// Narrative lines before the first copied field are skipped; continuation lines
+    /// are joined to the preceding field. Returns `None` when no copied fields are found.
+    pub fn as_copied_fields(&self) -> Option<Vec<(String, String)>> {
+        let start = self
+            .information
+            .iter()
+            .position(|line| is_copied_field_line(line))?;
+        let content = self.information[start..].join("\n");
+        let fields = crate::parser::extract_all_fields(&content);
+        (!fields.is_empty()).then_some(fields)
+    }
+}
+
+/// Check whether a line starts with a field tag such as `:20:` or `:32A:`
+fn is_copied_field_line(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix(':') else {
+        return false;
+    };
+    let Some(close) = rest.find(':') else {
+        return false;
+    };
+    let tag = &rest.as_bytes()[..close];
+    matches!(tag.len(), 2 | 3)
+        && tag[..2].iter().all(u8::is_ascii_digit)
+        && tag[2..].iter().all(u8::is_ascii_uppercase)
+}
+
 impl SwiftField for Field79 {
     fn parse(input: &str) -> crate::Result<Self>
     where
@@ -33,7 +72,14 @@ impl SwiftField for Field79 {
         let mut lines = Vec::new();
 
         // Parse up to 35 lines of 50 characters each
-        for line in input.lines().take(35) {
+        let line_count = input.lines().count();
+        if line_count > 35 {
+            return Err(ParseError::InvalidFormat {
+                message: format!("Field 79 exceeds 35 lines, found {}", line_count),
+            });
+        }
+
+        for line in input.lines() {
             // Validate line length (max 50 characters)
             if line.len() > 50 {
                 return Err(ParseError::InvalidFormat {
@@ -127,6 +173,25 @@ mod tests {
         assert_eq!(field.to_swift_string(), ":79:SINGLE LINE NARRATIVE");
     }
 
+    #[test]
+    fn test_field79_as_copied_fields() {
+        let field =
+            Field79::parse("/DUPL/\n:20:ORIGREF123\n:32A:250115EUR1000,00\n:50K:JOHN DOE\nBERLIN")
+                .unwrap();
+        let copied = field.as_copied_fields().unwrap();
+        assert_eq!(
+            copied,
+            vec![
+                ("20".to_string(), "ORIGREF123".to_string()),
+                ("32A".to_string(), "250115EUR1000,00".to_string()),
+                ("50K".to_string(), "JOHN DOE\nBERLIN".to_string()),
+            ]
+        );
+
+        let narrative = Field79::parse("PLEASE CANCEL: DUPLICATE").unwrap();
+        assert!(narrative.as_copied_fields().is_none());
+    }
+
     #[test]
     fn test_field79_max_lines() {
         let mut lines = Vec::new();
@@ -138,5 +203,12 @@ mod tests {
         assert_eq!(field.information.len(), 35);
         assert_eq!(field.information[0], "LINE 1");
         assert_eq!(field.information[34], "LINE 35");
+
+        lines.push("LINE 36".to_string());
+        assert!(Field79::parse(&lines.join("\n")).is_err());
+
+        let mut field = Field79::parse("LINE 1").unwrap();
+        assert!(field.extend_lines(lines[1..35].to_vec()).is_ok());
+        assert!(field.extend_lines(vec!["LINE 36".to_string()]).is_err());
     }
 }
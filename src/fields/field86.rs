@@ -1,7 +1,10 @@
 use super::swift_utils::parse_swift_chars;
-use crate::errors::ParseError;
+use crate::ValidationResult;
+use crate::errors::{ParseError, SwiftValidationError};
+use crate::swift_error_codes::t_series;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// **Field 86: Information to Account Owner**
 ///
@@ -17,6 +20,10 @@ use serde::{Deserialize, Serialize};
 /// FROM: INTERNATIONAL BANK
 /// PURPOSE: TRADE SETTLEMENT
 /// ```
+///
+/// Statements following the German convention carry a three-digit transaction code and
+/// `?NN` subfields instead, e.g. `166?00GUTSCHRIFT?20SVWZ+INVOICE 4711`. See
+/// [`Field86::subfields`]; the raw lines stay authoritative for serialization.
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
@@ -32,8 +39,18 @@ impl SwiftField for Field86 {
     {
         let mut lines = Vec::new();
 
+        if input.lines().count() > Self::MAX_LINES {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Field 86 exceeds {} lines: {}",
+                    Self::MAX_LINES,
+                    input.lines().count()
+                ),
+            });
+        }
+
         // Parse up to 6 lines of 65 characters each
-        for line in input.lines().take(6) {
+        for line in input.lines() {
             // Validate line length (max 65 characters)
             if line.len() > 65 {
                 return Err(ParseError::InvalidFormat {
@@ -62,6 +79,92 @@ impl SwiftField for Field86 {
     }
 }
 
+impl Field86 {
+    /// Maximum number of narrative lines
+    pub const MAX_LINES: usize = 6;
+
+    /// Maximum characters per narrative line
+    pub const MAX_LINE_LENGTH: usize = 65;
+
+    /// Check the `6*65x` line count and line lengths of a narrative built in code
+    pub fn validate(&self) -> ValidationResult {
+        let mut result = ValidationResult::valid();
+        let mut error = |code: &str, value: &str, message: String| {
+            result.is_valid = false;
+            result.errors.push(
+                SwiftValidationError::format_error(code, "86", value, "6*65x", &message).into(),
+            );
+        };
+
+        if self.narrative.is_empty() || self.narrative.len() > Self::MAX_LINES {
+            error(
+                t_series::T11,
+                &self.narrative.len().to_string(),
+                format!(
+                    "Field 86 must have 1 to {} lines, found {}",
+                    Self::MAX_LINES,
+                    self.narrative.len()
+                ),
+            );
+        }
+        for (index, line) in self.narrative.iter().enumerate() {
+            if line.chars().count() > Self::MAX_LINE_LENGTH {
+                error(
+                    t_series::T25,
+                    line,
+                    format!(
+                        "Field 86 line {} exceeds {} characters",
+                        index + 1,
+                        Self::MAX_LINE_LENGTH
+                    ),
+                );
+            }
+        }
+        result
+    }
+
+    /// Three-digit transaction code leading a structured narrative (e.g. "166")
+    pub fn transaction_code(&self) -> Option<&str> {
+        let first = self.narrative.first()?;
+        let code = first.get(..3)?;
+        (code.bytes().all(|b| b.is_ascii_digit()) && first[3..].starts_with('?')).then_some(code)
+    }
+
+    /// Structured `?NN` subfields keyed by their two-digit number
+    ///
+    /// Lines are joined before splitting, since subfields may wrap across lines. A
+    /// repeated key has its values concatenated; a `?` not followed by two digits is
+    /// kept as text. Returns `None` for free-text narratives.
+    pub fn subfields(&self) -> Option<BTreeMap<String, String>> {
+        self.transaction_code()?;
+        let joined = self.narrative.concat();
+
+        let mut subfields: BTreeMap<String, String> = BTreeMap::new();
+        let mut current: Option<String> = None;
+        for part in joined[4..].split('?') {
+            let key = part
+                .get(..2)
+                .filter(|k| k.bytes().all(|b| b.is_ascii_digit()));
+            match (key, &current) {
+                (Some(key), _) => {
+                    subfields
+                        .entry(key.to_string())
+                        .or_default()
+                        .push_str(&part[2..]);
+                    current = Some(key.to_string());
+                }
+                (None, Some(key)) => {
+                    let value = subfields.entry(key.clone()).or_default();
+                    value.push('?');
+                    value.push_str(part);
+                }
+                (None, None) => return None,
+            }
+        }
+        Some(subfields)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +213,40 @@ mod tests {
         assert_eq!(field.narrative[5], "LINE 6");
     }
 
+    #[test]
+    fn test_field86_structured_subfields() {
+        let input =
+            "166?00GUTSCHRIFT?100599?20SVWZ+RE 2024-001 UND?21\n RE 2024-002?32ACME\nGMBH?34997";
+        let field = Field86::parse(input).unwrap();
+        assert_eq!(field.transaction_code(), Some("166"));
+
+        let subfields = field.subfields().unwrap();
+        assert_eq!(subfields["00"], "GUTSCHRIFT");
+        assert_eq!(subfields["10"], "0599");
+        assert_eq!(subfields["20"], "SVWZ+RE 2024-001 UND");
+        assert_eq!(subfields["21"], " RE 2024-002");
+        assert_eq!(subfields["32"], "ACMEGMBH");
+        assert_eq!(subfields["34"], "997");
+
+        assert_eq!(field.to_swift_string(), format!(":86:{input}"));
+        assert!(field.validate().is_valid);
+
+        let free_text = Field86::parse("PAYMENT RECEIVED? MAYBE").unwrap();
+        assert!(free_text.subfields().is_none());
+    }
+
+    #[test]
+    fn test_field86_line_count() {
+        assert!(Field86::parse("1\n2\n3\n4\n5\n6\n7").is_err());
+
+        let field = Field86 {
+            narrative: vec!["X".repeat(66); 7],
+        };
+        let result = field.validate();
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 8);
+    }
+
     #[test]
     fn test_field86_empty_input() {
         assert!(Field86::parse("").is_err());
@@ -9,7 +9,7 @@
 //! **Format:** `5n3!a15d` (number, currency, amount)
 //! **Used in:** MT 940, MT 942 (statement messages)
 
-use super::swift_utils::{parse_amount, parse_currency, parse_swift_digits};
+use super::swift_utils::{format_swift_amount, parse_amount, parse_currency, parse_swift_digits};
 use crate::errors::ParseError;
 use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
@@ -112,7 +112,7 @@ impl SwiftField for Field90D {
     }
 
     fn to_swift_string(&self) -> String {
-        let amount_str = format!("{:.2}", self.amount).replace('.', ",");
+        let amount_str = format_swift_amount(self.amount, 2);
         format!(":90D:{}{}{}", self.number, self.currency, amount_str)
     }
 }
@@ -215,7 +215,7 @@ impl SwiftField for Field90C {
     }
 
     fn to_swift_string(&self) -> String {
-        let amount_str = format!("{:.2}", self.amount).replace('.', ",");
+        let amount_str = format_swift_amount(self.amount, 2);
         format!(":90C:{}{}{}", self.number, self.currency, amount_str)
     }
 }
@@ -7,6 +7,34 @@
 use super::swift_utils::parse_swift_chars;
 use crate::errors::ParseError;
 
+/// How an institution is identified in one of fields 51-58
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstitutionRef {
+    /// Option A: BIC
+    Bic(String),
+    /// Option B: location, when given
+    Location(String),
+    /// Option C, or option B without location: account or clearing code
+    PartyIdentifier(String),
+    /// Option D: name and address lines
+    NameAndAddress(Vec<String>),
+}
+
+impl InstitutionRef {
+    /// Option B falls back to its party identifier when no location is given
+    pub(crate) fn from_location(
+        location: &Option<String>,
+        party_identifier: &Option<String>,
+    ) -> Self {
+        match (location, party_identifier) {
+            (Some(location), _) => InstitutionRef::Location(location.clone()),
+            (None, identifier) => {
+                InstitutionRef::PartyIdentifier(identifier.clone().unwrap_or_default())
+            }
+        }
+    }
+}
+
 /// Payment method codes used in Field 57 and similar fields
 #[derive(Debug, Clone, PartialEq)]
 pub enum PaymentMethodCode {
@@ -266,6 +294,76 @@ pub fn extract_field_number(field_tag: &str) -> String {
         .collect()
 }
 
+/// Optional leading account line of a party or institution field: `[/1!a]/34x`
+///
+/// `indicator` holds the C/D mark of `/C/12345`. Fields store the line without its leading
+/// slash (see [`AccountLine::identifier`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountLine {
+    /// Credit/debit indicator ('C' or 'D'), when present
+    pub indicator: Option<char>,
+    /// Account number (1-34 characters)
+    pub account: String,
+}
+
+impl AccountLine {
+    /// Parse `line` as an account line; `Ok(None)` when it does not start with '/'
+    ///
+    /// `context` names the field component in error messages.
+    pub fn parse(line: &str, context: &str) -> Result<Option<Self>, ParseError> {
+        let Some(rest) = line.strip_prefix('/') else {
+            return Ok(None);
+        };
+        let (indicator, account) = match rest.split_once('/') {
+            Some((mark @ ("C" | "D"), account)) => (mark.chars().next(), account),
+            _ => (None, rest),
+        };
+
+        if account.is_empty() || account.len() > 34 {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "{} must be 1-34 characters, found {}",
+                    context,
+                    account.len()
+                ),
+            });
+        }
+        parse_swift_chars(account, context)?;
+
+        Ok(Some(AccountLine {
+            indicator,
+            account: account.to_string(),
+        }))
+    }
+
+    /// Rebuild from the form stored on fields (`C/12345` or `12345`)
+    pub fn from_identifier(identifier: &str) -> Self {
+        match identifier.split_once('/') {
+            Some((mark @ ("C" | "D"), account)) => AccountLine {
+                indicator: mark.chars().next(),
+                account: account.to_string(),
+            },
+            _ => AccountLine {
+                indicator: None,
+                account: identifier.to_string(),
+            },
+        }
+    }
+
+    /// The line without its leading slash, as stored on fields
+    pub fn identifier(&self) -> String {
+        match self.indicator {
+            Some(mark) => format!("{}/{}", mark, self.account),
+            None => self.account.clone(),
+        }
+    }
+
+    /// The line as it appears in the message, with its leading slash
+    pub fn to_line(&self) -> String {
+        format!("/{}", self.identifier())
+    }
+}
+
 /// Parse party identifier in format /1!a/34x, /2!a/34x, //XX, or /34x
 /// Used in fields 51-59 for institutional and party identification
 pub fn parse_party_identifier(input: &str) -> Result<Option<String>, ParseError> {
@@ -286,28 +384,11 @@ pub fn parse_party_identifier(input: &str) -> Result<Option<String>, ParseError>
         });
     }
 
-    // Check for /code/identifier format
-    if let Some(slash_pos) = remaining.find('/') {
-        let code = &remaining[..slash_pos];
-        let id = &remaining[slash_pos + 1..];
-
-        // Handle /1!a/34x format (single alphabetic character)
-        if code.len() == 1 && code.chars().all(|c| c.is_ascii_alphabetic()) {
-            if id.len() > 34 {
-                return Err(ParseError::InvalidFormat {
-                    message: format!("Party identifier exceeds 34 characters: {}", id.len()),
-                });
-            }
-            parse_swift_chars(id, "party identifier")?;
-            return Ok(Some(format!("{}/{}", code, id)));
-        }
-
-        // Handle /2!a/34x format (e.g., /CH/, /FW/, /CP/)
-        if (1..=2).contains(&code.len())
-            && code
-                .chars()
-                .all(|c| c.is_ascii_alphabetic() || c.is_ascii_digit())
-        {
+    // Handle /1!a/34x and /2!a/34x codes other than the C/D account-line mark (e.g. /CH/)
+    if let Some((code, id)) = remaining.split_once('/')
+        && !matches!(code, "C" | "D")
+    {
+        if (1..=2).contains(&code.len()) && code.chars().all(|c| c.is_ascii_alphanumeric()) {
             if id.len() > 34 {
                 return Err(ParseError::InvalidFormat {
                     message: format!("Party identifier exceeds 34 characters: {}", id.len()),
@@ -316,15 +397,13 @@ pub fn parse_party_identifier(input: &str) -> Result<Option<String>, ParseError>
             parse_swift_chars(id, "party identifier")?;
             return Ok(Some(format!("{}/{}", code, id)));
         }
-    } else if remaining.len() <= 34 {
-        // Simple /34x format (no additional slash)
-        parse_swift_chars(remaining, "party identifier")?;
-        return Ok(Some(remaining.to_string()));
+        return Err(ParseError::InvalidFormat {
+            message: format!("Invalid party identifier format: {}", input),
+        });
     }
 
-    Err(ParseError::InvalidFormat {
-        message: format!("Invalid party identifier format: {}", input),
-    })
+    // [/1!a]/34x account line
+    Ok(AccountLine::parse(input, "party identifier")?.map(|line| line.identifier()))
 }
 
 /// Parse and validate debit/credit mark (D or C)
@@ -594,6 +673,24 @@ mod tests {
         assert!(parse_party_identifier("/D/12345678901234567890123456789012345").is_err());
     }
 
+    #[test]
+    fn test_account_line() {
+        let line = AccountLine::parse("/12345", "account").unwrap().unwrap();
+        assert_eq!(line.indicator, None);
+        assert_eq!(line.account, "12345");
+        assert_eq!(line.to_line(), "/12345");
+
+        let line = AccountLine::parse("/C/12345", "account").unwrap().unwrap();
+        assert_eq!(line.indicator, Some('C'));
+        assert_eq!(line.account, "12345");
+        assert_eq!(line.identifier(), "C/12345");
+        assert_eq!(AccountLine::from_identifier("C/12345"), line);
+
+        assert_eq!(AccountLine::parse("ACME CORP", "account").unwrap(), None);
+        assert!(AccountLine::parse("/", "account").is_err());
+        assert!(AccountLine::parse(&format!("/D/{}", "1".repeat(35)), "account").is_err());
+    }
+
     #[test]
     fn test_parse_debit_credit_mark() {
         assert_eq!(parse_debit_credit_mark('D').unwrap(), "D");
@@ -4,8 +4,185 @@
 //! These utilities handle basic SWIFT data formats like BIC codes, currency codes,
 //! dates, amounts, and character validation.
 
-use crate::errors::ParseError;
+use crate::errors::{MissingMandatoryPolicy, ParseError, ParserConfig, SwiftValidationError};
+use crate::swift_error_codes::t_series;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::cell::{Cell, RefCell};
+
+/// Default pivot for two-digit years: `00`-`49` map to 20YY, `50`-`99` to 19YY
+pub const DEFAULT_DATE_PIVOT_YEAR: u8 = 50;
+
+/// Default cap on field 61 statement lines in a single MT940/MT942/MT950
+pub const DEFAULT_MAX_STATEMENT_LINES: usize = 10_000;
+
+/// Per-thread field parsing options taken from the active `ParserConfig`
+#[derive(Debug, Clone, Copy)]
+struct FieldParseOptions {
+    retain_raw_fields: bool,
+    date_pivot_year: u8,
+    trim_fixed_fields: bool,
+    max_statement_lines: usize,
+    warn_missing_mandatory: bool,
+    strip_thousands_separators: bool,
+}
+
+thread_local! {
+    static FIELD_PARSE_OPTIONS: Cell<FieldParseOptions> = const {
+        Cell::new(FieldParseOptions {
+            retain_raw_fields: false,
+            date_pivot_year: DEFAULT_DATE_PIVOT_YEAR,
+            trim_fixed_fields: false,
+            max_statement_lines: DEFAULT_MAX_STATEMENT_LINES,
+            warn_missing_mandatory: false,
+            strip_thousands_separators: false,
+        })
+    };
+    static MISSING_MANDATORY: RefCell<Option<Vec<ParseError>>> = const { RefCell::new(None) };
+}
+
+/// Puts the previous options back when dropped, so a panicking parse cannot leak its config
+struct RestoreFieldParseOptions(FieldParseOptions);
+
+impl Drop for RestoreFieldParseOptions {
+    fn drop(&mut self) {
+        FIELD_PARSE_OPTIONS.with(|cell| cell.set(self.0));
+    }
+}
+
+/// Run `f` with the field-level options of `config` applied to the current thread
+pub(crate) fn with_field_parse_options<R>(config: &ParserConfig, f: impl FnOnce() -> R) -> R {
+    let options = FieldParseOptions {
+        retain_raw_fields: config.retain_raw_fields,
+        date_pivot_year: config.date_pivot_year,
+        trim_fixed_fields: config.trim_fixed_fields,
+        max_statement_lines: config.max_statement_lines,
+        warn_missing_mandatory: config.missing_mandatory_policy == MissingMandatoryPolicy::Warn,
+        strip_thousands_separators: config.strip_thousands_separators,
+    };
+    let _restore = RestoreFieldParseOptions(FIELD_PARSE_OPTIONS.with(|cell| cell.replace(options)));
+    f()
+}
+
+/// Original field text kept under `ParserConfig::retain_raw_fields`, with the values it parsed to
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct RetainedRaw<T> {
+    text: String,
+    parsed: T,
+}
+
+impl<T: PartialEq> RetainedRaw<T> {
+    /// The original text, as long as the field still holds the values parsed from it
+    pub(crate) fn get(&self, current: &T) -> Option<&str> {
+        (self.parsed == *current).then_some(self.text.as_str())
+    }
+}
+
+/// Copy of the original field text when raw retention is enabled, `None` otherwise
+pub(crate) fn retained_raw<T>(input: &str, parsed: T) -> Option<RetainedRaw<T>> {
+    FIELD_PARSE_OPTIONS
+        .with(Cell::get)
+        .retain_raw_fields
+        .then(|| RetainedRaw {
+            text: input.to_string(),
+            parsed,
+        })
+}
+
+/// Whether missing mandatory fields are parsed empty and recorded instead of aborting
+///
+/// Only while [`collect_missing_mandatory`] runs, so an omission is never left unreported.
+pub(crate) fn warn_missing_mandatory() -> bool {
+    FIELD_PARSE_OPTIONS.with(Cell::get).warn_missing_mandatory
+        && MISSING_MANDATORY.with(|missing| missing.borrow().is_some())
+}
+
+/// Record a mandatory field parsed empty under `MissingMandatoryPolicy::Warn`
+pub(crate) fn record_missing_mandatory(error: ParseError) {
+    MISSING_MANDATORY.with(|missing| {
+        if let Some(missing) = missing.borrow_mut().as_mut() {
+            missing.push(error);
+        }
+    });
+}
+
+/// Reinstates the enclosing collection when dropped, so a panicking parse cannot leak records
+struct RestoreMissingMandatory(Option<Vec<ParseError>>);
+
+impl Drop for RestoreMissingMandatory {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        MISSING_MANDATORY.with(|missing| *missing.borrow_mut() = previous);
+    }
+}
+
+/// Run `f` and return the mandatory fields it recorded as missing
+pub(crate) fn collect_missing_mandatory<R>(f: impl FnOnce() -> R) -> (R, Vec<ParseError>) {
+    let _restore = RestoreMissingMandatory(
+        MISSING_MANDATORY.with(|missing| missing.replace(Some(Vec::new()))),
+    );
+    let result = f();
+    let recorded = MISSING_MANDATORY.with(|missing| missing.borrow_mut().take());
+    (result, recorded.unwrap_or_default())
+}
+
+/// Active cap on field 61 statement lines per message
+pub(crate) fn max_statement_lines() -> usize {
+    FIELD_PARSE_OPTIONS.with(Cell::get).max_statement_lines
+}
+
+/// Error for a statement exceeding `ParserConfig::max_statement_lines`
+pub(crate) fn too_many_statement_lines(message_type: &str, max: usize) -> ParseError {
+    ParseError::InvalidFormat {
+        message: format!(
+            "MT{}: more than {} statement lines (field 61); raise ParserConfig::max_statement_lines",
+            message_type, max
+        ),
+    }
+}
+
+/// Expand a two-digit year using the active pivot
+///
+/// Years below the pivot fall in the 2000s, the rest in the 1900s.
+pub fn expand_two_digit_year(year: u32) -> i32 {
+    let pivot = FIELD_PARSE_OPTIONS.with(Cell::get).date_pivot_year as u32;
+    if year < pivot {
+        2000 + year as i32
+    } else {
+        1900 + year as i32
+    }
+}
+
+/// Format error carrying its official SWIFT code; `component` names the failing part (e.g. "date")
+fn coded_error(
+    code: &str,
+    component: &str,
+    value: &str,
+    expected: &str,
+    message: String,
+) -> ParseError {
+    ParseError::SwiftValidation(Box::new(SwiftValidationError::format_error(
+        code, component, value, expected, &message,
+    )))
+}
+
+/// Strip trailing space padding from a fixed-length component
+///
+/// Padding is trimmed when `ParserConfig::trim_fixed_fields` is set and rejected otherwise.
+fn unpadded<'a>(input: &'a str, field_name: &str) -> Result<&'a str, ParseError> {
+    let trimmed = input.trim_end_matches(' ');
+    let padding = input.len() - trimmed.len();
+    if padding == 0 || FIELD_PARSE_OPTIONS.with(Cell::get).trim_fixed_fields {
+        return Ok(trimmed);
+    }
+
+    Err(ParseError::InvalidFormat {
+        message: format!(
+            "{} '{}' is padded with {} trailing space(s); fixed-length values must not be padded",
+            field_name, trimmed, padding
+        ),
+    })
+}
 
 /// Parse a string with exact length requirement
 pub fn parse_exact_length(
@@ -13,6 +190,7 @@ pub fn parse_exact_length(
     expected_len: usize,
     field_name: &str,
 ) -> Result<String, ParseError> {
+    let input = unpadded(input, field_name)?;
     if input.len() != expected_len {
         return Err(ParseError::InvalidFormat {
             message: format!(
@@ -117,52 +295,153 @@ pub fn parse_swift_chars(input: &str, field_name: &str) -> Result<String, ParseE
 
     if !input
         .chars()
-        .all(|c| c.is_alphanumeric() || SWIFT_SPECIAL.contains(c))
+        .all(|c| c.is_ascii_alphanumeric() || SWIFT_SPECIAL.contains(c))
     {
+        let (suggestion, _) = sanitize_swift_text(input);
         return Err(ParseError::InvalidFormat {
             message: format!(
-                "{} contains invalid characters for SWIFT format",
-                field_name
+                "{} contains invalid characters for SWIFT format; suggested repair: '{}'",
+                field_name, suggestion
             ),
         });
     }
     Ok(input.to_string())
 }
 
+/// Parse text restricted to the strict SWIFT `x` character set
+///
+/// Only `a-z A-Z 0-9 / - ? : ( ) . , ' +` and space are accepted, as required for references
+/// such as fields 20 and 21. Violations are reported as T04 with the offending character.
+pub fn parse_x_chars(input: &str, field_name: &str) -> Result<String, ParseError> {
+    const X_SPECIAL: &str = "/-?:().,'+ ";
+
+    if let Some((index, c)) = input
+        .char_indices()
+        .find(|&(_, c)| !c.is_ascii_alphanumeric() && !X_SPECIAL.contains(c))
+    {
+        return Err(coded_error(
+            t_series::T04,
+            field_name,
+            input,
+            "SWIFT x character set",
+            format!(
+                "{} contains '{}' at position {}, which is not in the SWIFT x character set",
+                field_name,
+                c,
+                index + 1
+            ),
+        ));
+    }
+    Ok(input.to_string())
+}
+
+/// Transliterate non-ASCII characters into the SWIFT character set
+///
+/// Smart quotes become `'`, dashes `-`, unicode spaces ` ` and accented Latin letters
+/// their uppercase ASCII base (`é` -> `E`); anything else becomes `.`. Every replacement
+/// is one-for-one, so field lengths are preserved. Returns the cleaned text and the
+/// `(char index, original char)` of each replaced character.
+pub fn sanitize_swift_text(input: &str) -> (String, Vec<(usize, char)>) {
+    let mut replaced = Vec::new();
+    let cleaned = input
+        .chars()
+        .enumerate()
+        .map(|(index, c)| {
+            if c.is_ascii() {
+                return c;
+            }
+            replaced.push((index, c));
+            transliterate(c)
+        })
+        .collect();
+    (cleaned, replaced)
+}
+
+/// ASCII replacement for a single non-ASCII character
+fn transliterate(c: char) -> char {
+    match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' | '\u{00B4}' => '\'',
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' | '\u{00AB}'
+        | '\u{00BB}' => '\'',
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+        '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{3000}' => ' ',
+        'À'..='Å' | 'à'..='å' | 'Æ' | 'æ' | 'Ā' | 'ā' | 'Ă' | 'ă' | 'Ą' | 'ą' => 'A',
+        'Ç' | 'ç' | 'Ć' | 'ć' | 'Ĉ' | 'ĉ' | 'Ċ' | 'ċ' | 'Č' | 'č' => 'C',
+        'Ð' | 'ð' | 'Ď' | 'ď' | 'Đ' | 'đ' => 'D',
+        'È'..='Ë' | 'è'..='ë' | 'Ē' | 'ē' | 'Ĕ' | 'ĕ' | 'Ė' | 'ė' | 'Ę' | 'ę' | 'Ě' | 'ě' => {
+            'E'
+        }
+        'Ğ' | 'ğ' | 'Ģ' | 'ģ' => 'G',
+        'Ì'..='Ï' | 'ì'..='ï' | 'Ī' | 'ī' | 'Į' | 'į' | 'İ' | 'ı' => 'I',
+        'Ķ' | 'ķ' => 'K',
+        'Ĺ' | 'ĺ' | 'Ļ' | 'ļ' | 'Ľ' | 'ľ' | 'Ł' | 'ł' => 'L',
+        'Ñ' | 'ñ' | 'Ń' | 'ń' | 'Ņ' | 'ņ' | 'Ň' | 'ň' => 'N',
+        'Ò'..='Ö' | 'ò'..='ö' | 'Ø' | 'ø' | 'Ō' | 'ō' | 'Ő' | 'ő' | 'Œ' | 'œ' => 'O',
+        'Ŕ' | 'ŕ' | 'Ř' | 'ř' => 'R',
+        'ß' | 'Ś' | 'ś' | 'Ş' | 'ş' | 'Š' | 'š' | 'Ș' | 'ș' => 'S',
+        'Þ' | 'þ' | 'Ţ' | 'ţ' | 'Ť' | 'ť' | 'Ț' | 'ț' => 'T',
+        'Ù'..='Ü' | 'ù'..='ü' | 'Ū' | 'ū' | 'Ů' | 'ů' | 'Ű' | 'ű' | 'Ų' | 'ų' => 'U',
+        'Ý' | 'ý' | 'ÿ' | 'Ÿ' => 'Y',
+        'Ź' | 'ź' | 'Ż' | 'ż' | 'Ž' | 'ž' => 'Z',
+        _ => '.',
+    }
+}
+
 /// Parse BIC code (8 or 11 characters)
 pub fn parse_bic(input: &str) -> Result<String, ParseError> {
+    let input = unpadded(input, "BIC")?;
     if input.len() != 8 && input.len() != 11 {
-        return Err(ParseError::InvalidFormat {
-            message: format!("BIC must be 8 or 11 characters, found {}", input.len()),
-        });
+        return Err(coded_error(
+            t_series::T28,
+            "BIC",
+            input,
+            "4!a2!a2!c[3!c]",
+            format!("BIC must be 8 or 11 characters, found {}", input.len()),
+        ));
     }
 
     // First 4 chars: Bank code (letters)
     if !input[0..4].chars().all(|c| c.is_alphabetic()) {
-        return Err(ParseError::InvalidFormat {
-            message: "BIC bank code (first 4 chars) must be letters".to_string(),
-        });
+        return Err(coded_error(
+            t_series::T27,
+            "BIC",
+            input,
+            "4!a2!a2!c[3!c]",
+            "BIC bank code (first 4 chars) must be letters".to_string(),
+        ));
     }
 
     // Next 2 chars: Country code (letters)
     if !input[4..6].chars().all(|c| c.is_alphabetic()) {
-        return Err(ParseError::InvalidFormat {
-            message: "BIC country code (chars 5-6) must be letters".to_string(),
-        });
+        return Err(coded_error(
+            t_series::T27,
+            "BIC",
+            input,
+            "4!a2!a2!c[3!c]",
+            "BIC country code (chars 5-6) must be letters".to_string(),
+        ));
     }
 
     // Next 2 chars: Location code (alphanumeric)
     if !input[6..8].chars().all(|c| c.is_alphanumeric()) {
-        return Err(ParseError::InvalidFormat {
-            message: "BIC location code (chars 7-8) must be alphanumeric".to_string(),
-        });
+        return Err(coded_error(
+            t_series::T27,
+            "BIC",
+            input,
+            "4!a2!a2!c[3!c]",
+            "BIC location code (chars 7-8) must be alphanumeric".to_string(),
+        ));
     }
 
     // Optional 3 chars: Branch code (alphanumeric)
     if input.len() == 11 && !input[8..11].chars().all(|c| c.is_alphanumeric()) {
-        return Err(ParseError::InvalidFormat {
-            message: "BIC branch code (chars 9-11) must be alphanumeric".to_string(),
-        });
+        return Err(coded_error(
+            t_series::T27,
+            "BIC",
+            input,
+            "4!a2!a2!c[3!c]",
+            "BIC branch code (chars 9-11) must be alphanumeric".to_string(),
+        ));
     }
 
     Ok(input.to_string())
@@ -267,19 +546,28 @@ pub fn validate_non_commodity_currency(currency: &str) -> Result<(), ParseError>
 
 /// Parse currency code (3 uppercase letters)
 pub fn parse_currency(input: &str) -> Result<String, ParseError> {
+    let input = unpadded(input, "Currency code")?;
     if input.len() != 3 {
-        return Err(ParseError::InvalidFormat {
-            message: format!(
+        return Err(coded_error(
+            t_series::T52,
+            "currency",
+            input,
+            "3!a",
+            format!(
                 "Currency code must be exactly 3 characters, found {}",
                 input.len()
             ),
-        });
+        ));
     }
 
     if !input.chars().all(|c| c.is_uppercase()) {
-        return Err(ParseError::InvalidFormat {
-            message: "Currency code must be uppercase letters".to_string(),
-        });
+        return Err(coded_error(
+            t_series::T52,
+            "currency",
+            input,
+            "3!a",
+            "Currency code must be uppercase letters".to_string(),
+        ));
     }
 
     Ok(input.to_string())
@@ -308,15 +596,66 @@ pub fn parse_currency_non_commodity(input: &str) -> Result<String, ParseError> {
 }
 
 /// Parse amount with optional decimal places
+///
+/// Amounts (`15d`) are unsigned: signs live in their own subfields (debit/credit
+/// marks in field 61, the `N` sign in field 37H), so a literal `-` or `+` is rejected.
+/// Thousands separators (`'` or space) in the integer part are a T40 error unless
+/// `ParserConfig::strip_thousands_separators` is set, in which case they are removed.
 pub fn parse_amount(input: &str) -> Result<f64, ParseError> {
+    let integer_len = input.find([',', '.']).unwrap_or(input.len());
+    let (integer, fraction) = input.split_at(integer_len);
+    if let Some(separator) = integer.chars().find(|c| matches!(c, '\'' | ' ')) {
+        if !FIELD_PARSE_OPTIONS
+            .with(Cell::get)
+            .strip_thousands_separators
+        {
+            return Err(coded_error(
+                t_series::T40,
+                "amount",
+                input,
+                "15d",
+                format!(
+                    "Invalid amount format: thousands separator '{}' is not allowed; enable ParserConfig::strip_thousands_separators to remove it",
+                    separator
+                ),
+            ));
+        }
+        let stripped: String = integer
+            .chars()
+            .filter(|c| !matches!(c, '\'' | ' '))
+            .chain(fraction.chars())
+            .collect();
+        return parse_amount(&stripped);
+    }
+
+    if let Some(invalid) = input
+        .chars()
+        .find(|c| !c.is_ascii_digit() && *c != ',' && *c != '.')
+    {
+        return Err(coded_error(
+            t_series::T40,
+            "amount",
+            input,
+            "15d",
+            format!(
+                "Invalid amount format: unexpected character '{}' (amounts are unsigned)",
+                invalid
+            ),
+        ));
+    }
+
     // Remove any commas (European decimal separator handling)
     let normalized = input.replace(',', ".");
 
-    normalized
-        .parse::<f64>()
-        .map_err(|e| ParseError::InvalidFormat {
-            message: format!("Invalid amount format: {}", e),
-        })
+    normalized.parse::<f64>().map_err(|e| {
+        coded_error(
+            t_series::T40,
+            "amount",
+            input,
+            "15d",
+            format!("Invalid amount format: {}", e),
+        )
+    })
 }
 
 /// Validate amount decimal precision for a specific currency (C03 validation)
@@ -391,6 +730,65 @@ pub fn parse_amount_with_currency(input: &str, currency: &str) -> Result<f64, Pa
     Ok(amount)
 }
 
+/// Parse a `[N]3!a15d` signed currency amount (fields 32H and 34E)
+///
+/// Returns whether the `N` (negative) sign was present, the currency and the unsigned amount.
+pub fn parse_signed_currency_amount(
+    input: &str,
+    field_name: &str,
+) -> Result<(bool, String, f64), ParseError> {
+    // `N` is a sign only when a full currency and amount follow, so NOK stays a currency
+    let (negative, rest) = match input.strip_prefix('N') {
+        Some(rest)
+            if rest.len() > 3
+                && rest.as_bytes()[..3].iter().all(u8::is_ascii_alphabetic)
+                && (rest.as_bytes()[3].is_ascii_digit() || rest.as_bytes()[3] == b',') =>
+        {
+            (true, rest)
+        }
+        _ => (false, input),
+    };
+
+    if rest.len() < 4 {
+        return Err(ParseError::InvalidFormat {
+            message: format!(
+                "{} must be [N]3!a15d (sign, currency and amount), found '{}'",
+                field_name, input
+            ),
+        });
+    }
+
+    let currency = parse_currency(&rest[..3])?;
+    let amount = parse_amount_with_currency(&rest[3..], &currency)?;
+    Ok((negative, currency, amount))
+}
+
+/// Format a `[N]3!a15d` signed currency amount (fields 32H and 34E)
+pub fn format_signed_currency_amount(negative: bool, currency: &str, amount: f64) -> String {
+    format!(
+        "{}{}{}",
+        if negative { "N" } else { "" },
+        currency,
+        format_swift_amount_for_currency(amount, currency)
+    )
+}
+
+/// Rounding mode applied when an amount has more fractional digits than the target scale
+///
+/// Rounding is performed on the shortest decimal representation of the `f64`
+/// (the same digits `Display` prints), so binary artifacts such as
+/// `1234.5700000000001` never leak into the formatted output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmountRounding {
+    /// Round half away from zero (1,005 -> 1,01)
+    #[default]
+    HalfUp,
+    /// Round half to even, a.k.a. banker's rounding (1,005 -> 1,00)
+    HalfEven,
+    /// Truncate towards zero (1,009 -> 1,00)
+    Down,
+}
+
 /// Format amount for SWIFT output with comma decimal separator
 ///
 /// This function ensures SWIFT-compliant amount formatting:
@@ -398,6 +796,7 @@ pub fn parse_amount_with_currency(input: &str, currency: &str) -> Result<f64, Pa
 /// - Maintains proper decimal precision as specified
 /// - Keeps all decimal places including trailing zeros (per SWIFT spec)
 /// - Ensures at least one digit in the integer part
+/// - Rounds half-up on the decimal representation (see [`AmountRounding`])
 ///
 /// # Arguments
 /// * `amount` - The amount to format
@@ -415,8 +814,85 @@ pub fn parse_amount_with_currency(input: &str, currency: &str) -> Result<f64, Pa
 /// assert_eq!(format_swift_amount(1000.50, 2), "1000,50");
 /// ```
 pub fn format_swift_amount(amount: f64, decimals: usize) -> String {
-    let formatted = format!("{:.width$}", amount, width = decimals);
-    formatted.replace('.', ",")
+    format_swift_amount_with_rounding(amount, decimals, AmountRounding::HalfUp)
+}
+
+/// Format amount for SWIFT output with an explicit scale and rounding mode
+///
+/// # Arguments
+/// * `amount` - The amount to format
+/// * `decimals` - Number of decimal places to emit
+/// * `rounding` - How to treat digits beyond `decimals`
+///
+/// # Examples
+/// ```
+/// use swift_mt_message::fields::swift_utils::{AmountRounding, format_swift_amount_with_rounding};
+///
+/// assert_eq!(format_swift_amount_with_rounding(0.1 + 0.2, 2, AmountRounding::HalfUp), "0,30");
+/// assert_eq!(format_swift_amount_with_rounding(1.005, 2, AmountRounding::HalfUp), "1,01");
+/// assert_eq!(format_swift_amount_with_rounding(1.005, 2, AmountRounding::HalfEven), "1,00");
+/// assert_eq!(format_swift_amount_with_rounding(1.009, 2, AmountRounding::Down), "1,00");
+/// ```
+pub fn format_swift_amount_with_rounding(
+    amount: f64,
+    decimals: usize,
+    rounding: AmountRounding,
+) -> String {
+    if !amount.is_finite() {
+        return format!("{:.width$}", amount, width = decimals).replace('.', ",");
+    }
+
+    // Display for f64 yields the shortest digits that round-trip, never scientific notation
+    let repr = format!("{}", amount.abs());
+    let (int_part, frac_part) = repr.split_once('.').unwrap_or((repr.as_str(), ""));
+
+    let mut digits: Vec<u8> = int_part.bytes().collect();
+    let kept_frac = frac_part.len().min(decimals);
+    digits.extend(frac_part[..kept_frac].bytes());
+    digits.extend(std::iter::repeat_n(b'0', decimals - kept_frac));
+
+    let dropped = &frac_part[kept_frac..];
+    let round_up = match (rounding, dropped.as_bytes().first()) {
+        (_, None) | (AmountRounding::Down, _) => false,
+        (AmountRounding::HalfUp, Some(&first)) => first >= b'5',
+        (AmountRounding::HalfEven, Some(&first)) => {
+            let last_kept_odd = digits.last().is_some_and(|d| (d - b'0') % 2 == 1);
+            first > b'5'
+                || (first == b'5' && (dropped[1..].bytes().any(|d| d != b'0') || last_kept_odd))
+        }
+    };
+
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, b'1');
+        }
+    }
+
+    let split = digits.len() - decimals;
+    let int_digits = std::str::from_utf8(&digits[..split]).unwrap_or("0");
+    let frac_digits = std::str::from_utf8(&digits[split..]).unwrap_or("");
+    let is_zero = digits.iter().all(|&d| d == b'0');
+    let sign = if amount.is_sign_negative() && !is_zero {
+        "-"
+    } else {
+        ""
+    };
+
+    if decimals == 0 {
+        format!("{sign}{int_digits}")
+    } else {
+        format!("{sign}{int_digits},{frac_digits}")
+    }
 }
 
 /// Format amount for SWIFT output with currency-specific decimal precision
@@ -444,71 +920,285 @@ pub fn format_swift_amount_for_currency(amount: f64, currency: &str) -> String {
     format_swift_amount(amount, decimals as usize)
 }
 
+/// Monetary amount held exactly in minor units of its currency
+///
+/// Decimal places follow [`get_currency_decimals`]. Amounts parsed with
+/// [`Amount::from_swift_str`] are exact, and formatting and arithmetic work on the minor
+/// units; only [`Amount::new`] and [`Amount::to_f64`] cross over to the `f64` values
+/// carried by the amount fields. Amounts in different currencies are not comparable
+/// (`partial_cmp` returns `None`) and cannot be added or subtracted.
+///
+/// # Examples
+/// ```
+/// use swift_mt_message::fields::swift_utils::Amount;
+///
+/// let fee = Amount::from_swift_str("EUR", "0,10").unwrap();
+/// let total = fee.checked_add(&Amount::new("EUR", 0.2).unwrap()).unwrap();
+/// assert_eq!(total.to_swift_str(), "0,30");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Amount {
+    currency: String,
+    minor_units: i64,
+}
+
+impl Amount {
+    /// Create an amount from a float
+    ///
+    /// The float's shortest decimal representation is rounded half-up to the currency's
+    /// decimals (see [`AmountRounding`]), so `0.1 + 0.2` becomes `0,30`.
+    pub fn new(currency: &str, value: f64) -> Result<Self, ParseError> {
+        if !value.is_finite() {
+            return Err(ParseError::InvalidFormat {
+                message: format!("Amount {} {} is not a finite number", value, currency),
+            });
+        }
+        let decimals = get_currency_decimals(currency) as usize;
+        let rounded = format_swift_amount_with_rounding(value, decimals, AmountRounding::HalfUp);
+        Self::from_decimal_digits(currency, &rounded, decimals)
+    }
+
+    /// Exact sum of float amounts in `currency`, each rounded as by [`Amount::new`]
+    ///
+    /// For reconciling the `f64` amount fields without float drift; an empty list sums to zero.
+    pub fn sum(currency: &str, values: impl IntoIterator<Item = f64>) -> Result<Self, ParseError> {
+        values
+            .into_iter()
+            .try_fold(Self::from_minor_units(currency, 0), |sum, value| {
+                sum.checked_add(&Self::new(currency, value)?)
+            })
+    }
+
+    /// Create an amount from an integer count of minor units (cents for EUR)
+    pub fn from_minor_units(currency: &str, minor_units: i64) -> Self {
+        Amount {
+            currency: currency.to_string(),
+            minor_units,
+        }
+    }
+
+    /// Parse a SWIFT amount (`1234,56`) exactly, enforcing the currency's decimal places (C03)
+    pub fn from_swift_str(currency: &str, input: &str) -> Result<Self, ParseError> {
+        // Format checks (T40) and thousands-separator handling follow `parse_amount`
+        parse_amount(input)?;
+        let normalized: String = input
+            .chars()
+            .filter(|c| !matches!(c, '\'' | ' '))
+            .map(|c| if c == '.' { ',' } else { c })
+            .collect();
+
+        let decimals = get_currency_decimals(currency) as usize;
+        let fraction = normalized
+            .split_once(',')
+            .map_or("", |(_, fraction)| fraction);
+        let decimal_places = fraction.trim_end_matches('0').len();
+        if decimal_places > decimals {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Amount has {} decimal places but currency {} allows maximum {} (Error code: C03)",
+                    decimal_places, currency, decimals
+                ),
+            });
+        }
+        Self::from_decimal_digits(currency, &normalized, decimals)
+    }
+
+    /// Build from `[-]digits[,digits]` with at most `decimals` significant fractional digits
+    fn from_decimal_digits(
+        currency: &str,
+        text: &str,
+        decimals: usize,
+    ) -> Result<Self, ParseError> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (integer, fraction) = digits.split_once(',').unwrap_or((digits, ""));
+        let fraction = fraction.trim_end_matches('0');
+        let units = format!("{}{:0<width$}", integer, fraction, width = decimals)
+            .parse::<i64>()
+            .ok()
+            .filter(|_| fraction.len() <= decimals)
+            .ok_or_else(|| ParseError::InvalidFormat {
+                message: format!(
+                    "Amount {} {} cannot be represented in minor units",
+                    text, currency
+                ),
+            })?;
+        Ok(Self::from_minor_units(
+            currency,
+            if negative { -units } else { units },
+        ))
+    }
+
+    /// Format as a SWIFT amount with the currency's decimal places
+    pub fn to_swift_str(&self) -> String {
+        let decimals = self.decimals() as usize;
+        let scale = 10u64.pow(decimals as u32);
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let units = self.minor_units.unsigned_abs();
+        if decimals == 0 {
+            format!("{sign}{units}")
+        } else {
+            format!(
+                "{sign}{},{:0width$}",
+                units / scale,
+                units % scale,
+                width = decimals
+            )
+        }
+    }
+
+    /// ISO 4217 currency code
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Value in minor units of the currency
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Number of decimal places of the currency
+    pub fn decimals(&self) -> u8 {
+        get_currency_decimals(&self.currency)
+    }
+
+    /// Value as a float, for interop with the `f64` amount fields
+    pub fn to_f64(&self) -> f64 {
+        self.minor_units as f64 / 10f64.powi(self.decimals() as i32)
+    }
+
+    /// Add two amounts of the same currency
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, ParseError> {
+        self.combine(other, "add", i64::checked_add)
+    }
+
+    /// Subtract an amount of the same currency
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount, ParseError> {
+        self.combine(other, "subtract", i64::checked_sub)
+    }
+
+    fn combine(
+        &self,
+        other: &Amount,
+        operation: &str,
+        op: fn(i64, i64) -> Option<i64>,
+    ) -> Result<Amount, ParseError> {
+        if self.currency != other.currency {
+            return Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Cannot {} amounts in different currencies ({} and {})",
+                    operation, self.currency, other.currency
+                ),
+            });
+        }
+        let minor_units =
+            op(self.minor_units, other.minor_units).ok_or_else(|| ParseError::InvalidFormat {
+                message: format!(
+                    "Amount overflow when trying to {} {}",
+                    operation, self.currency
+                ),
+            })?;
+        Ok(Self::from_minor_units(&self.currency, minor_units))
+    }
+}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (self.currency == other.currency).then(|| self.minor_units.cmp(&other.minor_units))
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.currency, self.to_swift_str())
+    }
+}
+
+/// Parse a balance (`1!a6!n3!a15d`) into D/C mark, value date, currency and amount
+///
+/// Shared by fields 60a, 62a, 64 and 65. A mark other than `D` or `C` is a coded format error.
+pub fn parse_balance(
+    input: &str,
+    field_name: &str,
+) -> Result<(String, NaiveDate, String, f64), ParseError> {
+    if input.len() < 10 || !input.is_char_boundary(10) || !input[..10].is_ascii() {
+        return Err(ParseError::InvalidFormat {
+            message: format!("{} must be at least 10 characters long", field_name),
+        });
+    }
+
+    let debit_credit_mark = &input[0..1];
+    if debit_credit_mark != "D" && debit_credit_mark != "C" {
+        return Err(coded_error(
+            t_series::T42,
+            "debit/credit mark",
+            debit_credit_mark,
+            "D or C",
+            format!("{} debit/credit mark must be 'D' or 'C'", field_name),
+        ));
+    }
+
+    let value_date = parse_date_yymmdd(&input[1..7])?;
+    let currency = parse_currency(&input[7..10])?;
+    let amount = parse_amount(&input[10..])?;
+
+    Ok((debit_credit_mark.to_string(), value_date, currency, amount))
+}
+
 /// Parse date in YYMMDD format
 pub fn parse_date_yymmdd(input: &str) -> Result<NaiveDate, ParseError> {
+    let date_error = |message: String| coded_error(t_series::T50, "date", input, "YYMMDD", message);
+
     if input.len() != 6 {
-        return Err(ParseError::InvalidFormat {
-            message: format!(
-                "Date must be in YYMMDD format (6 digits), found {} characters",
-                input.len()
-            ),
-        });
+        return Err(date_error(format!(
+            "Date must be in YYMMDD format (6 digits), found {} characters",
+            input.len()
+        )));
     }
 
     let year = input[0..2]
         .parse::<u32>()
-        .map_err(|_| ParseError::InvalidFormat {
-            message: "Invalid year in date".to_string(),
-        })?;
+        .map_err(|_| date_error("Invalid year in date".to_string()))?;
     let month = input[2..4]
         .parse::<u32>()
-        .map_err(|_| ParseError::InvalidFormat {
-            message: "Invalid month in date".to_string(),
-        })?;
+        .map_err(|_| date_error("Invalid month in date".to_string()))?;
     let day = input[4..6]
         .parse::<u32>()
-        .map_err(|_| ParseError::InvalidFormat {
-            message: "Invalid day in date".to_string(),
-        })?;
+        .map_err(|_| date_error("Invalid day in date".to_string()))?;
 
-    // Determine century: 00-49 -> 2000-2049, 50-99 -> 1950-1999
-    let full_year = if year <= 49 { 2000 + year } else { 1900 + year };
+    // Determine century from the configured pivot (default: 00-49 -> 20YY, 50-99 -> 19YY)
+    let full_year = expand_two_digit_year(year);
 
-    NaiveDate::from_ymd_opt(full_year as i32, month, day).ok_or_else(|| ParseError::InvalidFormat {
-        message: format!("Invalid date: {}/{}/{}", full_year, month, day),
-    })
+    NaiveDate::from_ymd_opt(full_year, month, day)
+        .ok_or_else(|| date_error(format!("Invalid date: {}/{}/{}", full_year, month, day)))
 }
 
 /// Parse date in YYYYMMDD format
 pub fn parse_date_yyyymmdd(input: &str) -> Result<NaiveDate, ParseError> {
+    let date_error =
+        |message: String| coded_error(t_series::T50, "date", input, "YYYYMMDD", message);
+
     if input.len() != 8 {
-        return Err(ParseError::InvalidFormat {
-            message: format!(
-                "Date must be in YYYYMMDD format (8 digits), found {} characters",
-                input.len()
-            ),
-        });
+        return Err(date_error(format!(
+            "Date must be in YYYYMMDD format (8 digits), found {} characters",
+            input.len()
+        )));
     }
 
     let year = input[0..4]
         .parse::<i32>()
-        .map_err(|_| ParseError::InvalidFormat {
-            message: "Invalid year in date".to_string(),
-        })?;
+        .map_err(|_| date_error("Invalid year in date".to_string()))?;
     let month = input[4..6]
         .parse::<u32>()
-        .map_err(|_| ParseError::InvalidFormat {
-            message: "Invalid month in date".to_string(),
-        })?;
+        .map_err(|_| date_error("Invalid month in date".to_string()))?;
     let day = input[6..8]
         .parse::<u32>()
-        .map_err(|_| ParseError::InvalidFormat {
-            message: "Invalid day in date".to_string(),
-        })?;
+        .map_err(|_| date_error("Invalid day in date".to_string()))?;
 
-    NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| ParseError::InvalidFormat {
-        message: format!("Invalid date: {}/{}/{}", year, month, day),
-    })
+    NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| date_error(format!("Invalid date: {}/{}/{}", year, month, day)))
 }
 
 /// Parse time in HHMM format
@@ -642,6 +1332,19 @@ mod tests {
     use super::*;
     use chrono::Datelike;
 
+    #[test]
+    fn test_field_parse_options_restored_after_panic() {
+        let config = ParserConfig {
+            retain_raw_fields: true,
+            ..Default::default()
+        };
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            with_field_parse_options(&config, || panic!("parse failed"))
+        }));
+        assert!(result.is_err());
+        assert!(retained_raw("USD1,", ()).is_none());
+    }
+
     #[test]
     fn test_parse_exact_length() {
         assert!(parse_exact_length("ABC", 3, "Test").is_ok());
@@ -677,12 +1380,141 @@ mod tests {
         assert_eq!(date.year(), 1999);
     }
 
+    #[test]
+    fn test_sanitize_swift_text() {
+        let (cleaned, replaced) =
+            sanitize_swift_text("Caf\u{e9} \u{201C}Cr\u{e8}me\u{201D} \u{2013} O\u{2019}Neil");
+        assert_eq!(cleaned, "CafE 'CrEme' - O'Neil");
+        assert_eq!(
+            replaced,
+            vec![
+                (3, '\u{e9}'),
+                (5, '\u{201C}'),
+                (8, '\u{e8}'),
+                (11, '\u{201D}'),
+                (13, '\u{2013}'),
+                (16, '\u{2019}'),
+            ]
+        );
+        assert!(parse_swift_chars(&cleaned, "test").is_ok());
+
+        let (cleaned, replaced) = sanitize_swift_text("M\u{fc}ller \u{a5}100");
+        assert_eq!(cleaned, "MUller .100");
+        assert_eq!(replaced.len(), 2);
+
+        let err = parse_swift_chars("Ren\u{e9}e", "name").unwrap_err();
+        assert!(
+            err.to_string().contains("suggested repair: 'RenEe'"),
+            "{err}"
+        );
+        assert_eq!(
+            sanitize_swift_text("PLAIN/TEXT"),
+            ("PLAIN/TEXT".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn test_parse_balance() {
+        let (mark, date, currency, amount) =
+            parse_balance("C250131EUR1500,25", "Field 62F").unwrap();
+        assert_eq!(mark, "C");
+        assert_eq!(date, NaiveDate::from_ymd_opt(2025, 1, 31).unwrap());
+        assert_eq!(currency, "EUR");
+        assert_eq!(amount, 1500.25);
+
+        let err = parse_balance("X250131EUR1500,25", "Field 62F").unwrap_err();
+        assert_eq!(err.swift_code(), Some("T42"));
+        assert!(err.to_string().contains("must be 'D' or 'C'"), "{err}");
+
+        assert!(parse_balance("C250231EUR1500,25", "Field 62F").is_err());
+        assert!(parse_balance("C25\u{e9}131EUR1,00", "Field 62F").is_err());
+    }
+
+    #[test]
+    fn test_parse_x_chars() {
+        assert_eq!(
+            parse_x_chars("REF/2024-01.A", "Field 20").unwrap(),
+            "REF/2024-01.A"
+        );
+        let err = parse_x_chars("REF_001", "Field 20").unwrap_err();
+        assert_eq!(err.swift_code(), Some("T04"));
+        assert!(err.to_string().contains("'_' at position 4"), "{err}");
+        assert!(parse_x_chars("REF#1", "Field 20").is_err());
+    }
+
+    #[test]
+    fn test_strip_thousands_separators() {
+        for input in ["1'234,56", "1 234,56"] {
+            let err = parse_amount(input).unwrap_err();
+            assert_eq!(err.swift_code(), Some("T40"));
+            assert!(err.to_string().contains("thousands separator"), "{err}");
+        }
+
+        let lenient = ParserConfig {
+            strip_thousands_separators: true,
+            ..Default::default()
+        };
+        with_field_parse_options(&lenient, || {
+            assert_eq!(parse_amount("1'234,56").unwrap(), 1234.56);
+            assert_eq!(parse_amount("1 234 567,").unwrap(), 1234567.0);
+            // Only the integer part is cleaned
+            assert!(parse_amount("1234,5'6").is_err());
+        });
+    }
+
+    #[test]
+    fn test_trim_fixed_fields() {
+        let err = parse_currency("USD  ").unwrap_err();
+        assert!(
+            err.to_string().contains("padded with 2 trailing space(s)"),
+            "{err}"
+        );
+        assert!(parse_bic("DEUTDEFF ").is_err());
+
+        let lenient = ParserConfig {
+            trim_fixed_fields: true,
+            ..Default::default()
+        };
+        with_field_parse_options(&lenient, || {
+            assert_eq!(parse_currency("USD  ").unwrap(), "USD");
+            assert_eq!(parse_bic("DEUTDEFF ").unwrap(), "DEUTDEFF");
+            assert!(parse_currency("US ").is_err());
+        });
+    }
+
+    #[test]
+    fn test_parse_date_yymmdd_pivot_year() {
+        let pivot = |date_pivot_year| ParserConfig {
+            date_pivot_year,
+            ..Default::default()
+        };
+
+        // Default pivot keeps both 79 and 80 in the 1900s
+        assert_eq!(parse_date_yymmdd("791231").unwrap().year(), 1979);
+        assert_eq!(parse_date_yymmdd("800101").unwrap().year(), 1980);
+
+        with_field_parse_options(&pivot(80), || {
+            assert_eq!(parse_date_yymmdd("791231").unwrap().year(), 2079);
+            assert_eq!(parse_date_yymmdd("800101").unwrap().year(), 1980);
+        });
+
+        with_field_parse_options(&pivot(0), || {
+            assert_eq!(parse_date_yymmdd("000101").unwrap().year(), 1900);
+        });
+
+        // The previous pivot is restored afterwards
+        assert_eq!(parse_date_yymmdd("491231").unwrap().year(), 2049);
+    }
+
     #[test]
     fn test_parse_amount() {
         assert!(parse_amount("1234.56").is_ok());
         assert!(parse_amount("1234,56").is_ok()); // European format
         assert!(parse_amount("1234").is_ok());
         assert!(parse_amount("abc").is_err());
+        assert!(parse_amount("-1234,56").is_err());
+        assert!(parse_amount("+1234,56").is_err());
+        assert!(parse_amount("1e3").is_err());
     }
 
     #[test]
@@ -874,4 +1706,103 @@ mod tests {
         assert_eq!(format_swift_amount_for_currency(5000.00, "EUR"), "5000,00");
         assert_eq!(format_swift_amount_for_currency(250.75, "EUR"), "250,75");
     }
+
+    #[test]
+    fn test_format_swift_amount_no_float_artifacts() {
+        // Parsed amounts serialize back to exactly the same digits
+        let amount = parse_amount_with_currency("1234,57", "EUR").unwrap();
+        assert_eq!(format_swift_amount_for_currency(amount, "EUR"), "1234,57");
+
+        // Accumulated binary error never reaches the output
+        assert_eq!(format_swift_amount(0.1 + 0.2, 2), "0,30");
+        assert_eq!(format_swift_amount(1234.5700000000001, 2), "1234,57");
+        assert_eq!(format_swift_amount(999.995, 2), "1000,00");
+        assert_eq!(format_swift_amount(-12.345, 2), "-12,35");
+        assert_eq!(format_swift_amount(-0.001, 2), "0,00");
+    }
+
+    #[test]
+    fn test_format_swift_amount_with_rounding() {
+        assert_eq!(
+            format_swift_amount_with_rounding(1.005, 2, AmountRounding::HalfUp),
+            "1,01"
+        );
+        assert_eq!(
+            format_swift_amount_with_rounding(1.005, 2, AmountRounding::HalfEven),
+            "1,00"
+        );
+        assert_eq!(
+            format_swift_amount_with_rounding(1.015, 2, AmountRounding::HalfEven),
+            "1,02"
+        );
+        assert_eq!(
+            format_swift_amount_with_rounding(1.0051, 2, AmountRounding::HalfEven),
+            "1,01"
+        );
+        assert_eq!(
+            format_swift_amount_with_rounding(1.009, 2, AmountRounding::Down),
+            "1,00"
+        );
+        assert_eq!(
+            format_swift_amount_with_rounding(1500000.4, 0, AmountRounding::HalfUp),
+            "1500000"
+        );
+    }
+
+    #[test]
+    fn test_amount_construction_and_formatting() {
+        let amount = Amount::from_swift_str("EUR", "1234,5").unwrap();
+        assert_eq!(amount.minor_units(), 123450);
+        assert_eq!(amount.to_swift_str(), "1234,50");
+        assert_eq!(amount.to_string(), "EUR1234,50");
+
+        assert_eq!(
+            Amount::new("JPY", 1500000.0).unwrap().to_swift_str(),
+            "1500000"
+        );
+        assert_eq!(Amount::new("BHD", 0.005).unwrap().to_swift_str(), "0,005");
+        assert_eq!(Amount::new("USD", 0.1 + 0.2).unwrap().minor_units(), 30);
+        assert_eq!(Amount::from_minor_units("USD", -5).to_swift_str(), "-0,05");
+        assert!(Amount::from_swift_str("JPY", "100,5").is_err());
+        assert!(Amount::new("USD", f64::NAN).is_err());
+        assert_eq!(Amount::new("USD", 1.005).unwrap().to_swift_str(), "1,01");
+
+        // Parsing keeps every digit, beyond what an f64 can hold
+        let large = Amount::from_swift_str("USD", "90071992547409,93").unwrap();
+        assert_eq!(large.minor_units(), 9007199254740993);
+        assert_eq!(large.to_swift_str(), "90071992547409,93");
+
+        let field =
+            <crate::fields::Field32B as crate::traits::SwiftField>::parse("USD1000,").unwrap();
+        assert_eq!(
+            field.to_amount().unwrap(),
+            Amount::new("USD", 1000.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_amount_arithmetic_and_comparison() {
+        let a = Amount::new("EUR", 10.25).unwrap();
+        let b = Amount::new("EUR", 0.75).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().to_swift_str(), "11,00");
+        assert_eq!(b.checked_sub(&a).unwrap().to_swift_str(), "-9,50");
+        assert!(a > b);
+
+        let usd = Amount::new("USD", 10.25).unwrap();
+        assert_eq!(a.partial_cmp(&usd), None);
+        assert_ne!(a, usd);
+        assert!(a.checked_add(&usd).is_err());
+        assert!(a.checked_sub(&usd).is_err());
+        assert!(
+            Amount::from_minor_units("EUR", i64::MAX)
+                .checked_add(&b)
+                .is_err()
+        );
+
+        // Summing rounds each value first, so float drift cannot creep in
+        let sum = Amount::sum("EUR", [0.1; 10]).unwrap();
+        assert_eq!(sum, Amount::new("EUR", 1.0).unwrap());
+        assert_eq!(Amount::sum("EUR", []).unwrap().minor_units(), 0);
+        assert!(Amount::sum("EUR", [1.0, f64::INFINITY]).is_err());
+    }
 }
@@ -0,0 +1,75 @@
+//! # Fixed-Width Export
+//!
+//! Positional flat-file records for legacy systems that cannot consume FIN. A user-supplied
+//! [`FixedWidthLayout`] maps field tags to column offsets and lengths; values are padded
+//! with spaces or truncated to fit.
+
+use crate::parser::extract_base_tag;
+use serde::{Deserialize, Serialize};
+
+/// One column of a fixed-width record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedWidthColumn {
+    /// Field tag, either exact ("32A") or a base tag matching any option ("59")
+    pub tag: String,
+    /// Zero-based character offset in the record
+    pub offset: usize,
+    /// Column width in characters
+    pub length: usize,
+}
+
+/// Mapping of field tags to positions in a fixed-width record
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedWidthLayout {
+    pub columns: Vec<FixedWidthColumn>,
+}
+
+impl FixedWidthLayout {
+    /// Create an empty layout
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a column for `tag` at `offset`, `length` characters wide
+    pub fn column(mut self, tag: &str, offset: usize, length: usize) -> Self {
+        self.columns.push(FixedWidthColumn {
+            tag: tag.to_string(),
+            offset,
+            length,
+        });
+        self
+    }
+
+    /// Record length: the end of the right-most column
+    pub fn record_length(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|column| column.offset + column.length)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Render `(tag, value)` fields into a single record
+    ///
+    /// Each column takes the first matching field; multi-line values are joined with a space.
+    /// Unmatched columns and gaps between columns are filled with spaces.
+    pub fn render(&self, fields: &[(String, String)]) -> String {
+        let mut record = vec![' '; self.record_length()];
+        for column in &self.columns {
+            let Some((_, value)) = fields
+                .iter()
+                .find(|(tag, _)| *tag == column.tag || extract_base_tag(tag) == column.tag)
+            else {
+                continue;
+            };
+            let value = value.lines().collect::<Vec<_>>().join(" ");
+            for (slot, c) in record[column.offset..column.offset + column.length]
+                .iter_mut()
+                .zip(value.chars())
+            {
+                *slot = c;
+            }
+        }
+        record.into_iter().collect()
+    }
+}
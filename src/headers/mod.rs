@@ -19,7 +19,8 @@
 //! # }
 //! ```
 
-use crate::errors::{ParseError, Result};
+use crate::errors::{ParseError, Result, SwiftValidationError};
+use crate::swift_error_codes::t_series;
 use serde::{Deserialize, Serialize};
 
 /// **Block 1: Basic Header**
@@ -120,13 +121,58 @@ impl<'de> serde::Deserialize<'de> for BasicHeader {
     }
 }
 
+/// Check a fixed-length header component before it is padded for output
+///
+/// Values may be shorter than `width` (they are padded on output) but must not be longer
+/// and must use the expected character class.
+fn check_fixed_component(
+    block: &str,
+    component: &str,
+    value: &str,
+    width: usize,
+    numeric: bool,
+) -> Result<()> {
+    let valid_chars = if numeric {
+        value.chars().all(|c| c.is_ascii_digit())
+    } else {
+        value.chars().all(|c| c.is_ascii_alphanumeric())
+    };
+    if value.is_empty() || value.len() > width || !valid_chars {
+        return Err(ParseError::InvalidBlockStructure {
+            reason: crate::errors::BlockErrorReason::InvalidContent,
+            offset: None,
+            block: block.to_string(),
+            message: format!(
+                "{} '{}' must be 1-{} {} characters",
+                component,
+                value,
+                width,
+                if numeric { "numeric" } else { "alphanumeric" }
+            ),
+        });
+    }
+    Ok(())
+}
+
 impl BasicHeader {
+    /// Check that fixed-length components fit their format before serialization
+    ///
+    /// `Display` pads short values (session `4!n`, sequence `6!n`, logical terminal to 12) and
+    /// truncates long ones; this rejects what would otherwise be silently truncated.
+    pub fn validate_padding(&self) -> Result<()> {
+        check_fixed_component("1", "Logical terminal", &self.logical_terminal, 12, false)?;
+        check_fixed_component("1", "Session number", &self.session_number, 4, true)?;
+        check_fixed_component("1", "Sequence number", &self.sequence_number, 6, true)
+    }
+
     /// Parse basic header from block 1 string
     pub fn parse(block1: &str) -> Result<Self> {
         // Expected format: F01SSSSSSSSSCCC0000NNNNNN (exactly 25 characters)
         // Where: F=app_id, 01=service_id, SSSSSSSSSCCC=logical_terminal(12), 0000=session(4), NNNNNN=sequence(6)
         if block1.len() != 25 {
             return Err(ParseError::InvalidBlockStructure {
+                reason: crate::errors::BlockErrorReason::InvalidContent,
+                offset: None,
                 block: "1".to_string(),
                 message: format!(
                     "Block 1 must be exactly 25 characters, got {}",
@@ -135,15 +181,46 @@ impl BasicHeader {
             });
         }
 
+        Self::from_parts(block1, &block1[3..15])
+    }
+
+    /// Parse basic header from a block 1 of 21 to 28 characters
+    ///
+    /// Legacy blocks with an 8-character logical terminal (21 characters) are padded
+    /// with `X` to 12 characters; longer terminals (up to 28 characters) are truncated.
+    pub fn parse_lenient(block1: &str) -> Result<Self> {
+        if !(21..=28).contains(&block1.len()) || !block1.is_ascii() {
+            return Err(ParseError::InvalidBlockStructure {
+                reason: crate::errors::BlockErrorReason::InvalidContent,
+                offset: None,
+                block: "1".to_string(),
+                message: format!(
+                    "Block 1 must be between 21 and 28 characters, got {}",
+                    block1.len()
+                ),
+            });
+        }
+
+        let raw_logical_terminal = &block1[3..block1.len() - 10];
+        let logical_terminal = format!("{:X<12.12}", raw_logical_terminal);
+        let normalized = format!(
+            "{}{}{}",
+            &block1[..3],
+            logical_terminal,
+            &block1[block1.len() - 10..]
+        );
+        Self::from_parts(&normalized, &logical_terminal)
+    }
+
+    /// Build the header from a 25-character block 1 and its logical terminal
+    fn from_parts(block1: &str, logical_terminal: &str) -> Result<Self> {
         let application_id = block1[0..1].to_string();
         let service_id = block1[1..3].to_string();
-        let raw_logical_terminal = block1[3..15].to_string();
         let session_number = block1[15..19].to_string();
         let sequence_number = block1[19..25].to_string();
 
         // Keep the full 12-character logical terminal as stored in the MT format
-        // The padding is necessary for the MT format and we handle normalization in tests
-        let logical_terminal = raw_logical_terminal;
+        let logical_terminal = logical_terminal.to_string();
 
         // Extract BIC from logical_terminal
         // SWIFT BICs are either 8 or 11 characters
@@ -377,6 +454,8 @@ impl ApplicationHeader {
     pub fn parse(block2: &str) -> Result<Self> {
         if block2.len() < 4 {
             return Err(ParseError::InvalidBlockStructure {
+                reason: crate::errors::BlockErrorReason::InvalidContent,
+                offset: None,
                 block: "2".to_string(),
                 message: format!(
                     "Block 2 too short: expected at least 4 characters, got {}",
@@ -394,6 +473,8 @@ impl ApplicationHeader {
                 // I + 103 + 12-char destination + priority + optional monitoring + optional obsolescence
                 if block2.len() < 17 {
                     return Err(ParseError::InvalidBlockStructure {
+                        reason: crate::errors::BlockErrorReason::InvalidContent,
+                        offset: None,
                         block: "2".to_string(),
                         message: format!(
                             "Input Block 2 too short: expected at least 17 characters, got {}",
@@ -442,27 +523,25 @@ impl ApplicationHeader {
                     destination_address.clone()
                 };
 
-                // Only parse delivery_monitoring if explicitly present
-                let delivery_monitoring = if block2.len() >= 18 {
-                    let monitoring = &block2[17..18];
-                    // Only set if it's a valid monitoring code (not a space or other character)
-                    if monitoring
-                        .chars()
-                        .all(|c| c.is_ascii_alphabetic() || c.is_ascii_digit())
-                    {
-                        Some(monitoring.to_string())
-                    } else {
-                        None
+                // Delivery monitoring (1 char) and obsolescence period (3 digits) are
+                // independent: "U1" is monitoring only, "N003" is obsolescence only
+                let is_monitoring_code =
+                    |code: &str| code.chars().all(|c| c.is_ascii_alphanumeric());
+                let trailer = &block2[17..];
+                let (delivery_monitoring, obsolescence_period) = match trailer.len() {
+                    0 => (None, None),
+                    3 if trailer.chars().all(|c| c.is_ascii_digit()) => {
+                        (None, Some(trailer.to_string()))
+                    }
+                    len => {
+                        let monitoring = &trailer[..1];
+                        if is_monitoring_code(monitoring) {
+                            let obsolescence = (len >= 4).then(|| trailer[1..4].to_string());
+                            (Some(monitoring.to_string()), obsolescence)
+                        } else {
+                            (None, None)
+                        }
                     }
-                } else {
-                    None
-                };
-
-                // Only parse obsolescence_period if explicitly present and delivery_monitoring exists
-                let obsolescence_period = if delivery_monitoring.is_some() && block2.len() >= 21 {
-                    Some(block2[18..21].to_string())
-                } else {
-                    None
                 };
 
                 Ok(ApplicationHeader::Input(InputApplicationHeader {
@@ -483,6 +562,8 @@ impl ApplicationHeader {
 
                 if block2.len() < 46 {
                     return Err(ParseError::InvalidBlockStructure {
+                        reason: crate::errors::BlockErrorReason::InvalidContent,
+                        offset: None,
                         block: "2".to_string(),
                         message: format!(
                             "Output Block 2 too short: expected at least 46 characters, got {}",
@@ -532,6 +613,8 @@ impl ApplicationHeader {
                 }))
             }
             _ => Err(ParseError::InvalidBlockStructure {
+                reason: crate::errors::BlockErrorReason::InvalidContent,
+                offset: None,
                 block: "2".to_string(),
                 message: format!(
                     "Invalid direction indicator: expected 'I' or 'O', got '{}'",
@@ -549,6 +632,28 @@ impl ApplicationHeader {
         }
     }
 
+    /// Message type as a typed [`MessageType`](crate::messages::MessageType)
+    pub fn kind(&self) -> crate::Result<crate::messages::MessageType> {
+        self.message_type().parse()
+    }
+
+    /// Check that fixed-length components fit their format before serialization
+    ///
+    /// Covers the `3!n` message type and, for input headers, the 12-character destination.
+    pub fn validate_padding(&self) -> Result<()> {
+        check_fixed_component("2", "Message type", self.message_type(), 3, true)?;
+        if let ApplicationHeader::Input(header) = self {
+            check_fixed_component(
+                "2",
+                "Destination address",
+                &header.destination_address,
+                12,
+                false,
+            )?;
+        }
+        Ok(())
+    }
+
     /// Get the priority if available
     pub fn priority(&self) -> Option<&str> {
         match self {
@@ -756,6 +861,11 @@ pub struct PaymentReleaseInfo {
     pub additional_info: Option<String>,
 }
 
+/// Code words allowed in tags 433 and 434
+///
+/// AOK: all OK (released without a hit), FPO: false positive, NOK: not OK (true hit).
+pub const SCREENING_CODE_WORDS: &[&str] = &["AOK", "FPO", "NOK"];
+
 /// Sanctions screening info for Tag 433
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
@@ -771,7 +881,7 @@ pub struct SanctionsScreeningInfo {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct PaymentControlsInfo {
-    /// Code word (3 chars)
+    /// Code word (AOK, FPO, NOK)
     pub code_word: String,
     /// Additional info (max 20 chars)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -779,6 +889,94 @@ pub struct PaymentControlsInfo {
 }
 
 impl UserHeader {
+    /// Check that a UETR (tag 121) is a lowercase UUID version 4 (xxxxxxxx-xxxx-4xxx-[89ab]xxx-xxxxxxxxxxxx)
+    pub fn is_valid_uetr(uetr: &str) -> bool {
+        let bytes = uetr.as_bytes();
+        bytes.len() == 36
+            && bytes.iter().enumerate().all(|(i, &b)| match i {
+                8 | 13 | 18 | 23 => b == b'-',
+                14 => b == b'4',
+                19 => matches!(b, b'8' | b'9' | b'a' | b'b'),
+                _ => b.is_ascii_digit() || (b'a'..=b'f').contains(&b),
+            })
+    }
+
+    /// Check the UETR (tag 121), if present, against [`UserHeader::is_valid_uetr`]
+    ///
+    /// An otherwise valid UETR in uppercase is reported as T04 (character set), anything
+    /// else as T06 (component structure). [`UserHeader::parse`] accepts any tag 121 value;
+    /// `SwiftMessage::validate` reports this error.
+    pub fn validate_uetr(&self) -> Option<SwiftValidationError> {
+        let uetr = self.unique_end_to_end_reference.as_deref()?;
+        if Self::is_valid_uetr(uetr) {
+            return None;
+        }
+
+        let (code, message) = if Self::is_valid_uetr(&uetr.to_ascii_lowercase()) {
+            (
+                t_series::T04,
+                format!("UETR '{uetr}' in block 3 tag 121 must be lowercase"),
+            )
+        } else {
+            (
+                t_series::T06,
+                format!("UETR '{uetr}' in block 3 tag 121 is not a UUID v4 (8-4-4-4-12 hex)"),
+            )
+        };
+        Some(SwiftValidationError::format_error(
+            code,
+            "121",
+            uetr,
+            "xxxxxxxx-xxxx-4xxx-[89ab]xxx-xxxxxxxxxxxx",
+            &message,
+        ))
+    }
+
+    /// Build a user header from `(tag, value)` pairs, e.g. `[("121", uetr), ("119", "STP")]`
+    ///
+    /// Values go through the same validation as [`UserHeader::parse`]. Tags that have no
+    /// typed field, and values containing braces, are rejected with T02 / T06, and an
+    /// invalid UETR as reported by [`UserHeader::validate_uetr`].
+    pub fn from_tags(tags: &[(&str, &str)]) -> Result<Self> {
+        const KNOWN_TAGS: &[&str] = &[
+            "103", "113", "108", "119", "423", "106", "424", "111", "121", "115", "165", "433",
+            "434",
+        ];
+
+        let mut block3 = String::new();
+        for &(tag, value) in tags {
+            if !KNOWN_TAGS.contains(&tag) {
+                return Err(ParseError::SwiftValidation(Box::new(
+                    SwiftValidationError::format_error(
+                        t_series::T02,
+                        tag,
+                        value,
+                        &KNOWN_TAGS.join(", "),
+                        &format!("Unknown block 3 tag '{tag}'"),
+                    ),
+                )));
+            }
+            if value.contains(['{', '}']) {
+                return Err(ParseError::SwiftValidation(Box::new(
+                    SwiftValidationError::format_error(
+                        t_series::T06,
+                        tag,
+                        value,
+                        "value without '{' or '}'",
+                        &format!("Block 3 tag {tag} value must not contain braces"),
+                    ),
+                )));
+            }
+            block3.push_str(&format!("{{{tag}:{value}}}"));
+        }
+
+        let header = Self::parse(&block3)?;
+        match header.validate_uetr() {
+            Some(error) => Err(ParseError::SwiftValidation(Box::new(error))),
+            None => Ok(header),
+        }
+    }
+
     /// Parse user header from block 3 string using structured parsing
     pub fn parse(block3: &str) -> Result<Self> {
         let mut user_header = UserHeader::default();
@@ -871,7 +1069,7 @@ impl UserHeader {
             && let Some(end) = block3[start..].find('}')
         {
             let value = &block3[start + 5..start + end];
-            user_header.sanctions_screening_info = Self::parse_sanctions_screening_info(value);
+            user_header.sanctions_screening_info = Self::parse_sanctions_screening_info(value)?;
         }
 
         if block3.contains("{434:")
@@ -879,7 +1077,7 @@ impl UserHeader {
             && let Some(end) = block3[start..].find('}')
         {
             let value = &block3[start + 5..start + end];
-            user_header.payment_controls_info = Self::parse_payment_controls_info(value);
+            user_header.payment_controls_info = Self::parse_payment_controls_info(value)?;
         }
 
         Ok(user_header)
@@ -920,14 +1118,9 @@ impl UserHeader {
     /// Parse payment release info from tag value
     fn parse_payment_release_info(value: &str) -> Option<PaymentReleaseInfo> {
         if value.len() >= 3 {
-            let code = value[0..3].to_string();
-            let additional_info = if value.len() > 4 && value.chars().nth(3) == Some('/') {
-                Some(value[4..].to_string())
-            } else {
-                None
-            };
+            let (code, additional_info) = Self::split_code_word(value);
             Some(PaymentReleaseInfo {
-                code,
+                code: code.to_string(),
                 additional_info,
             })
         } else {
@@ -935,40 +1128,62 @@ impl UserHeader {
         }
     }
 
-    /// Parse sanctions screening info from tag value
-    fn parse_sanctions_screening_info(value: &str) -> Option<SanctionsScreeningInfo> {
-        if value.len() >= 3 {
-            let code_word = value[0..3].to_string();
-            let additional_info = if value.len() > 4 && value.chars().nth(3) == Some('/') {
-                Some(value[4..].to_string())
-            } else {
-                None
-            };
-            Some(SanctionsScreeningInfo {
-                code_word,
-                additional_info,
-            })
-        } else {
-            None
+    /// Split a 165/433/434 value at the first '/' into code word and non-empty additional info
+    fn split_code_word(value: &str) -> (&str, Option<String>) {
+        match value.split_once('/') {
+            Some((code, info)) => (code, (!info.is_empty()).then(|| info.to_string())),
+            None => (value, None),
         }
     }
 
-    /// Parse payment controls info from tag value
-    fn parse_payment_controls_info(value: &str) -> Option<PaymentControlsInfo> {
-        if value.len() >= 3 {
-            let code_word = value[0..3].to_string();
-            let additional_info = if value.len() > 4 && value.chars().nth(3) == Some('/') {
-                Some(value[4..].to_string())
-            } else {
-                None
-            };
-            Some(PaymentControlsInfo {
-                code_word,
-                additional_info,
-            })
-        } else {
-            None
+    /// Split a 433/434 value (3!a[/20x]) into code word and additional info
+    ///
+    /// Values too short to hold a code word are ignored; unknown code words are rejected
+    /// with a T08 error.
+    fn parse_screening_code(tag: &str, value: &str) -> Result<Option<(String, Option<String>)>> {
+        if value.len() < 3 {
+            return Ok(None);
         }
+
+        let (code_word, additional_info) = Self::split_code_word(value);
+
+        if !SCREENING_CODE_WORDS.contains(&code_word) {
+            return Err(ParseError::SwiftValidation(Box::new(
+                SwiftValidationError::format_error(
+                    t_series::T08,
+                    tag,
+                    code_word,
+                    &SCREENING_CODE_WORDS.join(", "),
+                    &format!("Invalid code word '{code_word}' in block 3 tag {tag}"),
+                ),
+            )));
+        }
+
+        Ok(Some((code_word.to_string(), additional_info)))
+    }
+
+    /// Parse sanctions screening info from tag value
+    fn parse_sanctions_screening_info(value: &str) -> Result<Option<SanctionsScreeningInfo>> {
+        Ok(
+            Self::parse_screening_code("433", value)?.map(|(code_word, additional_info)| {
+                SanctionsScreeningInfo {
+                    code_word,
+                    additional_info,
+                }
+            }),
+        )
+    }
+
+    /// Parse payment controls info from tag value
+    fn parse_payment_controls_info(value: &str) -> Result<Option<PaymentControlsInfo>> {
+        Ok(
+            Self::parse_screening_code("434", value)?.map(|(code_word, additional_info)| {
+                PaymentControlsInfo {
+                    code_word,
+                    additional_info,
+                }
+            }),
+        )
     }
 }
 
@@ -1031,6 +1246,106 @@ impl std::fmt::Display for UserHeader {
     }
 }
 
+/// **Blocks 1-3: Message Headers**
+///
+/// Routing headers of a message, parsed without block 4 (see `SwiftParser::parse_headers`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct MessageHeaders {
+    /// Block 1
+    pub basic: BasicHeader,
+    /// Block 2
+    pub application: ApplicationHeader,
+    /// Block 3, if present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserHeader>,
+}
+
+impl MessageHeaders {
+    /// BIC of the sending institution
+    ///
+    /// Block 1 for input messages, the MIR logical terminal for output messages.
+    pub fn sender_bic(&self) -> &str {
+        match &self.application {
+            ApplicationHeader::Input(_) => &self.basic.sender_bic,
+            ApplicationHeader::Output(header) => {
+                let lt = &header.mir.lt_identifier;
+                lt.get(..8).unwrap_or(lt)
+            }
+        }
+    }
+
+    /// BIC of the receiving institution
+    ///
+    /// Block 2 for input messages, block 1 for output messages.
+    pub fn receiver_bic(&self) -> &str {
+        match &self.application {
+            ApplicationHeader::Input(header) => &header.receiver_bic,
+            ApplicationHeader::Output(_) => &self.basic.sender_bic,
+        }
+    }
+
+    /// UETR from block 3 tag 121, if present
+    pub fn uetr(&self) -> Option<&str> {
+        self.user
+            .as_ref()
+            .and_then(|user| user.unique_end_to_end_reference.as_deref())
+    }
+
+    /// Sanity-check the routing between block 1 and block 2
+    ///
+    /// Flags BICs that are malformed (T27) or disagree with the logical terminal or
+    /// destination address they were taken from (G003), and a sender equal to the receiver
+    /// at institution level (G019).
+    pub fn validate_routing(&self) -> crate::ValidationResult {
+        use crate::swift_error_codes::g_series;
+
+        let mut errors = Vec::new();
+        let mut check_bic = |block: &str, bic: &str, address: &str| {
+            if crate::fields::swift_utils::parse_bic(bic).is_err() {
+                errors.push(SwiftValidationError::format_error(
+                    t_series::T27,
+                    block,
+                    bic,
+                    "4!a2!a2!c[3!c]",
+                    &format!("{block} BIC '{bic}' is not a valid BIC"),
+                ));
+            } else if address.get(..8) != bic.get(..8) {
+                errors.push(SwiftValidationError::content_error(
+                    g_series::G003,
+                    block,
+                    address,
+                    &format!("{block} BIC '{bic}' does not match address '{address}'"),
+                    "The BIC must be the first 8 characters of the logical terminal address",
+                ));
+            }
+        };
+
+        check_bic(
+            "block1",
+            &self.basic.sender_bic,
+            &self.basic.logical_terminal,
+        );
+        if let ApplicationHeader::Input(header) = &self.application {
+            check_bic("block2", &header.receiver_bic, &header.destination_address);
+        }
+
+        let sender = self.sender_bic();
+        let receiver = self.receiver_bic();
+        if sender.get(..8).is_some() && sender.get(..8) == receiver.get(..8) {
+            errors.push(SwiftValidationError::relation_error(
+                g_series::G019,
+                "block2",
+                vec!["block1".to_string()],
+                &format!("Sender and receiver are the same institution ({sender})"),
+                "The receiver BIC must differ from the sender BIC",
+            ));
+        }
+
+        crate::ValidationResult::with_errors(errors.into_iter().map(Into::into).collect())
+    }
+}
+
 /// **Block 5: Trailer**
 ///
 /// Security and control information for message integrity and authentication.
@@ -1216,6 +1531,121 @@ impl std::fmt::Display for Trailer {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_user_header_screening_code_words() {
+        for code in SCREENING_CODE_WORDS {
+            let header = UserHeader::parse(&format!("{{433:{code}/SCREENED BY RTS}}")).unwrap();
+            let info = header.sanctions_screening_info.unwrap();
+            assert_eq!(info.code_word, *code);
+            assert_eq!(info.additional_info.as_deref(), Some("SCREENED BY RTS"));
+        }
+
+        let header = UserHeader::parse("{434:FPO}").unwrap();
+        let info = header.payment_controls_info.unwrap();
+        assert_eq!(info.code_word, "FPO");
+        assert!(info.additional_info.is_none());
+
+        match UserHeader::parse("{433:XYZ/UNKNOWN}") {
+            Err(ParseError::SwiftValidation(error)) => {
+                assert_eq!(error.code(), "T08");
+                assert!(error.to_string().contains("XYZ"));
+            }
+            other => panic!("expected T08 error, got {other:?}"),
+        }
+        assert!(UserHeader::parse("{434:OKK}").is_err());
+    }
+
+    #[test]
+    fn test_validate_routing() {
+        let headers = |block1: &str, block2: &str| MessageHeaders {
+            basic: BasicHeader::parse(block1).unwrap(),
+            application: ApplicationHeader::parse(block2).unwrap(),
+            user: None,
+        };
+
+        let valid = headers("F01BANKDEFFAXXX0000000000", "I103BANKUS33XXXXN");
+        assert!(valid.validate_routing().is_valid);
+
+        let same = headers("F01BANKDEFFAXXX0000000000", "I103BANKDEFFXXXXN");
+        let result = same.validate_routing();
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].to_string().contains("G019"));
+
+        let mut inconsistent = valid.clone();
+        inconsistent.basic.sender_bic = "OTHRGB2L".to_string();
+        let result = inconsistent.validate_routing();
+        assert!(!result.is_valid);
+        assert!(result.errors[0].to_string().contains("G003"));
+    }
+
+    #[test]
+    fn test_user_header_code_word_split() {
+        // Tag 119 after the code word tags is still picked up
+        let header = UserHeader::parse("{433:AOK/X}{434:NOK}{165:REL/Y}{119:STP}").unwrap();
+        assert_eq!(header.validation_flag.as_deref(), Some("STP"));
+
+        let screening = header.sanctions_screening_info.unwrap();
+        assert_eq!(screening.code_word, "AOK");
+        assert_eq!(screening.additional_info.as_deref(), Some("X"));
+
+        let controls = header.payment_controls_info.unwrap();
+        assert_eq!(controls.code_word, "NOK");
+        assert!(controls.additional_info.is_none());
+
+        let release = header.payment_release_information.unwrap();
+        assert_eq!(release.code, "REL");
+        assert_eq!(release.additional_info.as_deref(), Some("Y"));
+
+        let header = UserHeader::parse("{165:REL}").unwrap();
+        let release = header.payment_release_information.unwrap();
+        assert_eq!(release.code, "REL");
+        assert!(release.additional_info.is_none());
+    }
+
+    #[test]
+    fn test_user_header_from_tags() {
+        let uetr = "eb6305c9-1f7f-49de-aed0-16487c27b42d";
+        let header =
+            UserHeader::from_tags(&[("121", uetr), ("119", "STP"), ("433", "AOK")]).unwrap();
+        assert_eq!(header.unique_end_to_end_reference.as_deref(), Some(uetr));
+        assert_eq!(
+            format!("{{3:{header}}}"),
+            format!("{{3:{{119:STP}}{{121:{uetr}}}{{433:AOK}}}}")
+        );
+
+        let err = UserHeader::from_tags(&[("999", "X")]).unwrap_err();
+        assert_eq!(err.swift_code(), Some("T02"));
+        let err = UserHeader::from_tags(&[("121", "not-a-uuid")]).unwrap_err();
+        assert_eq!(err.swift_code(), Some("T06"));
+    }
+
+    #[test]
+    fn test_user_header_uetr_validation() {
+        let uetr = "eb6305c9-1f7f-49de-aed0-16487c27b42d";
+        let header = UserHeader::parse(&format!("{{121:{uetr}}}")).unwrap();
+        assert_eq!(header.unique_end_to_end_reference.as_deref(), Some(uetr));
+
+        assert!(header.validate_uetr().is_none());
+        assert!(UserHeader::default().validate_uetr().is_none());
+
+        // Invalid UETRs still parse, so the rejection can be reported
+        let error = UserHeader::parse(&format!("{{121:{}}}", uetr.to_uppercase()))
+            .unwrap()
+            .validate_uetr()
+            .unwrap();
+        assert_eq!(error.code(), "T04");
+        assert!(error.to_string().contains("must be lowercase"), "{error}");
+
+        for malformed in [
+            "eb6305c9-1f7f-49de-aed0",
+            "eb6305c91f7f49deaed016487c27b42d",
+        ] {
+            let header = UserHeader::parse(&format!("{{121:{malformed}}}")).unwrap();
+            assert_eq!(header.validate_uetr().unwrap().code(), "T06");
+        }
+    }
+
     #[test]
     fn test_application_header_input_parsing() {
         // Test Input message format parsing
@@ -1254,6 +1684,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_application_header_input_monitoring_and_obsolescence_combinations() {
+        let cases = [
+            ("I103DEUTDEFFAXXXN", None, None),
+            ("I103DEUTDEFFAXXXU1", Some("1"), None),
+            ("I103DEUTDEFFAXXXN003", None, Some("003")),
+            ("I103DEUTDEFFAXXXU3003", Some("3"), Some("003")),
+            ("I103DEUTDEFFAXXXS2020", Some("2"), Some("020")),
+        ];
+
+        for (block2, monitoring, obsolescence) in cases {
+            let header = ApplicationHeader::parse(block2).unwrap();
+            match &header {
+                ApplicationHeader::Input(input) => {
+                    assert_eq!(input.delivery_monitoring.as_deref(), monitoring, "{block2}");
+                    assert_eq!(
+                        input.obsolescence_period.as_deref(),
+                        obsolescence,
+                        "{block2}"
+                    );
+                }
+                ApplicationHeader::Output(_) => panic!("Expected Input header, got Output"),
+            }
+            assert_eq!(header.to_string(), block2);
+        }
+    }
+
     #[test]
     fn test_application_header_output_parsing() {
         // Test Output message format parsing - the exact case from the issue
@@ -1344,6 +1801,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_basic_header_length_strictness() {
+        let legacy = "F01DEUTDEFF0000123456";
+        let standard = "F01DEUTDEFFAXXX0000123456";
+        let long = "F01DEUTDEFF500AXXX0000123456";
+        assert_eq!((legacy.len(), standard.len(), long.len()), (21, 25, 28));
+
+        assert!(BasicHeader::parse(legacy).is_err());
+        assert!(BasicHeader::parse(standard).is_ok());
+        assert!(BasicHeader::parse(long).is_err());
+
+        let header = BasicHeader::parse_lenient(legacy).unwrap();
+        assert_eq!(header.logical_terminal, "DEUTDEFFXXXX");
+        assert_eq!(header.sender_bic, "DEUTDEFF");
+        assert_eq!(header.sequence_number, "123456");
+        assert_eq!(
+            BasicHeader::parse_lenient(standard).unwrap(),
+            BasicHeader::parse(standard).unwrap()
+        );
+        let header = BasicHeader::parse_lenient(long).unwrap();
+        assert_eq!(header.logical_terminal, "DEUTDEFF500A");
+        assert_eq!(header.session_number, "0000");
+        assert_eq!(header.to_string(), "F01DEUTDEFF500A0000123456");
+
+        assert!(BasicHeader::parse_lenient("F01DEUTDEF0000123456").is_err());
+        assert!(BasicHeader::parse_lenient("F01DEUTDEFF500AXXXX0000123456").is_err());
+    }
+
     #[test]
     fn test_basic_header_parsing() {
         let block1 = "F01DEUTDEFFAXXX0000123456";
@@ -9,6 +9,16 @@
 //! - **JSON serialization** with clean flattened output
 //! - **30+ message types** (MT101-MT950)
 //!
+//! ## Cargo Features
+//! - `sample` (default): scenario-based sample message generation
+//! - `plugin` (default): dataflow-rs workflow functions (implies `sample`)
+//! - `csv`: `MT103::write_csv` batch export to a `csv::Writer`
+//! - `wasm`: wasm-bindgen entry points (build with `--no-default-features`)
+//! - `jsonschema`, `ebcdic`
+//!
+//! The crate requires `std`. A `no_std` build is not supported: `serde_json`, `regex`,
+//! `chrono` and `thiserror` are all used with their standard-library features.
+//!
 //! ## Quick Start
 //! ```rust
 //! use swift_mt_message::parser::SwiftParser;
@@ -20,13 +30,18 @@
 //! # }
 //! ```
 
+#[cfg(feature = "plugin")]
+pub mod custom_rules;
 pub mod errors;
 pub mod fields;
+pub mod fixed_width;
 pub mod headers;
 pub mod messages;
 pub mod parsed_message;
 pub mod parser;
+#[cfg(feature = "sample")]
 pub mod sample;
+#[cfg(feature = "sample")]
 pub mod scenario_config;
 pub mod swift_error_codes;
 pub mod swift_message;
@@ -35,30 +50,45 @@ pub mod utils;
 pub mod validation_result;
 
 // Plugin module for dataflow-rs integration
+#[cfg(feature = "plugin")]
 pub mod plugin;
 
+// WebAssembly entry points
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 // Re-export all message types
 pub use messages::*;
 
 // Re-export core types
 pub use errors::{
-    ParseError, ParseResult, Result, SwiftBusinessError, SwiftContentError, SwiftFormatError,
-    SwiftGeneralError, SwiftRelationError, SwiftValidationError, SwiftValidationResult,
-    ValidationError, error_codes,
+    BlockErrorReason, MissingMandatoryPolicy, ParseError, ParseMetrics, ParseResult, ParserConfig,
+    Result, SwiftBusinessError, SwiftContentError, SwiftFormatError, SwiftGeneralError,
+    SwiftRelationError, SwiftValidationError, SwiftValidationResult, ValidationError, error_codes,
+};
+pub use fixed_width::{FixedWidthColumn, FixedWidthLayout};
+pub use headers::{ApplicationHeader, BasicHeader, MessageHeaders, Trailer, UserHeader};
+pub use parsed_message::{BicRef, ParsedSwiftMessage};
+pub use parser::{
+    BatchReport, FieldSpan, MtEnvelope, SwiftParser, SwiftStreamParser, extract_base_tag,
 };
-pub use headers::{ApplicationHeader, BasicHeader, Trailer, UserHeader};
-pub use parsed_message::ParsedSwiftMessage;
-pub use parser::{SwiftParser, extract_base_tag};
 pub use swift_error_codes as swift_codes;
-pub use swift_message::SwiftMessage;
-pub use traits::{SwiftField, SwiftMessageBody};
+pub use swift_message::write_rje;
+pub use swift_message::{SerializationMode, SerializeOptions, SwiftMessage};
+pub use traits::{
+    AnyField, CommonFields, CurrencyInfo, FieldSpec, FieldView, FieldVisitor, SequenceOccurrence,
+    SwiftField, SwiftMessageBody, sequence_occurrences,
+};
 pub use utils::{
-    get_field_tag_for_mt, get_field_tag_with_variant, is_numbered_field, map_variant_to_numbered,
+    generate_uetr, get_field_tag_for_mt, get_field_tag_with_variant, is_numbered_field,
+    map_variant_to_numbered,
 };
-pub use validation_result::ValidationResult;
+pub use validation_result::{ValidationReport, ValidationResult};
 
 // Re-export sample generation
+#[cfg(feature = "sample")]
 pub use sample::{SampleGenerator, generate_sample, generate_sample_with_config};
+#[cfg(feature = "sample")]
 pub use scenario_config::ScenarioConfig;
 
 /// Simplified result type for SWIFT operations
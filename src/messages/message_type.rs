@@ -0,0 +1,175 @@
+//! # Message Type
+//!
+//! Typed identifier for SWIFT MT message types, replacing bare `"103"` strings.
+
+use crate::errors::ParseError;
+use crate::traits::{FieldSpec, SwiftMessageBody};
+use std::fmt;
+use std::str::FromStr;
+
+/// Declares [`MessageType`] from `Variant => "code", "message function";` entries
+///
+/// The table is the single list of supported types: parsing, display, the message
+/// function and `SwiftParser::parse_auto` dispatch all derive from it.
+macro_rules! message_types {
+    ($($variant:ident => $code:literal, $function:literal;)*) => {
+        /// SWIFT MT message type
+        ///
+        /// Parses from the three-digit form (`"103"`) or with an `MT` prefix (`"MT103"`) and
+        /// displays as the three-digit form used in block 2. Valid types without a typed
+        /// implementation in this crate are kept as [`MessageType::Other`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum MessageType {
+            $(
+                #[doc = concat!("MT", $code, ": ", $function)]
+                $variant,
+            )*
+            /// Any other three-digit message type
+            Other(String),
+        }
+
+        impl MessageType {
+            /// Three-digit message type as it appears in block 2
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(MessageType::$variant => $code,)*
+                    MessageType::Other(message_type) => message_type,
+                }
+            }
+
+            /// Message function as named in the SWIFT standard (e.g., "Single Customer Credit Transfer")
+            ///
+            /// `None` for types without a typed implementation.
+            pub fn message_function(&self) -> Option<&'static str> {
+                match self {
+                    $(MessageType::$variant => Some($function),)*
+                    MessageType::Other(_) => None,
+                }
+            }
+
+            /// Field specs of the typed implementation; empty for `Other`
+            pub fn field_specs(&self) -> Vec<FieldSpec> {
+                match self {
+                    $(MessageType::$variant => <super::$variant as SwiftMessageBody>::field_specs(),)*
+                    MessageType::Other(_) => Vec::new(),
+                }
+            }
+
+            /// Supported type for a three-digit code, `Other` for any other code
+            fn from_code(code: &str) -> Self {
+                match code {
+                    $($code => MessageType::$variant,)*
+                    other => MessageType::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+message_types! {
+    MT101 => "101", "Request for Transfer";
+    MT103 => "103", "Single Customer Credit Transfer";
+    MT104 => "104", "Direct Debit and Request for Debit Transfer";
+    MT107 => "107", "General Direct Debit Message";
+    MT110 => "110", "Advice of Cheque(s)";
+    MT111 => "111", "Request for Stop Payment of a Cheque";
+    MT112 => "112", "Status of a Request for Stop Payment of a Cheque";
+    MT190 => "190", "Advice of Charges, Interest and Other Adjustments";
+    MT191 => "191", "Request for Payment of Charges, Interest and Other Expenses";
+    MT192 => "192", "Request for Cancellation";
+    MT196 => "196", "Answers";
+    MT199 => "199", "Free Format Message";
+    MT200 => "200", "Financial Institution Transfer for its Own Account";
+    MT202 => "202", "General Financial Institution Transfer";
+    MT204 => "204", "Financial Markets Direct Debit Message";
+    MT205 => "205", "Financial Institution Transfer Execution";
+    MT210 => "210", "Notice to Receive";
+    MT290 => "290", "Advice of Charges, Interest and Other Adjustments";
+    MT291 => "291", "Request for Payment of Charges, Interest and Other Expenses";
+    MT292 => "292", "Request for Cancellation";
+    MT296 => "296", "Answers";
+    MT299 => "299", "Free Format Message";
+    MT900 => "900", "Confirmation of Debit";
+    MT910 => "910", "Confirmation of Credit";
+    MT920 => "920", "Request Message";
+    MT935 => "935", "Rate Change Advice";
+    MT940 => "940", "Customer Statement Message";
+    MT941 => "941", "Balance Report";
+    MT942 => "942", "Interim Transaction Report";
+    MT950 => "950", "Statement Message";
+}
+
+impl MessageType {
+    /// SWIFT message category, the first digit of the message type
+    ///
+    /// `None` when the type does not start with a digit (e.g. an empty `Other`).
+    pub fn category(&self) -> Option<u8> {
+        self.as_str()
+            .bytes()
+            .next()
+            .filter(u8::is_ascii_digit)
+            .map(|digit| digit - b'0')
+    }
+
+    /// Whether the crate has a typed implementation for this message type
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, MessageType::Other(_))
+    }
+}
+
+impl FromStr for MessageType {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let digits = input
+            .strip_prefix("MT")
+            .or_else(|| input.strip_prefix("mt"))
+            .unwrap_or(input);
+        if digits.len() != 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::InvalidFormat {
+                message: format!("Invalid message type '{}', expected three digits", input),
+            });
+        }
+
+        Ok(MessageType::from_code(digits))
+    }
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_type_round_trip() {
+        let message_type: MessageType = "103".parse().unwrap();
+        assert_eq!(message_type, MessageType::MT103);
+        assert_eq!(message_type.to_string(), "103");
+        assert_eq!(
+            message_type.to_string().parse::<MessageType>().unwrap(),
+            message_type
+        );
+        assert_eq!("MT103".parse::<MessageType>().unwrap(), MessageType::MT103);
+        assert_eq!(message_type.category(), Some(1));
+        assert_eq!(
+            message_type.message_function(),
+            Some("Single Customer Credit Transfer")
+        );
+        assert!(message_type.is_supported());
+
+        let other: MessageType = "300".parse().unwrap();
+        assert_eq!(other, MessageType::Other("300".to_string()));
+        assert_eq!(other.category(), Some(3));
+        assert_eq!(other.message_function(), None);
+        assert!(!other.is_supported());
+        assert_eq!(MessageType::Other(String::new()).category(), None);
+
+        assert!("10".parse::<MessageType>().is_err());
+        assert!("MTABC".parse::<MessageType>().is_err());
+    }
+}
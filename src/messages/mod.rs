@@ -21,6 +21,7 @@
 //! ```
 
 // Message modules
+pub mod message_type;
 pub mod mt101;
 pub mod mt103;
 pub mod mt104;
@@ -53,8 +54,9 @@ pub mod mt942;
 pub mod mt950;
 
 // Re-export message types
-pub use mt101::{MT101, MT101Transaction};
-pub use mt103::MT103;
+pub use message_type::MessageType;
+pub use mt101::{MT101, MT101Transaction, validate_mt101_page_set};
+pub use mt103::{MT103, RegulatoryInfo, UsageProfile};
 pub use mt104::{MT104, MT104Transaction};
 pub use mt107::{MT107, MT107Transaction};
 pub use mt110::{MT110, MT110Cheque};
@@ -62,7 +64,7 @@ pub use mt111::MT111;
 pub use mt112::MT112;
 pub use mt190::MT190;
 pub use mt191::MT191;
-pub use mt192::MT192;
+pub use mt192::{CANCELLATION_CORRELATION_CODE, MT192};
 pub use mt196::MT196;
 pub use mt199::MT199;
 pub use mt290::MT290;
@@ -2,7 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 /// Sequence B - Transaction details
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -875,6 +875,81 @@ impl MT101 {
     }
 }
 
+/// Check that MT101 pages form one complete request (field 28D index/total)
+///
+/// All pages must carry the same total and the same sender's reference (field 20), and
+/// their indices must cover 1..=total exactly once. Pages may be given in any order.
+/// Violations are reported as T10 (page set structure) and T20 (reference).
+pub fn validate_mt101_page_set(pages: &[MT101]) -> crate::ValidationReport {
+    let mut report = crate::ValidationReport::new("101");
+    let Some(first) = pages.first() else {
+        return report;
+    };
+
+    let total = first.field_28d.total;
+    let mut seen = BTreeSet::new();
+    for page in pages {
+        let Field28D {
+            index,
+            total: page_total,
+        } = page.field_28d;
+        if page_total != total {
+            report.errors.push(SwiftValidationError::relation_error(
+                "T10",
+                "28D",
+                vec!["28D".to_string()],
+                &format!("Page {index} announces {page_total} pages, the first page {total}"),
+                "All pages of a request must carry the same total in field 28D",
+            ));
+        }
+        if index == 0 || index > total {
+            report.errors.push(SwiftValidationError::relation_error(
+                "T10",
+                "28D",
+                vec!["28D".to_string()],
+                &format!("Page index {index} is outside 1..={total}"),
+                "The message index of field 28D must be between 1 and the total",
+            ));
+        } else if !seen.insert(index) {
+            report.errors.push(SwiftValidationError::relation_error(
+                "T10",
+                "28D",
+                vec!["28D".to_string()],
+                &format!("Page {index} occurs more than once"),
+                "Each message index of a request must occur exactly once",
+            ));
+        }
+        if page.field_20.reference != first.field_20.reference {
+            report.errors.push(SwiftValidationError::relation_error(
+                "T20",
+                "20",
+                vec!["28D".to_string()],
+                &format!(
+                    "Page {index} has sender's reference '{}', expected '{}'",
+                    page.field_20.reference, first.field_20.reference
+                ),
+                "All pages of a request must carry the same sender's reference in field 20",
+            ));
+        }
+    }
+
+    let missing: Vec<String> = (1..=total)
+        .filter(|index| !seen.contains(index))
+        .map(|index| index.to_string())
+        .collect();
+    if !missing.is_empty() {
+        report.errors.push(SwiftValidationError::relation_error(
+            "T10",
+            "28D",
+            vec!["28D".to_string()],
+            &format!("Missing page(s) {} of {total}", missing.join(", ")),
+            "A request must contain every message index from 1 to the total",
+        ));
+    }
+
+    report
+}
+
 impl crate::traits::SwiftMessageBody for MT101 {
     fn message_type() -> &'static str {
         "101"
@@ -885,6 +960,84 @@ impl crate::traits::SwiftMessageBody for MT101 {
         MT101::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("21R", &self.field_21r)
+            .field("28D", &self.field_28d)
+            .optional("50", &self.instructing_party)
+            .optional("50", &self.ordering_customer)
+            .optional("52", &self.field_52a)
+            .optional("51A", &self.field_51a)
+            .field("30", &self.field_30)
+            .optional("25", &self.field_25);
+        for transaction in &self.transactions {
+            visitor
+                .field("21", &transaction.field_21)
+                .optional("21F", &transaction.field_21f)
+                .optional_repeated("23E", &transaction.field_23e)
+                .field("32B", &transaction.field_32b)
+                .optional("50", &transaction.instructing_party_tx)
+                .optional("50", &transaction.ordering_customer_tx)
+                .optional("52", &transaction.field_52)
+                .optional("56", &transaction.field_56)
+                .optional("57", &transaction.field_57)
+                .field("59", &transaction.field_59)
+                .optional("70", &transaction.field_70)
+                .optional("77B", &transaction.field_77b)
+                .optional("33B", &transaction.field_33b)
+                .field("71A", &transaction.field_71a)
+                .optional("25A", &transaction.field_25a)
+                .optional("36", &transaction.field_36);
+        }
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let sequence_a = [
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("21R", "16x", "Customer Specified Reference").optional(),
+            FieldSpec::new("28D", "5n/5n", "Message Index/Total"),
+            FieldSpec::new("50C", "4!a2!a2!c[3!c]", "Instructing Party").optional(),
+            FieldSpec::new("50L", "35x", "Instructing Party").optional(),
+            FieldSpec::new("50F", "35x4*35x", "Ordering Customer").optional(),
+            FieldSpec::new("50G", "/34x4!a2!a2!c[3!c]", "Ordering Customer").optional(),
+            FieldSpec::new("50H", "/34x4*35x", "Ordering Customer").optional(),
+            FieldSpec::new("52a", "Option A or C", "Account Servicing Institution").optional(),
+            FieldSpec::new("51A", "[/1!a][/34x]4!a2!a2!c[3!c]", "Sending Institution").optional(),
+            FieldSpec::new("30", "6!n", "Requested Execution Date"),
+            FieldSpec::new("25", "35x", "Authorisation").optional(),
+        ]
+        .map(|spec| spec.sequence("A"));
+        let sequence_b = [
+            FieldSpec::new("21", "16x", "Transaction Reference"),
+            FieldSpec::new("21F", "16x", "F/X Deal Reference").optional(),
+            FieldSpec::new("23E", "4!c[/30x]", "Instruction Code")
+                .optional()
+                .max(15),
+            FieldSpec::new("32B", "3!a15d", "Currency/Transaction Amount"),
+            FieldSpec::new("50C", "4!a2!a2!c[3!c]", "Instructing Party").optional(),
+            FieldSpec::new("50L", "35x", "Instructing Party").optional(),
+            FieldSpec::new("50F", "35x4*35x", "Ordering Customer").optional(),
+            FieldSpec::new("50G", "/34x4!a2!a2!c[3!c]", "Ordering Customer").optional(),
+            FieldSpec::new("50H", "/34x4*35x", "Ordering Customer").optional(),
+            FieldSpec::new("52a", "Option A or C", "Account Servicing Institution").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("59a", "No letter option, A or F", "Beneficiary"),
+            FieldSpec::new("70", "4*35x", "Remittance Information").optional(),
+            FieldSpec::new("77B", "3*35x", "Regulatory Reporting").optional(),
+            FieldSpec::new("33B", "3!a15d", "Currency/Original Ordered Amount").optional(),
+            FieldSpec::new("71A", "3!a", "Details of Charges"),
+            FieldSpec::new("25A", "/34x", "Charges Account").optional(),
+            FieldSpec::new("36", "12d", "Exchange Rate").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("B"));
+
+        sequence_a.into_iter().chain(sequence_b).collect()
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT101::to_mt_string(self)
@@ -895,3 +1048,92 @@ impl crate::traits::SwiftMessageBody for MT101 {
         MT101::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT101 {
+    fn currency(&self) -> Option<&str> {
+        self.transactions
+            .first()
+            .map(|tx| tx.field_32b.currency.as_str())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        let mut currencies = BTreeSet::new();
+        for tx in &self.transactions {
+            currencies.insert(tx.field_32b.currency.clone());
+            currencies.extend(tx.field_33b.iter().map(|f| f.currency.clone()));
+        }
+        currencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(index: u32, total: u32) -> MT101 {
+        let block4 = format!(
+            ":20:REQ001\n:28D:{index}/{total}\n:30:240315\n:21:TXN{index}\n:32B:EUR100,00\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n-"
+        );
+        MT101::parse_from_block4(&block4).unwrap()
+    }
+
+    #[test]
+    fn test_mt101_page_set() {
+        let pages = [page(2, 3), page(1, 3), page(3, 3)];
+        assert!(validate_mt101_page_set(&pages).is_valid());
+
+        let report = validate_mt101_page_set(&[page(1, 3), page(3, 3)]);
+        assert_eq!(report.codes(), vec!["T10"]);
+        assert!(
+            report.errors[0]
+                .message()
+                .contains("Missing page(s) 2 of 3")
+        );
+
+        let mut other_reference = page(2, 3);
+        other_reference.field_20.reference = "REQ002".to_string();
+        let report = validate_mt101_page_set(&[page(1, 3), other_reference, page(2, 4)]);
+        assert_eq!(report.codes(), vec!["T20", "T10", "T10", "T10"]);
+    }
+
+    #[test]
+    fn test_mt101_missing_required_fields_per_transaction() {
+        use crate::traits::{SwiftMessageBody, sequence_occurrences};
+
+        let block4 = ":20:REQ001\n:28D:1/1\n:30:240315\n:21:TXN1\n:32B:EUR100,00\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n:21:TXN2\n:23E:CHQB\n:32B:EUR200,00\n:59:/DE89370400440532013000\nJOHN ROE\n:71A:OUR\n-";
+        let mut mt101 = MT101::parse_from_block4(block4).unwrap();
+        assert!(mt101.missing_required_fields().is_empty());
+
+        let fields = mt101.to_fields_with_variants();
+        let tags: Vec<&str> = fields.iter().map(|(tag, _)| tag.as_str()).collect();
+        let occurrences = sequence_occurrences(&MT101::field_specs(), &tags);
+        let sizes: Vec<usize> = occurrences.iter().map(|o| o.fields.len()).collect();
+        assert_eq!(sizes, vec![3, 4, 5]);
+
+        mt101.transactions[1].field_21.reference.clear();
+        assert_eq!(mt101.missing_required_fields(), vec!["21"]);
+    }
+
+    #[test]
+    fn test_mt101_field_views() {
+        use crate::traits::SwiftMessageBody;
+
+        let block4 = ":20:REQ001\n:28D:1/1\n:50F:/DE89370400440532013000\nDEUTDEFF\n:30:240315\n:21:TXN1\n:32B:EUR100,00\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n:21:TXN2\n:32B:EUR200,00\n:59:/DE89370400440532013000\nJOHN ROE\n:71A:OUR\n-";
+        let mt101 = MT101::parse_from_block4(block4).unwrap();
+
+        let ordering = mt101.field("50F").unwrap();
+        assert_eq!(ordering.variant(), Some("F"));
+        assert!(mt101.field("50H").is_none());
+
+        // The first transaction answers, later ones are still listed in the views
+        let reference = mt101
+            .field("21")
+            .unwrap()
+            .downcast::<Field21NoOption>()
+            .unwrap();
+        assert!(std::ptr::eq(reference, &mt101.transactions[0].field_21));
+        let views = mt101.field_views();
+        assert_eq!(views.iter().filter(|view| view.tag == "21").count(), 2);
+        assert_eq!(views.len(), 12);
+    }
+}
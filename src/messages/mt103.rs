@@ -1,10 +1,24 @@
 use crate::errors::SwiftValidationError;
+use crate::fields::field_utils::InstitutionRef;
 use crate::fields::*;
 use crate::parser::utils::*;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
+/// Market practice rule set layered on base MT103 validation by `validate_profile`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UsageProfile {
+    /// SWIFT network rules only
+    #[default]
+    Core,
+    /// SWIFT gpi: a UETR (block 3 tag 121) is mandatory
+    Gpi,
+    /// CBPR+: gpi rules plus structured customers (50A/F, 59A/F) and BIC-only agents
+    /// (52A, 56A, 57A)
+    CbprPlus,
+}
+
 /// **MT103: Single Customer Credit Transfer**
 ///
 /// Customer payment instruction from ordering to beneficiary customer via financial institutions.
@@ -113,6 +127,15 @@ pub struct MT103 {
 }
 
 // Additional methods for MT103
+/// Combined view of field 26T and field 77B for regulatory reporting
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RegulatoryInfo {
+    /// Transaction type code from field 26T
+    pub transaction_type: Option<String>,
+    /// Structured entries from field 77B
+    pub entries: Vec<Field77BEntry>,
+}
+
 impl MT103 {
     /// Parse from SWIFT MT text format
     pub fn parse(input: &str) -> Result<Self, crate::errors::ParseError> {
@@ -179,6 +202,11 @@ impl MT103 {
         false
     }
 
+    /// Typed reason of a `/RETN/` or `/REJT/` code word in field 72
+    pub fn return_reason(&self) -> Option<Field72ReturnReason> {
+        self.field_72.as_ref().and_then(Field72::return_reason)
+    }
+
     /// Check if this MT103 message is STP compliant
     pub fn is_stp_compliant(&self) -> bool {
         // Check if this is an STP message (SPRI, SSTD, or SPAY)
@@ -209,11 +237,398 @@ impl MT103 {
             return false;
         }
 
+        // E10: Beneficiary account is mandatory for STP
+        if !self.has_beneficiary_account() {
+            return false;
+        }
+
         // Additional STP validation rules could be added here
         // For now, return true if basic checks pass
         true
     }
 
+    /// Export the message as a fixed-width positional record using `layout`
+    pub fn to_fixed_width(&self, layout: &crate::FixedWidthLayout) -> String {
+        layout.render(&crate::traits::SwiftMessageBody::to_fields_with_variants(
+            self,
+        ))
+    }
+
+    /// Institutions present in fields 52, 53, 54, 56 and 57, in routing order
+    ///
+    /// Each entry carries the option tag (e.g. `"56A"`) and the BIC, location,
+    /// party identifier or name of the institution.
+    pub fn routing_chain(&self) -> Vec<(&'static str, InstitutionRef)> {
+        [
+            self.field_52.as_ref().map(|f| f.institution()),
+            self.field_53.as_ref().map(|f| f.institution()),
+            self.field_54.as_ref().map(|f| f.institution()),
+            self.field_56.as_ref().map(|f| f.institution()),
+            self.field_57.as_ref().map(|f| f.institution()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Check if field 59a carries a beneficiary account (subfield 1)
+    pub fn has_beneficiary_account(&self) -> bool {
+        match &self.field_59 {
+            Field59::NoOption(f) => f.account.is_some(),
+            Field59::A(f) => f.account.is_some(),
+            Field59::F(f) => f.party_identifier.is_some(),
+        }
+    }
+
+    /// Validate presence of the beneficiary account in field 59a (Error code: E10)
+    ///
+    /// A missing account is reported as a warning, or as an error when `stp` is set.
+    /// Messages using code CHQB in field 23E are exempt, as E18 forbids the account there.
+    pub fn validate_beneficiary_account(&self, stp: bool) -> crate::ValidationResult {
+        let is_cheque = self
+            .field_23e
+            .as_ref()
+            .is_some_and(|codes| codes.iter().any(|f| f.instruction_code == "CHQB"));
+
+        if is_cheque || self.has_beneficiary_account() {
+            return crate::ValidationResult::valid();
+        }
+
+        let error = SwiftValidationError::content_error(
+            "E10",
+            "59a",
+            "",
+            "Subfield 1 (Account) in field 59a (Beneficiary Customer) is missing",
+            "Subfield 1 (Account) of field 59a Beneficiary Customer is mandatory for STP",
+        );
+
+        if stp {
+            crate::ValidationResult::with_error(error.into())
+        } else {
+            let mut result = crate::ValidationResult::valid();
+            result.warnings.push(error.to_string());
+            result
+        }
+    }
+
+    /// Field 72 code words MT103 accepts with service-level codes SPRI, SSTD and SPAY in 23B
+    ///
+    /// These are STP service levels, so phone and telecom instructions (which need manual
+    /// handling) and the reject/return codes of the STP variant are excluded.
+    pub const MT103_STP_FIELD72_CODE_WORDS: &'static [&'static str] =
+        &["ACC", "BNF", "INS", "INT", "REC", "TSU"];
+
+    /// Field 72 code words allowed with the bank operation code in field 23B
+    ///
+    /// CRED and CRTS accept every code word of [`FIELD72_CODE_WORDS`].
+    pub fn field_72_code_words_for(bank_operation_code: &str) -> Vec<&'static str> {
+        match bank_operation_code {
+            "SPRI" | "SSTD" | "SPAY" => Self::MT103_STP_FIELD72_CODE_WORDS.to_vec(),
+            _ => FIELD72_CODE_WORDS.iter().map(|(code, _)| *code).collect(),
+        }
+    }
+
+    /// Validate the code words of field 72 against those allowed with field 23B
+    ///
+    /// Unknown code words are reported as warnings; see [`Field72::validate_code_words`].
+    pub fn validate_field_72_code_words(&self) -> crate::ValidationResult {
+        self.field_72
+            .as_ref()
+            .map_or_else(crate::ValidationResult::valid, |field| {
+                field.validate_code_words(&Self::field_72_code_words_for(
+                    &self.field_23b.instruction_code,
+                ))
+            })
+    }
+
+    /// Policy check: 71F charges in the currency of field 33B or 32A under BEN and SHA (C02)
+    ///
+    /// Not a SWIFT network rule, so `validate_network_rules` does not run it; call it where
+    /// a charges policy requires sender's charges in the instructed or settlement currency.
+    pub fn validate_sender_charges_currency_policy(&self) -> Vec<SwiftValidationError> {
+        if !matches!(self.field_71a.code, ChargeBearer::Ben | ChargeBearer::Sha) {
+            return Vec::new();
+        }
+
+        let currency_32a = &self.field_32a.currency;
+        let currency_33b = self.field_33b.as_ref().map(|field| &field.currency);
+        self.field_71f
+            .iter()
+            .flatten()
+            .filter(|field| {
+                &field.currency != currency_32a && Some(&field.currency) != currency_33b
+            })
+            .map(|field| {
+                SwiftValidationError::content_error(
+                    crate::swift_error_codes::c_series::C02,
+                    "71F",
+                    &field.currency,
+                    &format!(
+                        "Currency code in field 71F ({}) must be the same as in field 33B ({}) or 32A ({}) when field 71A is {}",
+                        field.currency,
+                        currency_33b.map_or("-", |currency| currency.as_str()),
+                        currency_32a,
+                        self.field_71a.code
+                    ),
+                    "Sender's charges deducted under BEN or SHA must be expressed in the instructed or settlement currency",
+                )
+            })
+            .collect()
+    }
+
+    /// Flag known field 72 code words that are not allowed with the code in field 23B (T25)
+    ///
+    /// Narrative lengths and unknown code words are left to `validate_field_72_code_words`.
+    fn validate_field_72_23b_code_words(&self) -> Vec<SwiftValidationError> {
+        let Some(ref field_72) = self.field_72 else {
+            return Vec::new();
+        };
+        let allowed = Self::field_72_code_words_for(&self.field_23b.instruction_code);
+
+        field_72
+            .code_words()
+            .into_iter()
+            .filter(|word| {
+                FIELD72_CODE_WORDS
+                    .iter()
+                    .any(|(code, _)| *code == word.code)
+                    && !allowed.contains(&word.code.as_str())
+            })
+            .map(|word| {
+                SwiftValidationError::content_error(
+                    "T25",
+                    "72",
+                    &word.code,
+                    &format!(
+                        "Code word /{}/ in field 72 is not allowed with field 23B code {}",
+                        word.code, self.field_23b.instruction_code
+                    ),
+                    "Field 72 code words must be allowed for the bank operation code in field 23B",
+                )
+            })
+            .collect()
+    }
+
+    /// Flag a field 32A value date more than `max_days` after today as a warning
+    ///
+    /// A bank-policy check for fat-finger dates (e.g. wrong year), not a network rule.
+    pub fn validate_value_date_horizon(&self, max_days: i64) -> crate::ValidationResult {
+        let today = chrono::Utc::now().date_naive();
+        let days_ahead = (self.field_32a.value_date - today).num_days();
+
+        let mut result = crate::ValidationResult::valid();
+        if days_ahead > max_days {
+            result.warnings.push(format!(
+                "Value date {} in field 32A is {} days ahead, beyond the {}-day horizon",
+                self.field_32a.value_date, days_ahead, max_days
+            ));
+        }
+        result
+    }
+
+    /// Flag a field 32A value date more than `max_days_back` before today as a warning
+    ///
+    /// The counterpart of `validate_value_date_horizon` for backdated same-day payments;
+    /// also a policy screen rather than a network rule.
+    pub fn validate_backdated_value(&self, max_days_back: i64) -> crate::ValidationResult {
+        let today = chrono::Utc::now().date_naive();
+        let days_back = (today - self.field_32a.value_date).num_days();
+
+        let mut result = crate::ValidationResult::valid();
+        if days_back > max_days_back {
+            result.warnings.push(format!(
+                "Value date {} in field 32A is {} days in the past, beyond the {}-day window",
+                self.field_32a.value_date, days_back, max_days_back
+            ));
+        }
+        result
+    }
+
+    /// Fields that differ from `original`, as `(tag, value)` in SWIFT form
+    ///
+    /// Changed and added fields carry their new value in message order, followed by removed
+    /// fields with `None`. Repeated tags are compared occurrence by occurrence, and a change
+    /// of option letter (59 to 59A) shows as a removal plus an addition.
+    pub fn to_field_patch(&self, original: &MT103) -> Vec<(String, Option<String>)> {
+        use crate::traits::SwiftMessageBody;
+
+        fn numbered(fields: Vec<(String, String)>) -> Vec<((String, usize), String)> {
+            let mut seen: HashMap<String, usize> = HashMap::new();
+            fields
+                .into_iter()
+                .map(|(tag, value)| {
+                    let occurrence = seen.entry(tag.clone()).or_default();
+                    *occurrence += 1;
+                    ((tag, *occurrence), value)
+                })
+                .collect()
+        }
+
+        let current = numbered(self.to_fields_with_variants());
+        let previous = numbered(original.to_fields_with_variants());
+
+        let mut patch: Vec<(String, Option<String>)> = current
+            .iter()
+            .filter(|(key, value)| {
+                previous
+                    .iter()
+                    .find(|(previous_key, _)| previous_key == key)
+                    .is_none_or(|(_, previous_value)| previous_value != value)
+            })
+            .map(|((tag, _), value)| (tag.clone(), Some(value.clone())))
+            .collect();
+        patch.extend(
+            previous
+                .iter()
+                .filter(|(key, _)| !current.iter().any(|(current_key, _)| current_key == key))
+                .map(|((tag, _), _)| (tag.clone(), None)),
+        );
+        patch
+    }
+
+    /// Transaction type (26T) and regulatory reporting entries (77B) in one view
+    pub fn regulatory_info(&self) -> RegulatoryInfo {
+        RegulatoryInfo {
+            transaction_type: self.field_26t.as_ref().map(|field| field.type_code.clone()),
+            entries: self
+                .field_77b
+                .as_ref()
+                .map(Field77B::entries)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Column names of [`MT103::to_csv_record`], in record order
+    ///
+    /// Columns are only ever appended, so positional readers keep working across releases.
+    pub const CSV_HEADER: &'static [&'static str] = &[
+        "reference",
+        "value_date",
+        "currency",
+        "amount",
+        "ordering_customer",
+        "beneficiary",
+        "charges",
+    ];
+
+    /// Flat reporting record matching [`MT103::CSV_HEADER`]
+    ///
+    /// - `reference`: field 20
+    /// - `value_date`, `currency`: field 32A, the date as `YYYY-MM-DD`
+    /// - `amount`: field 32A with a `.` decimal point and the currency's decimal places
+    /// - `ordering_customer`, `beneficiary`: first name line of 50a / 59a (without a
+    ///   `1/` line number), or the BIC when the party has no name
+    /// - `charges`: field 71A code
+    pub fn to_csv_record(&self) -> Vec<String> {
+        fn first_name(lines: &[String]) -> String {
+            lines
+                .first()
+                .map(|line| line.strip_prefix("1/").unwrap_or(line).to_string())
+                .unwrap_or_default()
+        }
+
+        let ordering_customer = match &self.field_50 {
+            Field50OrderingCustomerAFK::A(field) => first_name(&field.name_and_address),
+            Field50OrderingCustomerAFK::F(field) => field
+                .name_and_address
+                .as_deref()
+                .map(first_name)
+                .unwrap_or_else(|| field.bic.clone()),
+            Field50OrderingCustomerAFK::K(field) => first_name(&field.name_and_address),
+            Field50OrderingCustomerAFK::NoOption(field) => first_name(&field.name_and_address),
+        };
+        let beneficiary = match &self.field_59 {
+            Field59::A(field) => field.bic.clone(),
+            Field59::F(field) => first_name(&field.name_and_address),
+            Field59::NoOption(field) => first_name(&field.name_and_address),
+        };
+        let decimals = swift_utils::get_currency_decimals(&self.field_32a.currency) as usize;
+
+        vec![
+            self.field_20.reference.clone(),
+            self.field_32a.value_date.format("%Y-%m-%d").to_string(),
+            self.field_32a.currency.clone(),
+            format!("{:.*}", decimals, self.field_32a.amount),
+            ordering_customer,
+            beneficiary,
+            self.field_71a.code.as_str().to_string(),
+        ]
+    }
+
+    /// Write a header row followed by one [`MT103::to_csv_record`] row per message
+    #[cfg(feature = "csv")]
+    pub fn write_csv<W: std::io::Write>(
+        writer: &mut csv::Writer<W>,
+        messages: &[MT103],
+    ) -> csv::Result<()> {
+        writer.write_record(Self::CSV_HEADER)?;
+        for message in messages {
+            writer.write_record(message.to_csv_record())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Whether the ordering customer (50a) and beneficiary (59a) are the same party
+    ///
+    /// Accounts are compared when both sides carry one, otherwise BICs (first 8 characters).
+    /// A by-name party cannot be compared reliably and is never flagged.
+    pub fn detect_self_payment(&self) -> bool {
+        let normalize = |value: &str| {
+            value
+                .trim_start_matches('/')
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_uppercase()
+        };
+        let (ordering_account, ordering_bic) = self.field_50.account_and_bic();
+        let (beneficiary_account, beneficiary_bic) = self.field_59.account_and_bic();
+
+        match (
+            ordering_account,
+            beneficiary_account,
+            ordering_bic,
+            beneficiary_bic,
+        ) {
+            (Some(ordering), Some(beneficiary), _, _) => {
+                normalize(ordering) == normalize(beneficiary)
+            }
+            (_, _, Some(ordering), Some(beneficiary)) => {
+                ordering.get(..8).unwrap_or(ordering) == beneficiary.get(..8).unwrap_or(beneficiary)
+            }
+            _ => false,
+        }
+    }
+
+    /// Flag a self-payment (see `detect_self_payment`) as a warning
+    pub fn validate_self_payment(&self) -> crate::ValidationResult {
+        let mut result = crate::ValidationResult::valid();
+        if self.detect_self_payment() {
+            result.warnings.push(
+                "Ordering customer (field 50a) and beneficiary (field 59a) identify the same party"
+                    .to_string(),
+            );
+        }
+        result
+    }
+
+    /// Drop field 36 when it is not allowed, so C1 (D75) holds by construction
+    ///
+    /// Field 36 is removed when 33B is absent or shares the 32A currency. When the
+    /// currencies differ and field 36 is missing, the D75 error is returned, since the
+    /// rate cannot be derived.
+    pub fn normalize_exchange_rate(&mut self) -> Result<(), SwiftValidationError> {
+        let currencies_differ = self
+            .field_33b
+            .as_ref()
+            .is_some_and(|field_33b| field_33b.currency != self.field_32a.currency);
+        if !currencies_differ {
+            self.field_36 = None;
+        }
+        self.validate_c1_currency_exchange().map_or(Ok(()), Err)
+    }
+
     // ========================================================================
     // NETWORK VALIDATION RULES (SR 2025 MT103 STP & REMIT)
     // ========================================================================
@@ -437,17 +852,127 @@ impl MT103 {
             ));
         }
 
+        if (bank_op_code == "SSTD" || bank_op_code == "SPAY")
+            && matches!(self.field_56, Some(Field56Intermediary::D(_)))
+        {
+            return Some(SwiftValidationError::content_error(
+                "E17",
+                "56D",
+                "",
+                &format!(
+                    "Field 56a must use option A or C when field 23B is {}",
+                    bank_op_code
+                ),
+                "If field 23B contains one of the codes SSTD or SPAY, field 56a may be used with either option A or option C",
+            ));
+        }
+
         None
     }
 
+    /// C2/C5/C6/C8/C11: Field 23B Routing Option Restrictions (Error codes: E03, E04, E05, E07, E09)
+    /// If field 23B is SPRI, SSTD or SPAY, fields 53a, 54a, 55a and 57a are limited in the
+    /// options they may use
+    fn validate_23b_routing_options(&self) -> Vec<SwiftValidationError> {
+        let mut errors = Vec::new();
+        let bank_op_code = self.field_23b.instruction_code.as_str();
+
+        if !matches!(bank_op_code, "SPRI" | "SSTD" | "SPAY") {
+            return errors;
+        }
+
+        match &self.field_53 {
+            Some(Field53SenderCorrespondent::D(_)) => {
+                errors.push(SwiftValidationError::content_error(
+                    "E03",
+                    "53D",
+                    "",
+                    &format!(
+                        "Field 53a must not use option D when field 23B is {}",
+                        bank_op_code
+                    ),
+                    "If field 23B contains one of the codes SPRI, SSTD or SPAY, field 53a must not be used with option D",
+                ));
+            }
+            Some(Field53SenderCorrespondent::B(field_53b))
+                if field_53b.party_identifier.is_none() =>
+            {
+                errors.push(SwiftValidationError::content_error(
+                    "E04",
+                    "53B",
+                    "",
+                    &format!(
+                        "Field 53B must contain a party identifier when field 23B is {}",
+                        bank_op_code
+                    ),
+                    "If field 23B contains one of the codes SPRI, SSTD or SPAY and field 53a is present with option B, Party Identifier must be present",
+                ));
+            }
+            _ => {}
+        }
+
+        if matches!(
+            self.field_54,
+            Some(Field54ReceiverCorrespondent::B(_) | Field54ReceiverCorrespondent::D(_))
+        ) {
+            errors.push(SwiftValidationError::content_error(
+                "E05",
+                "54a",
+                "",
+                &format!(
+                    "Field 54a may only use option A when field 23B is {}",
+                    bank_op_code
+                ),
+                "If field 23B contains one of the codes SPRI, SSTD or SPAY, field 54a may be used with option A only",
+            ));
+        }
+
+        if matches!(
+            self.field_55,
+            Some(
+                Field55ThirdReimbursementInstitution::B(_)
+                    | Field55ThirdReimbursementInstitution::D(_)
+            )
+        ) {
+            errors.push(SwiftValidationError::content_error(
+                "E07",
+                "55a",
+                "",
+                &format!(
+                    "Field 55a may only use option A when field 23B is {}",
+                    bank_op_code
+                ),
+                "If field 23B contains one of the codes SPRI, SSTD or SPAY, field 55a may be used with option A only",
+            ));
+        }
+
+        let invalid_57 = match &self.field_57 {
+            Some(Field57::B(_)) => true,
+            Some(Field57::D(field_57d)) => field_57d.party_identifier.is_none(),
+            _ => false,
+        };
+        if invalid_57 {
+            errors.push(SwiftValidationError::content_error(
+                "E09",
+                "57a",
+                "",
+                &format!(
+                    "Field 57a must use option A, C or D with a party identifier when field 23B is {}",
+                    bank_op_code
+                ),
+                "If field 23B contains one of the codes SPRI, SSTD or SPAY, field 57a may be used with option A, option C or option D. Subfield 1 (Party Identifier) in option D must be present",
+            ));
+        }
+
+        errors
+    }
+
     /// C7 (C14): Details of Charges and Sender's/Receiver's Charges (Error codes: E13, D50, E15)
     /// Complex rules for fields 71A, 71F, and 71G
     fn validate_c7_charges(&self) -> Vec<SwiftValidationError> {
         let mut errors = Vec::new();
-        let charges_code = &self.field_71a.code;
-
-        match charges_code.as_str() {
-            "OUR" => {
+        match self.field_71a.code {
+            ChargeBearer::Our => {
                 // If 71A is OUR, field 71F is not allowed, field 71G is optional
                 if self.has_field_71f() {
                     errors.push(SwiftValidationError::content_error(
@@ -459,7 +984,7 @@ impl MT103 {
                     ));
                 }
             }
-            "SHA" => {
+            ChargeBearer::Sha => {
                 // If 71A is SHA, field 71F is optional, field 71G is not allowed
                 if self.has_field_71g() {
                     errors.push(SwiftValidationError::content_error(
@@ -471,7 +996,7 @@ impl MT103 {
                     ));
                 }
             }
-            "BEN" => {
+            ChargeBearer::Ben => {
                 // If 71A is BEN, at least one occurrence of 71F is mandatory, 71G is not allowed
                 if !self.has_field_71f() {
                     errors.push(SwiftValidationError::content_error(
@@ -492,7 +1017,6 @@ impl MT103 {
                     ));
                 }
             }
-            _ => {}
         }
 
         errors
@@ -762,6 +1286,11 @@ impl MT103 {
     }
 
     /// Main validation method - validates all network rules
+    ///
+    /// **Note**: the correspondent chain (53a/54a/55a) is covered by C4 (E06) and the 23B
+    /// option rules (E03, E04, E05, E07). SR 2025 defines no rule making 53a or 54a depend
+    /// on field 52a or on the currency, so none is checked.
+    ///
     /// Returns array of validation errors, respects stop_on_first_error flag
     pub fn validate_network_rules(&self, stop_on_first_error: bool) -> Vec<SwiftValidationError> {
         let mut all_errors = Vec::new();
@@ -820,6 +1349,20 @@ impl MT103 {
             }
         }
 
+        // Field 23B routing option restrictions on 53a, 54a, 55a and 57a
+        let routing_errors = self.validate_23b_routing_options();
+        all_errors.extend(routing_errors);
+        if stop_on_first_error && !all_errors.is_empty() {
+            return all_errors;
+        }
+
+        // Field 72 code words allowed with field 23B
+        let code_word_errors = self.validate_field_72_23b_code_words();
+        all_errors.extend(code_word_errors);
+        if stop_on_first_error && !all_errors.is_empty() {
+            return all_errors;
+        }
+
         // C7 (C14): Details of Charges
         let c7_errors = self.validate_c7_charges();
         all_errors.extend(c7_errors);
@@ -866,6 +1409,68 @@ impl MT103 {
     }
 }
 
+impl crate::SwiftMessage<MT103> {
+    /// Validate against a usage guideline on top of the base network rules
+    ///
+    /// Lives on the full message because gpi and CBPR+ rules read the UETR from block 3.
+    /// Profile violations are reported as G-series errors after the base report.
+    pub fn validate_profile(&self, profile: UsageProfile) -> crate::ValidationReport {
+        use crate::swift_error_codes::g_series;
+        use crate::traits::SwiftMessageBody;
+
+        let mut report = self.fields.validate_all();
+        let category = match profile {
+            UsageProfile::Core => return report,
+            UsageProfile::Gpi => "gpi",
+            UsageProfile::CbprPlus => "CBPR+",
+        };
+
+        let has_uetr = self
+            .user_header
+            .as_ref()
+            .is_some_and(|header| header.unique_end_to_end_reference.is_some());
+        if !has_uetr {
+            report.errors.push(SwiftValidationError::general_error(
+                g_series::G016,
+                "121",
+                "",
+                &format!("UETR (block 3 tag 121) is mandatory under {category}"),
+                Some(category),
+            ));
+        }
+
+        if profile == UsageProfile::CbprPlus {
+            const ALLOWED_OPTIONS: &[(&str, &[&str])] = &[
+                ("50", &["A", "F"]),
+                ("59", &["A", "F"]),
+                ("52", &["A"]),
+                ("56", &["A"]),
+                ("57", &["A"]),
+            ];
+            for (tag, value) in self.fields.to_fields_with_variants() {
+                let (number, option) = tag.split_at(tag.len().min(2));
+                let Some((_, allowed)) = ALLOWED_OPTIONS.iter().find(|(n, _)| *n == number) else {
+                    continue;
+                };
+                if !allowed.contains(&option) {
+                    report.errors.push(SwiftValidationError::general_error(
+                        g_series::G023,
+                        &tag,
+                        &value,
+                        &format!(
+                            "Field {tag} is not allowed under {category}; use option {}",
+                            allowed.join(" or ")
+                        ),
+                        Some(category),
+                    ));
+                }
+            }
+        }
+
+        report
+    }
+}
+
 impl crate::traits::SwiftMessageBody for MT103 {
     fn message_type() -> &'static str {
         "103"
@@ -986,6 +1591,75 @@ impl crate::traits::SwiftMessageBody for MT103 {
         })
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional_repeated("13C", &self.field_13c)
+            .field("23B", &self.field_23b)
+            .optional_repeated("23E", &self.field_23e)
+            .optional("26T", &self.field_26t)
+            .field("32A", &self.field_32a)
+            .optional("33B", &self.field_33b)
+            .optional("36", &self.field_36)
+            .field("50", &self.field_50)
+            .optional("51A", &self.field_51a)
+            .optional("52", &self.field_52)
+            .optional("53", &self.field_53)
+            .optional("54", &self.field_54)
+            .optional("55", &self.field_55)
+            .optional("56", &self.field_56)
+            .optional("57", &self.field_57)
+            .field("59", &self.field_59)
+            .optional("70", &self.field_70)
+            .field("71A", &self.field_71a)
+            .optional_repeated("71F", &self.field_71f)
+            .optional("71G", &self.field_71g)
+            .optional("72", &self.field_72)
+            .optional("77B", &self.field_77b)
+            .optional("77T", &self.field_77t);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("13C", "/8c/4!n1!x4!n", "Time Indication")
+                .optional()
+                .max(5),
+            FieldSpec::new("23B", "4!c", "Bank Operation Code"),
+            FieldSpec::new("23E", "4!c[/35x]", "Instruction Code")
+                .optional()
+                .max(15),
+            FieldSpec::new("26T", "3!c", "Transaction Type Code").optional(),
+            FieldSpec::new(
+                "32A",
+                "6!n3!a15d",
+                "Value Date/Currency/Interbank Settled Amount",
+            ),
+            FieldSpec::new("33B", "3!a15d", "Currency/Instructed Amount").optional(),
+            FieldSpec::new("36", "12d", "Exchange Rate").optional(),
+            FieldSpec::new("50a", "Option A, F or K", "Ordering Customer"),
+            FieldSpec::new("51A", "[/1!a][/34x]4!a2!a2!c[3!c]", "Sending Institution").optional(),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("53a", "Option A, B or D", "Sender's Correspondent").optional(),
+            FieldSpec::new("54a", "Option A, B or D", "Receiver's Correspondent").optional(),
+            FieldSpec::new("55a", "Option A, B or D", "Third Reimbursement Institution").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary Institution").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("59a", "No letter option, A or F", "Beneficiary Customer"),
+            FieldSpec::new("70", "4*35x", "Remittance Information").optional(),
+            FieldSpec::new("71A", "3!a", "Details of Charges"),
+            FieldSpec::new("71F", "3!a15d", "Sender's Charges")
+                .optional()
+                .max(10),
+            FieldSpec::new("71G", "3!a15d", "Receiver's Charges").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+            FieldSpec::new("77B", "3*35x", "Regulatory Reporting").optional(),
+            FieldSpec::new("77T", "9000z", "Envelope Contents").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT103::to_mt_string(self)
@@ -997,10 +1671,323 @@ impl crate::traits::SwiftMessageBody for MT103 {
     }
 }
 
+impl crate::traits::CurrencyInfo for MT103 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32a.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        let mut currencies = BTreeSet::from([self.field_32a.currency.clone()]);
+        currencies.extend(self.field_33b.iter().map(|f| f.currency.clone()));
+        currencies.extend(self.field_71f.iter().flatten().map(|f| f.currency.clone()));
+        currencies.extend(self.field_71g.iter().map(|f| f.currency.clone()));
+        currencies
+    }
+}
+
+impl crate::traits::CommonFields for MT103 {
+    fn reference(&self) -> &str {
+        &self.field_20.reference
+    }
+
+    fn value_date_amount(&self) -> &Field32A {
+        &self.field_32a
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_mt103_field_72_code_words() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n\
+             :59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n:72:/ACC/PAY VIA BRANCH 12\n-";
+        let mut mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        let result = mt103.validate_field_72_code_words();
+        assert!(result.is_valid && result.warnings.is_empty());
+
+        mt103.field_72 = Some(Field72 {
+            information: vec![
+                "/TELE/CONFIRM BY TELEX".to_string(),
+                "//BEFORE RELEASE OF FUNDS".to_string(),
+            ],
+        });
+        let result = mt103.validate_field_72_code_words();
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+
+        assert!(mt103.return_reason().is_none());
+        mt103.field_72 = Some(<Field72 as crate::traits::SwiftField>::parse("/RETN/AC04").unwrap());
+        assert_eq!(
+            mt103.return_reason().unwrap().reason,
+            ReturnReasonCode::ClosedAccountNumber
+        );
+    }
+
+    #[test]
+    fn test_mt103_field_72_code_words_by_23b() {
+        let block4 = ":20:TXN123456\n:23B:SSTD\n:32A:240315USD1000,00\n:50K:JOHN DOE\n\
+             :59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n:72:/PHON/CALL BENEFICIARY\n-";
+        let mut mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        assert!(!mt103.validate_field_72_code_words().is_valid);
+        let errors = mt103.validate_network_rules(false);
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.code() == "T25" && e.field() == "72")
+        );
+
+        // The same instruction is allowed with CRED
+        mt103.field_23b.instruction_code = "CRED".to_string();
+        assert!(mt103.validate_field_72_code_words().is_valid);
+        assert!(
+            !mt103
+                .validate_network_rules(false)
+                .iter()
+                .any(|e| e.field() == "72")
+        );
+    }
+
+    #[test]
+    fn test_mt103_value_date_horizon() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n\
+             :59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-";
+        let mut mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        let today = chrono::Utc::now().date_naive();
+
+        mt103.field_32a.value_date = today + chrono::Duration::days(365);
+        let result = mt103.validate_value_date_horizon(30);
+        assert!(result.is_valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("365 days ahead"));
+
+        mt103.field_32a.value_date = today + chrono::Duration::days(2);
+        assert!(mt103.validate_value_date_horizon(30).warnings.is_empty());
+    }
+
+    #[test]
+    fn test_mt103_backdated_value() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n\
+             :59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-";
+        let mut mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        let today = chrono::Utc::now().date_naive();
+
+        mt103.field_32a.value_date = today - chrono::Duration::days(10);
+        let result = mt103.validate_backdated_value(2);
+        assert!(result.is_valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("10 days in the past"));
+
+        mt103.field_32a.value_date = today - chrono::Duration::days(1);
+        assert!(mt103.validate_backdated_value(2).warnings.is_empty());
+        mt103.field_32a.value_date = today + chrono::Duration::days(5);
+        assert!(mt103.validate_backdated_value(2).warnings.is_empty());
+    }
+
+    #[test]
+    fn test_mt103_to_fixed_width() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n\
+             :59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-";
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        let layout = crate::FixedWidthLayout::new()
+            .column("20", 0, 16)
+            .column("32A", 16, 24)
+            .column("59", 40, 30)
+            .column("72", 70, 5);
+
+        let record = mt103.to_fixed_width(&layout);
+        assert_eq!(record.chars().count(), 75);
+        assert_eq!(&record[0..16], "TXN123456       ");
+        assert_eq!(&record[16..40], "240315USD1000,00        ");
+        // Multi-line value joined and truncated to the column width
+        assert_eq!(&record[40..70], "/DE89370400440532013000 BENEFI");
+        assert_eq!(&record[70..75], "     ");
+    }
+
+    #[test]
+    fn test_mt103_field_views() {
+        use crate::traits::SwiftMessageBody;
+
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:/DE89370400440532013000\nJOHN DOE\n:59:/GB29NWBK60161331926819\nJANE ROE\n:71A:SHA\n:71F:EUR5,\n:71F:EUR2,50\n-";
+        let mt103 = MT103::parse_from_block4(block4).unwrap();
+
+        // The view borrows the field in place
+        let reference = mt103.field("20").unwrap().downcast::<Field20>().unwrap();
+        assert!(std::ptr::eq(reference, &mt103.field_20));
+
+        let ordering = mt103.field("50K").unwrap();
+        assert_eq!(ordering.variant(), Some("K"));
+        assert!(std::ptr::eq(
+            ordering.downcast::<Field50OrderingCustomerAFK>().unwrap(),
+            &mt103.field_50
+        ));
+        assert!(mt103.field("50A").is_none());
+        assert_eq!(
+            mt103.field("32A").unwrap().to_swift_string(),
+            ":32A:240315USD1000,00"
+        );
+        assert!(mt103.field("72").is_none());
+
+        let views = mt103.field_views();
+        assert_eq!(views.iter().filter(|view| view.tag == "71F").count(), 2);
+        assert_eq!(views.len(), 8);
+    }
+
+    #[test]
+    fn test_mt103_to_field_patch() {
+        use crate::traits::SwiftMessageBody;
+
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n:72:/ACC/INFO\n-";
+        let original = MT103::parse_from_block4(block4).unwrap();
+        assert!(original.to_field_patch(&original).is_empty());
+
+        let mut amended = original.clone();
+        amended.field_59 = <Field59 as crate::traits::SwiftField>::parse_with_variant(
+            "/GB29NWBK60161331926819\nJANE ROE",
+            None,
+            Some("59"),
+        )
+        .unwrap();
+        assert_eq!(
+            amended.to_field_patch(&original),
+            vec![(
+                "59".to_string(),
+                Some("/GB29NWBK60161331926819\nJANE ROE".to_string())
+            )]
+        );
+
+        amended.field_72 = None;
+        amended.field_70 =
+            Some(<Field70 as crate::traits::SwiftField>::parse("INVOICE 42").unwrap());
+        let patch = amended.to_field_patch(&original);
+        assert_eq!(patch.len(), 3);
+        assert!(patch.contains(&("70".to_string(), Some("INVOICE 42".to_string()))));
+        assert_eq!(patch.last(), Some(&("72".to_string(), None)));
+    }
+
+    #[test]
+    fn test_mt103_csv_record() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315JPY1500000,\n:50K:/DE89370400440532013000\nJOHN DOE\nMAIN STREET 1\n:59:/GB29NWBK60161331926819\nJANE ROE\n:71A:OUR\n-";
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        let record = mt103.to_csv_record();
+        assert_eq!(record.len(), MT103::CSV_HEADER.len());
+        assert_eq!(
+            record,
+            vec![
+                "TXN123456",
+                "2024-03-15",
+                "JPY",
+                "1500000",
+                "JOHN DOE",
+                "JANE ROE",
+                "OUR"
+            ]
+        );
+
+        #[cfg(feature = "csv")]
+        {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            MT103::write_csv(&mut writer, &[mt103.clone(), mt103]).unwrap();
+            let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+            let lines: Vec<&str> = output.lines().collect();
+            assert_eq!(
+                lines[0],
+                "reference,value_date,currency,amount,ordering_customer,beneficiary,charges"
+            );
+            assert_eq!(lines.len(), 3);
+            assert_eq!(
+                lines[2],
+                "TXN123456,2024-03-15,JPY,1500000,JOHN DOE,JANE ROE,OUR"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mt103_regulatory_info() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:26T:K90\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n:77B:/ORDERRES/BE//MEILAAN 1\n//9000 GENT\n-";
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+
+        let info = mt103.regulatory_info();
+        assert_eq!(info.transaction_type.as_deref(), Some("K90"));
+        assert_eq!(
+            info.entries,
+            vec![Field77BEntry {
+                code: "ORDERRES".to_string(),
+                country: Some("BE".to_string()),
+                narrative: vec!["MEILAAN 1".to_string(), "9000 GENT".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_mt103_detect_self_payment() {
+        let message = |field_50: &str, field_59: &str| {
+            let block4 = format!(
+                ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n{field_50}\n{field_59}\n:71A:SHA\n-"
+            );
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&block4).unwrap()
+        };
+
+        let same = message(
+            ":50K:/DE89370400440532013000\nJOHN DOE",
+            ":59:/DE89 3704 0044 0532 0130 00\nJOHN DOE",
+        );
+        assert!(same.detect_self_payment());
+        assert_eq!(same.validate_self_payment().warnings.len(), 1);
+
+        let different = message(
+            ":50K:/DE89370400440532013000\nJOHN DOE",
+            ":59:/GB29NWBK60161331926819\nJANE ROE",
+        );
+        assert!(!different.detect_self_payment());
+        assert!(different.validate_self_payment().warnings.is_empty());
+
+        // By-name ordering customer against a by-BIC beneficiary cannot be compared
+        let by_name = message(":50K:JOHN DOE", ":59A:DEUTDEFF");
+        assert!(!by_name.detect_self_payment());
+    }
+
+    #[test]
+    fn test_mt103_routing_chain() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n\
+             :52A:ORDBUS33\n:56A:/CHIPS1234\nINTMUS33\n:57A:AWIBGB2L\n\
+             :59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-";
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+
+        assert_eq!(
+            mt103.routing_chain(),
+            vec![
+                ("52A", InstitutionRef::Bic("ORDBUS33".to_string())),
+                ("56A", InstitutionRef::Bic("INTMUS33".to_string())),
+                ("57A", InstitutionRef::Bic("AWIBGB2L".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mt103_legacy_field_50_without_option() {
+        let block4 = ":20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50:JOHN DOE\n123 MAIN ST\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-";
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+
+        match &mt103.field_50 {
+            Field50OrderingCustomerAFK::NoOption(field) => {
+                assert_eq!(field.name_and_address, vec!["JOHN DOE", "123 MAIN ST"]);
+            }
+            other => panic!("expected no-option field 50, got {other:?}"),
+        }
+        let serialized = mt103.to_mt_string();
+        assert!(
+            serialized.contains(":50:JOHN DOE\n123 MAIN ST"),
+            "{serialized}"
+        );
+        assert!(!serialized.contains(":50K:"));
+    }
+
     #[test]
     fn test_mt103_parse() {
         let mt103_text = r#":20:123456789012345
@@ -1021,7 +2008,7 @@ LOS ANGELES, CA 90001
         let mt103 = result.unwrap();
         assert_eq!(mt103.field_20.reference, "123456789012345");
         assert_eq!(mt103.field_23b.instruction_code, "CRED");
-        assert_eq!(mt103.field_71a.code, "OUR");
+        assert_eq!(mt103.field_71a.code, ChargeBearer::Our);
     }
 
     #[test]
@@ -1046,4 +2033,256 @@ LOS ANGELES, CA 90001
         // SPRI message without field 56 should be STP compliant
         assert!(mt103.is_stp_compliant());
     }
+
+    #[test]
+    fn test_mt103_bank_operation_code_rules() {
+        let base = ":20:REF1\n:23B:SPRI\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:OUR\n-";
+
+        // SPRI with a 23E code outside SDVA, TELB, PHOB, INTC
+        let spri_with_hold = base.replace(":32A:", ":23E:HOLD\n:32A:");
+        let mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&spri_with_hold).unwrap();
+        let errors = mt103.validate_network_rules(false);
+        assert!(errors.iter().any(|e| e.code() == "E01"));
+
+        // SPRI with an allowed 23E code
+        let spri_with_sdva = base.replace(":32A:", ":23E:SDVA\n:32A:");
+        let mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&spri_with_sdva).unwrap();
+        assert!(mt103.validate_network_rules(false).is_empty());
+
+        // SSTD restricts 53a to options other than D and 54a to option A
+        let sstd_routing = base.replace(":23B:SPRI", ":23B:SSTD").replace(
+            ":59:",
+            ":53D:CORRESPONDENT BANK\n:54B:LONDON\n:57D:/123456\nACCOUNT BANK\n:59:",
+        );
+        let mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&sstd_routing).unwrap();
+        let errors = mt103.validate_network_rules(false);
+        let codes: Vec<&str> = errors.iter().map(|e| e.code()).collect();
+        assert_eq!(codes, vec!["E03", "E05"]);
+    }
+
+    #[test]
+    fn test_mt103_sender_charges_currency_policy() {
+        let base = ":20:REF1\n:23B:CRED\n:32A:241201USD990,00\n:33B:USD1000,00\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:SHA\n:71F:USD10,00\n-";
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(base).unwrap();
+        assert!(mt103.validate_sender_charges_currency_policy().is_empty());
+
+        let foreign = base.replace(":71F:USD10,00", ":71F:USD5,00\n:71F:EUR5,00");
+        let mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&foreign).unwrap();
+        let errors = mt103.validate_sender_charges_currency_policy();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), "C02");
+        assert!(errors[0].message().contains("EUR"));
+
+        // A policy check only: network validation accepts the foreign charges currency
+        assert!(mt103.validate_network_rules(false).is_empty());
+
+        let ben = foreign.replace(":71A:SHA", ":71A:BEN");
+        let mt103 = <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&ben).unwrap();
+        assert_eq!(mt103.validate_sender_charges_currency_policy().len(), 1);
+    }
+
+    #[test]
+    fn test_mt103_normalize_exchange_rate() {
+        let base = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:33B:USD1000,00\n:36:0,9\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:OUR\n-";
+        let mut mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(base).unwrap();
+        assert!(mt103.validate_c1_currency_exchange().is_some());
+        assert!(mt103.normalize_exchange_rate().is_ok());
+        assert!(mt103.field_36.is_none());
+        assert!(mt103.validate_network_rules(false).is_empty());
+
+        let converted = base.replace(":33B:USD", ":33B:EUR");
+        let mut mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(&converted).unwrap();
+        assert!(mt103.normalize_exchange_rate().is_ok());
+        assert!(mt103.field_36.is_some());
+
+        mt103.field_36 = None;
+        let error = mt103.normalize_exchange_rate().unwrap_err();
+        assert_eq!(error.code(), "D75");
+    }
+
+    #[test]
+    fn test_mt103_validate_profile() {
+        let raw = "{1:F01BANKDEFFAXXX0000000000}{2:I103BANKGB2LXXXXN}{4:\n:20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:OUR\n-}";
+        let mut message = crate::SwiftParser::parse::<MT103>(raw).unwrap();
+        assert!(message.validate_profile(UsageProfile::Core).is_valid());
+
+        let gpi = message.validate_profile(UsageProfile::Gpi);
+        assert_eq!(gpi.codes(), vec!["G016"]);
+
+        message.ensure_uetr();
+        assert!(message.validate_profile(UsageProfile::Gpi).is_valid());
+        let cbpr = message.validate_profile(UsageProfile::CbprPlus);
+        assert_eq!(cbpr.codes(), vec!["G023", "G023"]);
+        assert_eq!(cbpr.errors[0].field(), "50K");
+    }
+
+    #[test]
+    fn test_mt103_beneficiary_account() {
+        let with_account = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:OUR\n-";
+        let without_account = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59:JANE SMITH\n:71A:OUR\n-";
+        let option_a_without_account = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59A:DEUTDEFFXXX\n:71A:OUR\n-";
+
+        let parse = |text: &str| {
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(text).unwrap()
+        };
+
+        let mt103 = parse(with_account);
+        for stp in [false, true] {
+            let result = mt103.validate_beneficiary_account(stp);
+            assert!(result.is_valid);
+            assert!(result.warnings.is_empty());
+        }
+
+        for text in [without_account, option_a_without_account] {
+            let mt103 = parse(text);
+
+            let normal = mt103.validate_beneficiary_account(false);
+            assert!(normal.is_valid);
+            assert_eq!(normal.warnings.len(), 1);
+            assert!(normal.warnings[0].contains("E10"));
+
+            let stp = mt103.validate_beneficiary_account(true);
+            assert!(!stp.is_valid);
+            assert_eq!(stp.errors.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_mt103_occurrence_limits() {
+        use crate::traits::SwiftMessageBody;
+
+        let instructions = |count: usize| ":23E:PHOB/CALL BENEFICIARY\n".repeat(count);
+        let block4 = |count: usize| {
+            format!(
+                ":20:TXN123456\n:23B:CRED\n{}:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n-",
+                instructions(count)
+            )
+        };
+
+        let within = MT103::parse_from_block4(&block4(15)).unwrap();
+        assert!(within.validate_occurrence_limits().is_empty());
+
+        let over = MT103::parse_from_block4(&block4(16)).unwrap();
+        let errors = over.validate_occurrence_limits();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), "T11");
+        assert!(errors[0].to_string().contains("23E occurs 16 times"));
+        assert!(
+            over.validate_all()
+                .errors
+                .iter()
+                .any(|error| error.code() == "T11")
+        );
+
+        // 13C and 71F are limited as well
+        let block4 = format!(
+            ":20:TXN123456\n{}:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nJANE ROE\n:71A:BEN\n{}-",
+            ":13C:/SNDTIME/1200+0100\n".repeat(6),
+            ":71F:EUR1,00\n".repeat(11)
+        );
+        let over = MT103::parse_from_block4(&block4).unwrap();
+        let errors = over.validate_occurrence_limits();
+        assert_eq!(errors.len(), 2);
+        assert!(
+            errors[0]
+                .to_string()
+                .contains("13C occurs 6 times, maximum is 5")
+        );
+        assert!(
+            errors[1]
+                .to_string()
+                .contains("71F occurs 11 times, maximum is 10")
+        );
+    }
+
+    #[test]
+    fn test_mt103_validate_all() {
+        use crate::traits::SwiftMessageBody;
+
+        let text = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:OUR\n:71G:EUR10,00\n-";
+        let mut mt103 = MT103::parse_from_block4(text).unwrap();
+        mt103.field_20.reference = "REFERENCE-TOO-LONG-FOR-20".to_string();
+        mt103.field_23b.instruction_code = "XXXX".to_string();
+
+        let report = mt103.validate_all();
+        assert_eq!(report.message_type, "103");
+        assert!(!report.is_valid());
+        assert!(report.has_code("T12"));
+        assert!(report.has_code("T36"));
+        assert!(report.has_code("C02"));
+        assert!(!report.errors_in_series('T').is_empty());
+        assert!(!report.errors_in_series('C').is_empty());
+    }
+
+    #[test]
+    fn test_mt103_currencies() {
+        use crate::traits::{CurrencyInfo, SwiftMessageBody};
+
+        let text = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:33B:EUR920,00\n:36:1,0869565\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:SHA\n-";
+        let mt103 = MT103::parse_from_block4(text).unwrap();
+
+        assert_eq!(mt103.currency(), Some("USD"));
+        assert_eq!(
+            mt103.currencies(),
+            BTreeSet::from(["EUR".to_string(), "USD".to_string()])
+        );
+        assert!(mt103.is_multi_currency());
+    }
+
+    #[test]
+    fn test_mt103_missing_required_fields() {
+        use crate::traits::SwiftMessageBody;
+
+        let text = ":20:REF1\n:23B:CRED\n:32A:241201USD1000,00\n:50K:JOHN DOE\n:59:/98765432109876543210\nJANE SMITH\n:71A:OUR\n-";
+        let mut mt103 = MT103::parse_from_block4(text).unwrap();
+        assert!(mt103.missing_required_fields().is_empty());
+
+        mt103.field_20.reference.clear();
+        mt103.field_23b.instruction_code.clear();
+        assert_eq!(mt103.missing_required_fields(), vec!["20", "23B"]);
+    }
+
+    #[test]
+    fn test_mt103_field_specs() {
+        let specs = <MT103 as crate::traits::SwiftMessageBody>::field_specs();
+
+        let field_20 = specs.iter().find(|spec| spec.tag == "20").unwrap();
+        assert!(!field_20.optional);
+        assert_eq!(field_20.format, "16x");
+
+        let field_23e = specs.iter().find(|spec| spec.tag == "23E").unwrap();
+        assert!(field_23e.optional);
+        assert!(field_23e.repetitive);
+    }
+
+    #[test]
+    fn test_mt103_to_fields_with_variants() {
+        let mt103_text = r#":20:123456789012345
+:23B:CRED
+:32A:241201USD1000000,00
+:50K:/12345678901234567890
+JOHN DOE
+:57A:DEUTDEFF
+:59:/98765432109876543210
+JANE SMITH
+:71A:OUR
+-"#;
+        let mt103 =
+            <MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(mt103_text).unwrap();
+        let fields = crate::traits::SwiftMessageBody::to_fields_with_variants(&mt103);
+
+        let tags: Vec<&str> = fields.iter().map(|(tag, _)| tag.as_str()).collect();
+        assert_eq!(tags, vec!["20", "23B", "32A", "50K", "57A", "59", "71A"]);
+        assert!(
+            fields
+                .iter()
+                .any(|(tag, value)| tag == "50K" && value == "/12345678901234567890\nJOHN DOE")
+        );
+    }
 }
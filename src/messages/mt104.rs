@@ -2,6 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// Sequence B - Transaction details
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -406,6 +407,21 @@ impl MT104 {
     // HELPER METHODS
     // ========================================================================
 
+    /// Set field 19 to the sum of the field 32B amounts in Sequence B where C9 requires it
+    ///
+    /// Field 19 is set when Sequence C is present and its field 32B differs from the sum,
+    /// and cleared otherwise. Fails with C02 if the transactions are not all in the same
+    /// currency.
+    pub fn recompute_field_19(&mut self) -> Result<(), SwiftValidationError> {
+        let sum = Field19::sum_of(self.transactions.iter().map(|tx| &tx.field_32b))?;
+        let required = self
+            .field_32b
+            .as_ref()
+            .is_some_and(|settlement| (settlement.amount - sum.amount).abs() >= 0.01);
+        self.field_19 = required.then_some(sum);
+        Ok(())
+    }
+
     /// Check if Sequence C is present (has at least field 32B)
     fn has_sequence_c(&self) -> bool {
         self.field_32b.is_some()
@@ -943,25 +959,8 @@ impl MT104 {
 
     /// C10: Field 19 Amount Validation (Error code: C01)
     fn validate_c10_field_19_amount(&self) -> Option<SwiftValidationError> {
-        if let Some(ref field_19) = self.field_19 {
-            // Calculate sum of amounts in Sequence B
-            let sum_of_amounts: f64 = self.transactions.iter().map(|tx| tx.field_32b.amount).sum();
-
-            if (field_19.amount - sum_of_amounts).abs() > 0.01 {
-                return Some(SwiftValidationError::content_error(
-                    "C01",
-                    "19",
-                    &field_19.amount.to_string(),
-                    &format!(
-                        "Field 19 amount ({}) must equal the sum of amounts in all occurrences of field 32B in Sequence B ({})",
-                        field_19.amount, sum_of_amounts
-                    ),
-                    "If field 19 is present in sequence C then it must be equal to the sum of the amounts in all occurrences of field 32B in sequence B",
-                ));
-            }
-        }
-
-        None
+        let field_19 = self.field_19.as_ref()?;
+        validate_field_19_tieout(field_19, self.transactions.iter().map(|tx| &tx.field_32b))
     }
 
     /// C11: Currency Code Consistency (Error code: C02)
@@ -1382,6 +1381,112 @@ impl crate::traits::SwiftMessageBody for MT104 {
         MT104::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("21R", &self.field_21r)
+            .optional("23E", &self.field_23e)
+            .optional("21E", &self.field_21e)
+            .field("30", &self.field_30)
+            .optional("51A", &self.field_51a)
+            .optional("50", &self.instructing_party)
+            .optional("50", &self.creditor)
+            .optional("52", &self.field_52)
+            .optional("26T", &self.field_26t)
+            .optional("77B", &self.field_77b)
+            .optional("71A", &self.field_71a)
+            .optional("72", &self.field_72);
+        for transaction in &self.transactions {
+            visitor
+                .field("21", &transaction.field_21)
+                .optional("23E", &transaction.field_23e)
+                .optional("21C", &transaction.field_21c)
+                .optional("21D", &transaction.field_21d)
+                .optional("21E", &transaction.field_21e)
+                .field("32B", &transaction.field_32b)
+                .optional("50", &transaction.instructing_party_tx)
+                .optional("50", &transaction.creditor_tx)
+                .optional("52", &transaction.field_52)
+                .optional("57", &transaction.field_57)
+                .field("59", &transaction.field_59)
+                .optional("70", &transaction.field_70)
+                .optional("26T", &transaction.field_26t)
+                .optional("77B", &transaction.field_77b)
+                .optional("33B", &transaction.field_33b)
+                .optional("71A", &transaction.field_71a)
+                .optional("71F", &transaction.field_71f)
+                .optional("71G", &transaction.field_71g)
+                .optional("36", &transaction.field_36);
+        }
+        visitor
+            .optional("32B", &self.field_32b)
+            .optional("19", &self.field_19)
+            .optional("71F", &self.field_71f)
+            .optional("71G", &self.field_71g)
+            .optional("53", &self.field_53);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let sequence_a = [
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("21R", "16x", "Customer Specified Reference").optional(),
+            FieldSpec::new("23E", "4!c[/35x]", "Instruction Code").optional(),
+            FieldSpec::new("21E", "35x", "Registration Reference").optional(),
+            FieldSpec::new("30", "6!n", "Requested Execution Date"),
+            FieldSpec::new("51A", "[/1!a][/34x]4!a2!a2!c[3!c]", "Sending Institution").optional(),
+            FieldSpec::new("50C", "4!a2!a2!c[3!c]", "Instructing Party").optional(),
+            FieldSpec::new("50L", "35x", "Instructing Party").optional(),
+            FieldSpec::new("50A", "[/34x]4!a2!a2!c[3!c]", "Creditor").optional(),
+            FieldSpec::new("50K", "[/34x]4*35x", "Creditor").optional(),
+            FieldSpec::new("52a", "Option A, C or D", "Creditor's Bank").optional(),
+            FieldSpec::new("26T", "3!c", "Transaction Type Code").optional(),
+            FieldSpec::new("77B", "3*35x", "Regulatory Reporting").optional(),
+            FieldSpec::new("71A", "3!a", "Details of Charges").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+        .map(|spec| spec.sequence("A"));
+        let sequence_b = [
+            FieldSpec::new("21", "16x", "Transaction Reference"),
+            FieldSpec::new("23E", "4!c[/35x]", "Instruction Code").optional(),
+            FieldSpec::new("21C", "35x", "Mandate Reference").optional(),
+            FieldSpec::new("21D", "35x", "Direct Debit Reference").optional(),
+            FieldSpec::new("21E", "35x", "Registration Reference").optional(),
+            FieldSpec::new("32B", "3!a15d", "Currency and Transaction Amount"),
+            FieldSpec::new("50C", "4!a2!a2!c[3!c]", "Instructing Party").optional(),
+            FieldSpec::new("50L", "35x", "Instructing Party").optional(),
+            FieldSpec::new("50A", "[/34x]4!a2!a2!c[3!c]", "Creditor").optional(),
+            FieldSpec::new("50K", "[/34x]4*35x", "Creditor").optional(),
+            FieldSpec::new("52a", "Option A, C or D", "Creditor's Bank").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Debtor's Bank").optional(),
+            FieldSpec::new("59a", "No letter option or A", "Debtor"),
+            FieldSpec::new("70", "4*35x", "Remittance Information").optional(),
+            FieldSpec::new("26T", "3!c", "Transaction Type Code").optional(),
+            FieldSpec::new("77B", "3*35x", "Regulatory Reporting").optional(),
+            FieldSpec::new("33B", "3!a15d", "Currency/Original Ordered Amount").optional(),
+            FieldSpec::new("71A", "3!a", "Details of Charges").optional(),
+            FieldSpec::new("71F", "3!a15d", "Sender's Charges").optional(),
+            FieldSpec::new("71G", "3!a15d", "Receiver's Charges").optional(),
+            FieldSpec::new("36", "12d", "Exchange Rate").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("B"));
+        let sequence_c = [
+            FieldSpec::new("32B", "3!a15d", "Currency and Settlement Amount").optional(),
+            FieldSpec::new("19", "17d", "Sum of Amounts").optional(),
+            FieldSpec::new("71F", "3!a15d", "Sum of Sender's Charges").optional(),
+            FieldSpec::new("71G", "3!a15d", "Sum of Receiver's Charges").optional(),
+            FieldSpec::new("53a", "Option A, B or D", "Sender's Correspondent").optional(),
+        ]
+        .map(|spec| spec.sequence("C"));
+
+        sequence_a
+            .into_iter()
+            .chain(sequence_b)
+            .chain(sequence_c)
+            .collect()
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT104::to_mt_string(self)
@@ -1392,3 +1497,58 @@ impl crate::traits::SwiftMessageBody for MT104 {
         MT104::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT104 {
+    fn currency(&self) -> Option<&str> {
+        self.field_32b
+            .as_ref()
+            .or_else(|| self.transactions.first().map(|tx| &tx.field_32b))
+            .map(|f| f.currency.as_str())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        let mut currencies = BTreeSet::new();
+        for tx in &self.transactions {
+            currencies.insert(tx.field_32b.currency.clone());
+            currencies.extend(tx.field_33b.iter().map(|f| f.currency.clone()));
+            currencies.extend(tx.field_71f.iter().map(|f| f.currency.clone()));
+            currencies.extend(tx.field_71g.iter().map(|f| f.currency.clone()));
+        }
+        currencies.extend(self.field_32b.iter().map(|f| f.currency.clone()));
+        currencies.extend(self.field_71f.iter().map(|f| f.currency.clone()));
+        currencies.extend(self.field_71g.iter().map(|f| f.currency.clone()));
+        currencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mt104_recompute_field_19() {
+        let block4 = ":20:BATCH001\n:30:250115\n:50K:/DE89370400440532013000\nCREDITOR GMBH\n\
+             :21:TX001\n:32B:EUR1000,10\n:59:/DE44500105175407324931\nDEBTOR ONE\n\
+             :21:TX002\n:32B:EUR2500,25\n:59:/FR1420041010050500013M02606\nDEBTOR TWO\n\
+             :21:TX003\n:32B:EUR0,20\n:59:/DE02120300000000202051\nDEBTOR THREE\n\
+             :32B:EUR3510,55\n";
+        let mut mt104 = MT104::parse_from_block4(block4).unwrap();
+        assert_eq!(mt104.transactions.len(), 3);
+        assert!(mt104.field_19.is_none());
+
+        mt104.recompute_field_19().unwrap();
+        assert_eq!(mt104.field_19.as_ref().unwrap().amount, 3500.55);
+        assert!(mt104.to_mt_string().contains(":19:3500,55"));
+        assert!(mt104.validate_c9_field_19().is_none());
+        assert!(mt104.validate_c10_field_19_amount().is_none());
+
+        // Not allowed once Sequence C settles exactly the sum
+        mt104.field_32b.as_mut().unwrap().amount = 3500.55;
+        mt104.recompute_field_19().unwrap();
+        assert!(mt104.field_19.is_none());
+        assert!(mt104.validate_c9_field_19().is_none());
+
+        mt104.transactions[2].field_32b.currency = "USD".to_string();
+        assert_eq!(mt104.recompute_field_19().unwrap_err().code(), "C02");
+    }
+}
@@ -1,7 +1,10 @@
 use crate::errors::SwiftValidationError;
+use crate::fields::swift_utils::Amount;
 use crate::fields::*;
 use crate::parser::utils::*;
+use crate::swift_error_codes::d_series;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// Sequence B - Transaction details
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -463,13 +466,22 @@ impl MT107 {
             ));
         } else if !has_23e_a && !has_23e_all_b {
             if has_23e_any_b {
-                errors.push(SwiftValidationError::content_error(
-                    "D86",
-                    "23E",
-                    "",
-                    "Field 23E must be present in every Sequence B transaction when not in Sequence A",
-                    "When field 23E is not in Sequence A, it must be present in each occurrence of Sequence B",
-                ));
+                // Report each transaction lacking its instruction code
+                for (idx, transaction) in self.transactions.iter().enumerate() {
+                    if transaction.field_23e.is_none() {
+                        errors.push(SwiftValidationError::relation_error(
+                            "D86",
+                            "23E",
+                            vec!["21".to_string()],
+                            &format!(
+                                "Transaction {} ({}): Field 23E is mandatory in every Sequence B transaction when not present in Sequence A",
+                                idx + 1,
+                                transaction.field_21.reference
+                            ),
+                            "When field 23E is not in Sequence A, it must be present in each occurrence of Sequence B",
+                        ));
+                    }
+                }
             } else {
                 errors.push(SwiftValidationError::content_error(
                     "D86",
@@ -819,6 +831,22 @@ impl MT107 {
         errors
     }
 
+    /// Set field 19 to the sum of the field 32B amounts in Sequence B where C8 requires it
+    ///
+    /// Field 19 is set when charges (71F or 71G) are included in Sequence B and cleared
+    /// otherwise. Fails with C02 if the transactions are not all in the same currency.
+    pub fn recompute_field_19(&mut self) -> Result<(), SwiftValidationError> {
+        let sum = Field19::sum_of(self.transactions.iter().map(|tx| &tx.field_32b))?;
+        let required = self.has_71f_in_seq_b() || self.has_71g_in_seq_b();
+        self.field_19 = required.then_some(sum);
+        Ok(())
+    }
+
+    /// Sum of the field 32B amounts across all Sequence B transactions
+    pub fn sum_of_transaction_amounts(&self) -> f64 {
+        self.transactions.iter().map(|tx| tx.field_32b.amount).sum()
+    }
+
     /// C8: Sum of Amounts and Settlement Amount (Error code: D80, C01)
     /// The sum of amounts in field 32B of Sequence B must be in field 32B of Sequence C
     /// (when no charges) or in field 19 of Sequence C
@@ -829,8 +857,12 @@ impl MT107 {
             return errors;
         }
 
-        // Calculate sum of all transaction amounts
-        let sum_of_amounts: f64 = self.transactions.iter().map(|tx| tx.field_32b.amount).sum();
+        // Reconcile in minor units of the settlement currency to avoid float drift
+        let sum_of_amounts = self.sum_of_transaction_amounts();
+        let sum = Amount::sum(
+            &self.field_32b.currency,
+            self.transactions.iter().map(|tx| tx.field_32b.amount),
+        );
 
         // Check if charges are present
         let has_charges = self.has_71f_in_seq_b() || self.has_71g_in_seq_b();
@@ -838,22 +870,13 @@ impl MT107 {
         if has_charges {
             // Field 19 should be present and equal to sum
             if let Some(ref field_19) = self.field_19 {
-                let field_19_amount = field_19.amount;
-                if (field_19_amount - sum_of_amounts).abs() >= 0.01 {
-                    errors.push(SwiftValidationError::content_error(
-                        "C01",
-                        "19",
-                        &field_19_amount.to_string(),
-                        &format!(
-                            "Field 19 ({:.2}) must equal the sum of amounts in field 32B of Sequence B ({:.2})",
-                            field_19_amount, sum_of_amounts
-                        ),
-                        "Field 19 must equal the sum of the amounts in all occurrences of field 32B in Sequence B",
-                    ));
-                }
+                errors.extend(validate_field_19_tieout(
+                    field_19,
+                    self.transactions.iter().map(|tx| &tx.field_32b),
+                ));
             } else {
                 errors.push(SwiftValidationError::content_error(
-                    "D80",
+                    d_series::D80,
                     "19",
                     "",
                     "Field 19 is mandatory when charges are present in Sequence B",
@@ -863,9 +886,13 @@ impl MT107 {
         } else {
             // No charges - field 32B of Sequence C should equal sum, field 19 must not be present
             let settlement_amount = self.field_32b.amount;
-            if (settlement_amount - sum_of_amounts).abs() >= 0.01 {
+            let matches = match (self.field_32b.to_amount(), &sum) {
+                (Ok(settlement), Ok(sum)) => settlement == *sum,
+                _ => false,
+            };
+            if !matches {
                 errors.push(SwiftValidationError::content_error(
-                    "D80",
+                    d_series::D80,
                     "32B",
                     &settlement_amount.to_string(),
                     &format!(
@@ -878,7 +905,7 @@ impl MT107 {
 
             if self.field_19.is_some() {
                 errors.push(SwiftValidationError::content_error(
-                    "D80",
+                    d_series::D80,
                     "19",
                     "",
                     "Field 19 must not be present when no charges are included in Sequence B",
@@ -1155,6 +1182,110 @@ impl crate::traits::SwiftMessageBody for MT107 {
         MT107::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("23E", &self.field_23e)
+            .optional("21E", &self.field_21e)
+            .field("30", &self.field_30)
+            .optional("51A", &self.field_51a)
+            .optional("50", &self.instructing_party)
+            .optional("50", &self.creditor)
+            .optional("52", &self.field_52)
+            .optional("26T", &self.field_26t)
+            .optional("77B", &self.field_77b)
+            .optional("71A", &self.field_71a)
+            .optional("72", &self.field_72);
+        for txn in &self.transactions {
+            visitor
+                .field("21", &txn.field_21)
+                .optional("23E", &txn.field_23e)
+                .optional("21C", &txn.field_21c)
+                .optional("21D", &txn.field_21d)
+                .optional("21E", &txn.field_21e)
+                .field("32B", &txn.field_32b)
+                .optional("50", &txn.instructing_party_tx)
+                .optional("50", &txn.creditor_tx)
+                .optional("52", &txn.field_52)
+                .optional("57", &txn.field_57)
+                .field("59", &txn.field_59)
+                .optional("70", &txn.field_70)
+                .optional("26T", &txn.field_26t)
+                .optional("77B", &txn.field_77b)
+                .optional("33B", &txn.field_33b)
+                .optional("71A", &txn.field_71a)
+                .optional("71F", &txn.field_71f)
+                .optional("71G", &txn.field_71g)
+                .optional("36", &txn.field_36);
+        }
+        visitor
+            .field("32B", &self.field_32b)
+            .optional("19", &self.field_19)
+            .optional("71F", &self.field_71f)
+            .optional("71G", &self.field_71g)
+            .optional("53", &self.field_53);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let sequence_a = [
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("23E", "4!c[/35x]", "Instruction Code").optional(),
+            FieldSpec::new("21E", "35x", "Registration Reference").optional(),
+            FieldSpec::new("30", "6!n", "Requested Execution Date"),
+            FieldSpec::new("51A", "[/1!a][/34x]4!a2!a2!c[3!c]", "Sending Institution").optional(),
+            FieldSpec::new("50C", "4!a2!a2!c[3!c]", "Instructing Party").optional(),
+            FieldSpec::new("50L", "35x", "Instructing Party").optional(),
+            FieldSpec::new("50A", "[/34x]4!a2!a2!c[3!c]", "Creditor").optional(),
+            FieldSpec::new("50K", "[/34x]4*35x", "Creditor").optional(),
+            FieldSpec::new("52a", "Option A, C or D", "Creditor's Bank").optional(),
+            FieldSpec::new("26T", "3!c", "Transaction Type Code").optional(),
+            FieldSpec::new("77B", "3*35x", "Regulatory Reporting").optional(),
+            FieldSpec::new("71A", "3!a", "Details of Charges").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+        .map(|spec| spec.sequence("A"));
+        let sequence_b = [
+            FieldSpec::new("21", "16x", "Transaction Reference"),
+            FieldSpec::new("23E", "4!c[/35x]", "Instruction Code").optional(),
+            FieldSpec::new("21C", "35x", "Mandate Reference").optional(),
+            FieldSpec::new("21D", "35x", "Direct Debit Reference").optional(),
+            FieldSpec::new("21E", "35x", "Registration Reference").optional(),
+            FieldSpec::new("32B", "3!a15d", "Currency and Transaction Amount"),
+            FieldSpec::new("50C", "4!a2!a2!c[3!c]", "Instructing Party").optional(),
+            FieldSpec::new("50L", "35x", "Instructing Party").optional(),
+            FieldSpec::new("50A", "[/34x]4!a2!a2!c[3!c]", "Creditor").optional(),
+            FieldSpec::new("50K", "[/34x]4*35x", "Creditor").optional(),
+            FieldSpec::new("52a", "Option A, C or D", "Creditor's Bank").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Debtor's Bank").optional(),
+            FieldSpec::new("59a", "No letter option, A or F", "Debtor"),
+            FieldSpec::new("70", "4*35x", "Remittance Information").optional(),
+            FieldSpec::new("26T", "3!c", "Transaction Type Code").optional(),
+            FieldSpec::new("77B", "3*35x", "Regulatory Reporting").optional(),
+            FieldSpec::new("33B", "3!a15d", "Currency/Original Ordered Amount").optional(),
+            FieldSpec::new("71A", "3!a", "Details of Charges").optional(),
+            FieldSpec::new("71F", "3!a15d", "Sender's Charges").optional(),
+            FieldSpec::new("71G", "3!a15d", "Receiver's Charges").optional(),
+            FieldSpec::new("36", "12d", "Exchange Rate").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("B"));
+        let sequence_c = [
+            FieldSpec::new("32B", "3!a15d", "Currency and Settlement Amount"),
+            FieldSpec::new("19", "17d", "Sum of Amounts").optional(),
+            FieldSpec::new("71F", "3!a15d", "Sum of Sender's Charges").optional(),
+            FieldSpec::new("71G", "3!a15d", "Sum of Receiver's Charges").optional(),
+            FieldSpec::new("53a", "Option A, B or D", "Sender's Correspondent").optional(),
+        ]
+        .map(|spec| spec.sequence("C"));
+
+        sequence_a
+            .into_iter()
+            .chain(sequence_b)
+            .chain(sequence_c)
+            .collect()
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -1210,3 +1341,84 @@ impl crate::traits::SwiftMessageBody for MT107 {
         MT107::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT107 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32b.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        let mut currencies = BTreeSet::from([self.field_32b.currency.clone()]);
+        for tx in &self.transactions {
+            currencies.insert(tx.field_32b.currency.clone());
+            currencies.extend(tx.field_33b.iter().map(|f| f.currency.clone()));
+            currencies.extend(tx.field_71f.iter().map(|f| f.currency.clone()));
+            currencies.extend(tx.field_71g.iter().map(|f| f.currency.clone()));
+        }
+        currencies.extend(self.field_71f.iter().map(|f| f.currency.clone()));
+        currencies.extend(self.field_71g.iter().map(|f| f.currency.clone()));
+        currencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(settlement: &str, first_23e: &str, second_23e: &str) -> String {
+        format!(
+            ":20:BATCH001\n:30:250115\n:50K:/DE89370400440532013000\nCREDITOR GMBH\n\
+             :21:TX001\n{}:32B:EUR1000,10\n:59:/DE44500105175407324931\nDEBTOR ONE\n\
+             :21:TX002\n{}:32B:EUR2500,25\n:59:/FR1420041010050500013M02606\nDEBTOR TWO\n\
+             :32B:EUR{}\n",
+            first_23e, second_23e, settlement
+        )
+    }
+
+    #[test]
+    fn test_mt107_balanced_batch() {
+        let mt107 =
+            MT107::parse_from_block4(&batch("3500,35", ":23E:AUTH\n", ":23E:AUTH\n")).unwrap();
+        assert_eq!(mt107.transactions.len(), 2);
+        assert!((mt107.sum_of_transaction_amounts() - 3500.35).abs() < 1e-9);
+        assert!(mt107.validate_network_rules(false).is_empty());
+    }
+
+    #[test]
+    fn test_mt107_transaction_sum_mismatch() {
+        let mt107 =
+            MT107::parse_from_block4(&batch("3500,36", ":23E:AUTH\n", ":23E:AUTH\n")).unwrap();
+        let errors = mt107.validate_network_rules(false);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), "D80");
+    }
+
+    #[test]
+    fn test_mt107_recompute_field_19() {
+        let mut mt107 =
+            MT107::parse_from_block4(&batch("3500,35", ":23E:AUTH\n", ":23E:AUTH\n")).unwrap();
+        mt107.field_19 = Some(Field19 { amount: 1.0 });
+        mt107.recompute_field_19().unwrap();
+        assert!(mt107.field_19.is_none());
+        assert!(mt107.validate_network_rules(false).is_empty());
+
+        // Charges in Sequence B move the sum into field 19
+        mt107.transactions[0].field_71f = Some(Field71F {
+            currency: "EUR".to_string(),
+            amount: 5.0,
+        });
+        mt107.recompute_field_19().unwrap();
+        assert_eq!(mt107.field_19.as_ref().unwrap().amount, 3500.35);
+        assert!(mt107.validate_c8_sum_of_amounts().is_empty());
+    }
+
+    #[test]
+    fn test_mt107_missing_23e_per_transaction() {
+        let mt107 = MT107::parse_from_block4(&batch("3500,35", ":23E:AUTH\n", "")).unwrap();
+        let errors = mt107.validate_network_rules(false);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), "D86");
+        assert!(matches!(errors[0], SwiftValidationError::Relation(_)));
+        assert!(errors[0].message().contains("TX002"));
+    }
+}
@@ -3,6 +3,7 @@ use crate::fields::Field52DrawerBank;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// Cheque details (repeating sequence)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -227,6 +228,45 @@ impl crate::traits::SwiftMessageBody for MT110 {
         MT110::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("53", &self.field_53a)
+            .optional("54", &self.field_54a)
+            .optional("72", &self.field_72);
+        for cheque in &self.cheques {
+            visitor
+                .field("21", &cheque.field_21)
+                .field("30", &cheque.field_30)
+                .field("32", &cheque.field_32)
+                .optional("50", &cheque.field_50)
+                .optional("52", &cheque.field_52)
+                .field("59", &cheque.field_59);
+        }
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let header = [
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("53a", "Option A, B or D", "Sender's Correspondent").optional(),
+            FieldSpec::new("54a", "Option A, B or D", "Receiver's Correspondent").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ];
+        let cheques = [
+            FieldSpec::new("21", "16x", "Cheque Number"),
+            FieldSpec::new("30", "6!n", "Date of Issue"),
+            FieldSpec::new("32a", "Option A or B", "Amount"),
+            FieldSpec::new("50a", "Option A, F or K", "Payer").optional(),
+            FieldSpec::new("52a", "Option A, B or D", "Drawer Bank").optional(),
+            FieldSpec::new("59a", "No letter option, A or F", "Payee"),
+        ]
+        .map(|spec| spec.repeating_sequence("Cheque"));
+
+        header.into_iter().chain(cheques).collect()
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -255,6 +295,21 @@ impl crate::traits::SwiftMessageBody for MT110 {
     }
 }
 
+impl crate::traits::CurrencyInfo for MT110 {
+    fn currency(&self) -> Option<&str> {
+        self.cheques
+            .first()
+            .map(|cheque| cheque.field_32.currency())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        self.cheques
+            .iter()
+            .map(|cheque| cheque.field_32.currency().to_string())
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,6 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT111: Request for Stop Payment of a Cheque**
 ///
@@ -108,6 +109,31 @@ impl crate::traits::SwiftMessageBody for MT111 {
         MT111::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("30", &self.field_30)
+            .field("32", &self.field_32)
+            .optional("52", &self.field_52)
+            .optional("59", &self.field_59)
+            .optional("75", &self.field_75);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("21", "16x", "Cheque Number"),
+            FieldSpec::new("30", "6!n", "Date of Issue"),
+            FieldSpec::new("32a", "Option A or B", "Amount"),
+            FieldSpec::new("52a", "Option A or D", "Drawer Bank").optional(),
+            FieldSpec::new("59", "[/34x]4*35x", "Payee").optional(),
+            FieldSpec::new("75", "6*35x", "Queries").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         let mut result = String::new();
@@ -128,3 +154,13 @@ impl crate::traits::SwiftMessageBody for MT111 {
         MT111::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT111 {
+    fn currency(&self) -> Option<&str> {
+        Some(self.field_32.currency())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        BTreeSet::from([self.field_32.currency().to_string()])
+    }
+}
@@ -2,6 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT190: Advice of Charges, Interest and Other Adjustments**
 ///
@@ -121,6 +122,31 @@ impl crate::traits::SwiftMessageBody for MT190 {
         MT190::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("25", &self.field_25)
+            .field("32", &self.field_32)
+            .optional("52", &self.field_52)
+            .field("71B", &self.field_71b)
+            .optional("72", &self.field_72);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("25", "35x", "Account Identification"),
+            FieldSpec::new("32a", "Option C or D", "Value Date, Currency Code, Amount"),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("71B", "6*35x", "Details of Charges"),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT190::to_mt_string(self)
@@ -131,3 +157,13 @@ impl crate::traits::SwiftMessageBody for MT190 {
         MT190::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT190 {
+    fn currency(&self) -> Option<&str> {
+        Some(self.field_32.currency())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        BTreeSet::from([self.field_32.currency().to_string()])
+    }
+}
@@ -2,6 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT191: Request for Payment of Charges, Interest and Other Expenses**
 ///
@@ -127,6 +128,31 @@ impl crate::traits::SwiftMessageBody for MT191 {
         MT191::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("32B", &self.field_32b)
+            .optional("52", &self.field_52)
+            .optional("57", &self.field_57)
+            .field("71B", &self.field_71b)
+            .optional("72", &self.field_72);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("32B", "3!a15d", "Currency Code, Amount"),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("71B", "6*35x", "Details of Charges"),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT191::to_mt_string(self)
@@ -137,3 +163,13 @@ impl crate::traits::SwiftMessageBody for MT191 {
         MT191::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT191 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32b.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        BTreeSet::from([self.field_32b.currency.clone()])
+    }
+}
@@ -1,6 +1,7 @@
 use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
+use crate::traits::SwiftField;
 use serde::{Deserialize, Serialize};
 
 /// **MT192: Request for Cancellation**
@@ -40,7 +41,15 @@ impl MT192 {
         let field_11s = parser.parse_field::<Field11S>("11S")?;
 
         // Parse optional field 79
-        let field_79 = parser.parse_optional_field::<Field79>("79")?;
+        let mut field_79 = parser.parse_optional_field::<Field79>("79")?;
+
+        // Copied fields of the original message follow the narrative; keep them in field 79
+        if let Some(ref mut field_79) = field_79
+            && !parser.is_complete()
+        {
+            let is_copied = original_field_matcher(&field_11s);
+            field_79.extend_lines(parser.take_copied_fields(is_copied)?)?;
+        }
 
         Ok(MT192 {
             field_20,
@@ -152,6 +161,18 @@ impl MT192 {
         errors
     }
 
+    // ========================================================================
+    // CANCELLATION CORRELATION
+    // ========================================================================
+
+    /// Correlate this cancellation request with the original MT103
+    ///
+    /// Checks that field 21 references the original field 20 and that any fields
+    /// copied into field 79 (20, 32A, 50a, 59a) match the original message.
+    pub fn correlate_cancellation(&self, original: &super::MT103) -> crate::ValidationResult {
+        correlate_with_mt103(&self.field_21, self.field_79.as_ref(), original)
+    }
+
     /// Main validation method - validates all network rules
     /// Returns array of validation errors, respects stop_on_first_error flag
     pub fn validate_network_rules(&self, stop_on_first_error: bool) -> Vec<SwiftValidationError> {
@@ -173,6 +194,131 @@ impl MT192 {
     }
 }
 
+/// Accepts the tags of the original message type named in field 11S of an n92
+///
+/// Any tag is accepted when the original type has no field specs in this crate.
+pub(crate) fn original_field_matcher(field_11s: &Field11S) -> impl Fn(&str) -> bool {
+    let specs = field_11s
+        .message_type
+        .parse::<super::MessageType>()
+        .map(|message_type| message_type.field_specs())
+        .unwrap_or_default();
+    move |tag| specs.is_empty() || specs.iter().any(|spec| spec.matches(tag))
+}
+
+/// Code of the relation errors reported by [`MT192::correlate_cancellation`] and
+/// [`MT292::correlate_cancellation`](super::MT292::correlate_cancellation)
+///
+/// The check is not a SWIFT network rule, so it carries this crate-specific code rather
+/// than a network error code.
+pub const CANCELLATION_CORRELATION_CODE: &str = "CANCELLATION_MISMATCH";
+
+/// Correlate an n92 cancellation request (field 21 and field 79 copy) with an MT103
+pub(crate) fn correlate_with_mt103(
+    field_21: &Field21NoOption,
+    field_79: Option<&Field79>,
+    original: &super::MT103,
+) -> crate::ValidationResult {
+    let mut errors = Vec::new();
+
+    if field_21.reference != original.field_20.reference {
+        errors.push(SwiftValidationError::relation_error(
+            CANCELLATION_CORRELATION_CODE,
+            "21",
+            vec!["20".to_string()],
+            &format!(
+                "Related reference '{}' does not match original message reference '{}'",
+                field_21.reference, original.field_20.reference
+            ),
+            "Field 21 of the cancellation request must contain field 20 of the original message",
+        ));
+    }
+
+    let copied = field_79
+        .and_then(Field79::as_copied_fields)
+        .unwrap_or_default();
+
+    for (tag, value) in &copied {
+        match tag.as_str() {
+            "20" if value != &original.field_20.reference => {
+                errors.push(copied_field_mismatch(
+                    "20",
+                    value,
+                    &original.field_20.reference,
+                ));
+            }
+            "32A" => match Field32A::parse(value) {
+                Ok(field_32a) => {
+                    let expected = &original.field_32a;
+                    for (component, matches) in [
+                        ("value date", field_32a.value_date == expected.value_date),
+                        ("currency", field_32a.currency == expected.currency),
+                        ("amount", field_32a.amount == expected.amount),
+                    ] {
+                        if !matches {
+                            errors.push(SwiftValidationError::relation_error(
+                                CANCELLATION_CORRELATION_CODE,
+                                "32A",
+                                vec!["79".to_string()],
+                                &format!(
+                                    "Copied field 32A {} does not match the original message ({} vs {})",
+                                    component,
+                                    value,
+                                    expected.to_swift_string().trim_start_matches(":32A:")
+                                ),
+                                "Fields copied into field 79 must match the original message",
+                            ));
+                        }
+                    }
+                }
+                Err(_) => {
+                    errors.push(copied_field_mismatch(
+                        "32A",
+                        value,
+                        original
+                            .field_32a
+                            .to_swift_string()
+                            .trim_start_matches(":32A:"),
+                    ));
+                }
+            },
+            _ if tag.starts_with("50") || tag.starts_with("59") => {
+                let expected = if tag.starts_with("50") {
+                    original.field_50.to_swift_string()
+                } else {
+                    original.field_59.to_swift_string()
+                };
+                let copied_field = format!(":{}:{}", tag, value);
+                if copied_field.trim_end() != expected.trim_end() {
+                    let expected_value = expected
+                        .splitn(3, ':')
+                        .nth(2)
+                        .unwrap_or_default()
+                        .to_string();
+                    errors.push(copied_field_mismatch(tag, value, &expected_value));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    crate::ValidationResult::with_errors(errors.into_iter().map(Into::into).collect())
+}
+
+/// Build the relation error for a copied field 79 value that differs from the original
+fn copied_field_mismatch(tag: &str, copied: &str, expected: &str) -> SwiftValidationError {
+    SwiftValidationError::relation_error(
+        CANCELLATION_CORRELATION_CODE,
+        tag,
+        vec!["79".to_string()],
+        &format!(
+            "Copied field {} '{}' does not match the original message '{}'",
+            tag, copied, expected
+        ),
+        "Fields copied into field 79 must match the original message",
+    )
+}
+
 // Implement the SwiftMessageBody trait for MT192
 impl crate::traits::SwiftMessageBody for MT192 {
     fn message_type() -> &'static str {
@@ -183,6 +329,34 @@ impl crate::traits::SwiftMessageBody for MT192 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("11S", &self.field_11s)
+            .optional("79", &self.field_79);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Sender's Reference"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new(
+                "11S",
+                "3!n6!n[4!n6!n]",
+                "MT and Date of the Original Message",
+            ),
+            FieldSpec::new(
+                "79",
+                "35*50x",
+                "Narrative Description of the Original Message",
+            )
+            .optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -199,3 +373,83 @@ impl crate::traits::SwiftMessageBody for MT192 {
         MT192::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn original_mt103() -> crate::MT103 {
+        <crate::MT103 as crate::SwiftMessageBody>::parse_from_block4(
+            ":20:ORIGREF123\n:23B:CRED\n:32A:250115EUR1000,00\n:50K:JOHN DOE\nBERLIN\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n-",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_mt192_correlate_cancellation_matching() {
+        let block4 = ":20:CANC0001\n:21:ORIGREF123\n:11S:103250115\n:79:/DUPL/\n:20:ORIGREF123\n:32A:250115EUR1000,00\n:50K:JOHN DOE\nBERLIN\n:59:/DE89370400440532013000\nJANE ROE\n-";
+        let mt192 = MT192::parse_from_block4(block4).unwrap();
+
+        let result = mt192.correlate_cancellation(&original_mt103());
+        assert!(result.is_valid, "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_mt192_correlate_cancellation_tampered() {
+        let block4 = ":20:CANC0001\n:21:ORIGREF999\n:11S:103250115\n:79:/DUPL/\n:20:ORIGREF123\n:32A:250115EUR9000,00\n:59:/DE89370400440532013000\nMALLORY\n-";
+        let mt192 = MT192::parse_from_block4(block4).unwrap();
+
+        let result = mt192.correlate_cancellation(&original_mt103());
+        assert!(!result.is_valid);
+        let messages: Vec<String> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 3, "{:?}", messages);
+        assert!(messages[0].contains("Related reference 'ORIGREF999'"));
+        assert!(messages[1].contains("Copied field 32A amount"));
+        assert!(messages[2].contains("Copied field 59"));
+        assert!(
+            messages
+                .iter()
+                .all(|m| m.contains(CANCELLATION_CORRELATION_CODE)),
+            "{:?}",
+            messages
+        );
+    }
+
+    #[test]
+    fn test_mt192_field_79_copied_fields() {
+        let block4 = ":20:CANC0001\n:21:ORIGREF123\n:11S:103250115\n:79:/DUPL/\n:20:ORIGREF123\n:32A:250115EUR1000,00\n-";
+        let mt192 = MT192::parse_from_block4(block4).unwrap();
+
+        let copied = mt192.field_79.as_ref().unwrap().as_copied_fields().unwrap();
+        assert_eq!(
+            copied,
+            vec![
+                ("20".to_string(), "ORIGREF123".to_string()),
+                ("32A".to_string(), "250115EUR1000,00".to_string()),
+            ]
+        );
+
+        // Copied fields are preserved on serialization
+        let mt_string = crate::traits::SwiftMessageBody::to_mt_string(&mt192);
+        assert!(mt_string.ends_with(":79:/DUPL/\n:20:ORIGREF123\n:32A:250115EUR1000,00"));
+
+        // Narrative plus copied fields must stay within 35 lines
+        let with_copied_lines = |count: usize| {
+            let copied: String = (0..count).map(|i| format!(":72:/ACC/LINE{i}\n")).collect();
+            format!(":20:CANC0001\n:21:ORIGREF123\n:11S:103250115\n:79:/DUPL/\n{copied}-")
+        };
+        assert!(MT192::parse_from_block4(&with_copied_lines(34)).is_ok());
+        assert!(MT192::parse_from_block4(&with_copied_lines(35)).is_err());
+
+        // A field the original MT103 cannot contain is not swallowed into the narrative
+        let unexpected = block4.replace("\n-", "\n:99Z:TRAILER\n-");
+        let err = MT192::parse_from_block4(&unexpected).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unexpected field 99Z after field 79"),
+            "{err}"
+        );
+        let nested_79 = block4.replace("\n-", "\n:79:SECOND NARRATIVE\n-");
+        assert!(MT192::parse_from_block4(&nested_79).is_err());
+    }
+}
@@ -104,6 +104,23 @@ impl crate::traits::SwiftMessageBody for MT199 {
         MT199::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("21", &self.field_21)
+            .field("79", &self.field_79);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference").optional(),
+            FieldSpec::new("79", "35*50x", "Narrative"),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         let mut result = String::new();
@@ -3,6 +3,7 @@ use crate::fields::*;
 use crate::parser::MessageParser;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT200: Financial Institution Transfer for Own Account**
 ///
@@ -188,6 +189,29 @@ impl crate::traits::SwiftMessageBody for MT200 {
         MT200::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("32A", &self.field_32a)
+            .optional("53B", &self.field_53b)
+            .optional("56", &self.field_56)
+            .field("57", &self.field_57)
+            .optional("72", &self.field_72);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("32A", "6!n3!a15d", "Value Date, Currency Code, Amount"),
+            FieldSpec::new("53B", "[/1!a][/34x][35x]", "Sender's Correspondent").optional(),
+            FieldSpec::new("56a", "Option A or D", "Intermediary").optional(),
+            FieldSpec::new("57a", "Option A, B or D", "Account With Institution"),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT200::to_mt_string(self)
@@ -198,3 +222,13 @@ impl crate::traits::SwiftMessageBody for MT200 {
         MT200::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT200 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32a.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        BTreeSet::from([self.field_32a.currency.clone()])
+    }
+}
@@ -3,6 +3,7 @@ use crate::fields::*;
 use crate::parser::MessageParser;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// Sequence B - Cover Payment Details (MT202 COV)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -269,6 +270,14 @@ impl MT202 {
     pub fn validate_network_rules(&self, stop_on_first_error: bool) -> Vec<SwiftValidationError> {
         let mut all_errors = Vec::new();
 
+        // Field 21 (Related Reference) presence, NONREF when there is none
+        if let Some(error) = self.field_21.validate_present() {
+            all_errors.push(error);
+            if stop_on_first_error {
+                return all_errors;
+            }
+        }
+
         // C1: Intermediary and Account With Institution (Sequence A)
         if let Some(error) = self.validate_c1_intermediary_seq_a() {
             all_errors.push(error);
@@ -329,6 +338,66 @@ impl crate::traits::SwiftMessageBody for MT202 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .optional_repeated("13C", &self.field_13c)
+            .field("32A", &self.field_32a)
+            .optional("52", &self.field_52)
+            .optional("53", &self.field_53)
+            .optional("54", &self.field_54)
+            .optional("56", &self.field_56)
+            .optional("57", &self.field_57)
+            .field("58", &self.field_58)
+            .optional("72", &self.field_72);
+        if let Some(seq_b) = &self.sequence_b {
+            visitor
+                .optional("50", &seq_b.ordering_customer)
+                .optional("52", &seq_b.ordering_institution)
+                .optional("56", &seq_b.intermediary)
+                .optional("57", &seq_b.account_with_institution)
+                .optional("59", &seq_b.beneficiary_customer)
+                .optional("70", &seq_b.remittance_information)
+                .optional("72", &seq_b.sender_to_receiver_information)
+                .optional("33B", &seq_b.currency_amount);
+        }
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let sequence_a = [
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("13C", "/8c/4!n1!x4!n", "Time Indication")
+                .optional()
+                .max(5),
+            FieldSpec::new("32A", "6!n3!a15d", "Value Date, Currency Code, Amount"),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("53a", "Option A, B or D", "Sender's Correspondent").optional(),
+            FieldSpec::new("54a", "Option A, B or D", "Receiver's Correspondent").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("58a", "Option A or D", "Beneficiary Institution"),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+        .map(|spec| spec.sequence("A"));
+        let sequence_b = [
+            FieldSpec::new("50a", "Option A, F or K", "Ordering Customer").optional(),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary Institution").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("59a", "No letter option, A or F", "Beneficiary Customer").optional(),
+            FieldSpec::new("70", "4*35x", "Remittance Information").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+            FieldSpec::new("33B", "3!a15d", "Currency/Instructed Amount").optional(),
+        ]
+        .map(|spec| spec.sequence("B"));
+
+        sequence_a.into_iter().chain(sequence_b).collect()
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -365,3 +434,30 @@ impl crate::traits::SwiftMessageBody for MT202 {
         MT202::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT202 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32a.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        let mut currencies = BTreeSet::from([self.field_32a.currency.clone()]);
+        currencies.extend(
+            self.sequence_b
+                .iter()
+                .filter_map(|seq| seq.currency_amount.as_ref())
+                .map(|f| f.currency.clone()),
+        );
+        currencies
+    }
+}
+
+impl crate::traits::CommonFields for MT202 {
+    fn reference(&self) -> &str {
+        &self.field_20.reference
+    }
+
+    fn value_date_amount(&self) -> &Field32A {
+        &self.field_32a
+    }
+}
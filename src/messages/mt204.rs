@@ -4,7 +4,7 @@ use crate::fields::*;
 use crate::parser::MessageParser;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 /// **MT204: Financial Markets Direct Debit Message**
 ///
@@ -144,12 +144,14 @@ impl MT204 {
     // HELPER METHODS
     // ========================================================================
 
-    /// Get the sum of all transaction amounts in Sequence B
-    fn calculate_sum_of_transactions(&self) -> f64 {
-        self.transactions
-            .iter()
-            .map(|tx| tx.currency_amount.amount)
-            .sum()
+    /// Set field 19 to the sum of the field 32B amounts in Sequence B
+    ///
+    /// Field 19 is mandatory in MT204, so it is always set and C1 holds by construction.
+    /// Fails with C02 if the transactions are not all in the same currency.
+    pub fn recompute_field_19(&mut self) -> Result<(), SwiftValidationError> {
+        self.sum_of_amounts =
+            Field19::sum_of(self.transactions.iter().map(|tx| &tx.currency_amount))?;
+        Ok(())
     }
 
     /// Get all unique currency codes from Sequence B transactions
@@ -167,30 +169,10 @@ impl MT204 {
     /// C1: Sum of Amounts Must Equal Total of Transaction Amounts (Error code: C01)
     /// The amount in field 19 must equal the sum of amounts in all occurrences of field 32B
     fn validate_c1_sum_of_amounts(&self) -> Option<SwiftValidationError> {
-        if self.transactions.is_empty() {
-            return None; // No transactions to validate
-        }
-
-        let sum_of_transactions = self.calculate_sum_of_transactions();
-        let field_19_amount = self.sum_of_amounts.amount;
-
-        // Use a small epsilon for floating-point comparison (0.01 = 1 cent)
-        let difference = (field_19_amount - sum_of_transactions).abs();
-
-        if difference > 0.01 {
-            return Some(SwiftValidationError::content_error(
-                "C01",
-                "19",
-                &field_19_amount.to_string(),
-                &format!(
-                    "Sum of amounts in field 19 ({:.2}) must equal the sum of all field 32B amounts ({:.2}). Difference: {:.2}",
-                    field_19_amount, sum_of_transactions, difference
-                ),
-                "The amount in field 19 must equal the sum of the amounts in all occurrences of field 32B",
-            ));
-        }
-
-        None
+        validate_field_19_tieout(
+            &self.sum_of_amounts,
+            self.transactions.iter().map(|tx| &tx.currency_amount),
+        )
     }
 
     /// C2: Currency Code Consistency Across All Transactions (Error code: C02)
@@ -284,6 +266,48 @@ impl crate::traits::SwiftMessageBody for MT204 {
         MT204::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("19", &self.sum_of_amounts)
+            .field("20", &self.transaction_reference)
+            .field("30", &self.execution_date)
+            .optional("57", &self.account_with_institution)
+            .optional("58", &self.beneficiary_institution)
+            .optional("72", &self.sender_to_receiver);
+        for txn in &self.transactions {
+            visitor
+                .field("20", &txn.transaction_reference)
+                .optional("21", &txn.related_reference)
+                .field("32B", &txn.currency_amount)
+                .optional("53", &txn.senders_correspondent)
+                .optional("72", &txn.sender_to_receiver);
+        }
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let sequence_a = [
+            FieldSpec::new("19", "17d", "Sum of Amounts"),
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("30", "6!n", "Value Date"),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("58a", "Option A or D", "Beneficiary Institution").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+        .map(|spec| spec.sequence("A"));
+        let sequence_b = [
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference").optional(),
+            FieldSpec::new("32B", "3!a15d", "Transaction Amount"),
+            FieldSpec::new("53a", "Option A, B or D", "Debit Institution").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("B"));
+
+        sequence_a.into_iter().chain(sequence_b).collect()
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         let mut result = String::new();
@@ -312,3 +336,38 @@ impl crate::traits::SwiftMessageBody for MT204 {
         MT204::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT204 {
+    fn currency(&self) -> Option<&str> {
+        self.transactions
+            .first()
+            .map(|tx| tx.currency_amount.currency.as_str())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        self.transactions
+            .iter()
+            .map(|tx| tx.currency_amount.currency.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mt204_recompute_field_19() {
+        let block4 = ":19:1,\n:20:BATCH001\n:30:250115\n\
+             :20:TX001\n:32B:USD1000,10\n:20:TX002\n:32B:USD2500,25\n-";
+        let mut mt204 = MT204::parse_from_block4(block4).unwrap();
+        assert!(mt204.validate_c1_sum_of_amounts().is_some());
+
+        mt204.recompute_field_19().unwrap();
+        assert_eq!(mt204.sum_of_amounts.amount, 3500.35);
+        assert!(mt204.validate_c1_sum_of_amounts().is_none());
+
+        mt204.transactions[1].currency_amount.currency = "EUR".to_string();
+        assert_eq!(mt204.recompute_field_19().unwrap_err().code(), "C02");
+    }
+}
@@ -3,6 +3,7 @@ use crate::fields::*;
 use crate::parser::MessageParser;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT205: Financial Institution Transfer Execution**
 ///
@@ -212,6 +213,45 @@ impl crate::traits::SwiftMessageBody for MT205 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.transaction_reference)
+            .field("21", &self.related_reference)
+            .optional_repeated("13C", &self.time_indication)
+            .optional("23B", &self.bank_operation_code)
+            .field("32A", &self.value_date_amount)
+            .optional("33B", &self.instructed_amount)
+            .optional("52", &self.ordering_institution)
+            .optional("53", &self.senders_correspondent)
+            .optional("54", &self.receivers_correspondent)
+            .optional("56", &self.intermediary)
+            .optional("57", &self.account_with_institution)
+            .field("58", &self.beneficiary_institution)
+            .optional("72", &self.sender_to_receiver);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("13C", "/8c/4!n1!x4!n", "Time Indication")
+                .optional()
+                .max(5),
+            FieldSpec::new("23B", "4!c", "Bank Operation Code").optional(),
+            FieldSpec::new("32A", "6!n3!a15d", "Value Date, Currency Code, Amount"),
+            FieldSpec::new("33B", "3!a15d", "Currency/Instructed Amount").optional(),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("53a", "Option A, B or D", "Sender's Correspondent").optional(),
+            FieldSpec::new("54a", "Option A, B or D", "Receiver's Correspondent").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary").optional(),
+            FieldSpec::new("57a", "Option A, B, C or D", "Account With Institution").optional(),
+            FieldSpec::new("58a", "Option A or D", "Beneficiary Institution"),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -237,3 +277,73 @@ impl crate::traits::SwiftMessageBody for MT205 {
         MT205::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT205 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.value_date_amount.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        let mut currencies = BTreeSet::from([self.value_date_amount.currency.clone()]);
+        currencies.extend(self.instructed_amount.iter().map(|f| f.currency.clone()));
+        currencies
+    }
+}
+
+impl crate::traits::CommonFields for MT205 {
+    fn reference(&self) -> &str {
+        &self.transaction_reference.reference
+    }
+
+    fn value_date_amount(&self) -> &Field32A {
+        &self.value_date_amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::SwiftMessageBody;
+
+    const BASE: &str = ":20:TRF123\r\n:21:REL456\r\n:32A:240315USD1000,00\r\n:52A:ORDBUS33XXX\r\n{chain}:58A:BENBGB22XXX\r\n";
+
+    fn codes(chain: &str) -> Vec<String> {
+        let message = MT205::parse_from_block4(&BASE.replace("{chain}", chain)).unwrap();
+        message
+            .validate_network_rules(false)
+            .iter()
+            .map(|error| error.code().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_mt205_correspondent_chain_rules() {
+        assert!(codes(":53A:CORRUS33\r\n:56A:INTMDEFF\r\n:57A:ACWIFRPP\r\n").is_empty());
+        assert_eq!(codes(":56A:INTMDEFFXXX\r\n"), vec!["C81"]);
+        assert!(
+            MT205::parse_from_block4(&BASE.replace("{chain}", ":56A:INTMDEFF\r\n"))
+                .unwrap()
+                .validate_all()
+                .errors
+                .iter()
+                .any(|error| error.code() == "C81")
+        );
+    }
+
+    #[test]
+    fn test_mt205_occurrence_limits() {
+        let message = |count: usize| {
+            let block4 = BASE.replace("{chain}", "").replacen(
+                ":32A:",
+                &format!("{}:32A:", ":13C:/SNDTIME/1200+0100\r\n".repeat(count)),
+                1,
+            );
+            MT205::parse_from_block4(&block4).unwrap()
+        };
+
+        assert!(message(5).validate_occurrence_limits().is_empty());
+        let errors = message(6).validate_occurrence_limits();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), "T11");
+    }
+}
@@ -3,6 +3,7 @@ use crate::fields::*;
 use crate::parser::MessageParser;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT210: Notice to Receive**
 ///
@@ -251,6 +252,41 @@ impl crate::traits::SwiftMessageBody for MT210 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.transaction_reference)
+            .optional("25", &self.account_identification)
+            .field("30", &self.value_date);
+        for txn in &self.transactions {
+            visitor
+                .optional("21", &txn.related_reference)
+                .field("32B", &txn.currency_amount)
+                .optional("50", &txn.ordering_customer)
+                .optional("52", &txn.ordering_institution)
+                .optional("56", &txn.intermediary);
+        }
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let header = [
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("25", "35x", "Account Identification").optional(),
+            FieldSpec::new("30", "6!n", "Value Date"),
+        ];
+        let transactions = [
+            FieldSpec::new("21", "16x", "Related Reference").optional(),
+            FieldSpec::new("32B", "3!a15d", "Currency Code, Amount"),
+            FieldSpec::new("50a", "No letter option, C or F", "Ordering Customer").optional(),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("Transaction"));
+
+        header.into_iter().chain(transactions).collect()
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -275,3 +311,18 @@ impl crate::traits::SwiftMessageBody for MT210 {
         MT210::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+impl crate::traits::CurrencyInfo for MT210 {
+    fn currency(&self) -> Option<&str> {
+        self.transactions
+            .first()
+            .map(|tx| tx.currency_amount.currency.as_str())
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        self.transactions
+            .iter()
+            .map(|tx| tx.currency_amount.currency.clone())
+            .collect()
+    }
+}
@@ -31,6 +31,9 @@ pub struct MT292 {
     pub field_79: Option<Field79>,
 
     /// Copy of mandatory fields from original message
+    ///
+    /// Parsing keeps copied fields in field 79 (see [`Field79::as_copied_fields`]), so this
+    /// is only filled when deserializing.
     #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
     pub original_fields: HashMap<String, serde_json::Value>,
 }
@@ -46,14 +49,19 @@ impl MT292 {
         let field_11s = parser.parse_field::<Field11S>("11S")?;
 
         // Parse optional/conditional Field 79
-        let field_79 = parser.parse_optional_field::<Field79>("79")?;
-
-        // Collect any remaining fields as original message fields
-        // This would need to be implemented in MessageParser but for now use empty HashMap
-        let original_fields = HashMap::new();
+        let mut field_79 = parser.parse_optional_field::<Field79>("79")?;
+
+        // Copied fields of the original message follow the narrative; keep them in field 79
+        if let Some(ref mut field_79) = field_79
+            && !parser.is_complete()
+        {
+            let is_copied = super::mt192::original_field_matcher(&field_11s);
+            field_79.extend_lines(parser.take_copied_fields(is_copied)?)?;
+        }
 
-        // Validation: Either Field 79 or original fields must be present
-        if field_79.is_none() && original_fields.is_empty() {
+        // Validation: Either Field 79 or original fields must be present; copied fields are
+        // only read after field 79, so field 79 is required here
+        if field_79.is_none() {
             return Err(ParseError::InvalidFormat {
                 message:
                     "MT292: Either Field 79 or copy of original message fields must be present"
@@ -66,7 +74,7 @@ impl MT292 {
             field_21,
             field_11s,
             field_79,
-            original_fields,
+            original_fields: HashMap::new(),
         })
     }
 
@@ -112,6 +120,14 @@ impl MT292 {
         None
     }
 
+    /// Correlate this cancellation request with the original MT103
+    ///
+    /// Checks that field 21 references the original field 20 and that any fields
+    /// copied into field 79 (20, 32A, 50a, 59a) match the original message.
+    pub fn correlate_cancellation(&self, original: &super::MT103) -> crate::ValidationResult {
+        super::mt192::correlate_with_mt103(&self.field_21, self.field_79.as_ref(), original)
+    }
+
     /// Main validation method - validates all network rules
     /// Returns array of validation errors, respects stop_on_first_error flag
     pub fn validate_network_rules(&self, stop_on_first_error: bool) -> Vec<SwiftValidationError> {
@@ -138,6 +154,34 @@ impl crate::traits::SwiftMessageBody for MT292 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("11S", &self.field_11s)
+            .optional("79", &self.field_79);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new(
+                "11S",
+                "3!n6!n[4!n6!n]",
+                "MT and Date of the Original Message",
+            ),
+            FieldSpec::new(
+                "79",
+                "35*50x",
+                "Narrative Description of the Original Message",
+            )
+            .optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -158,6 +158,46 @@ impl crate::traits::SwiftMessageBody for MT296 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("76", &self.field_76)
+            .optional("77A", &self.field_77a)
+            .optional("11R", &self.field_11r)
+            .optional("11S", &self.field_11s)
+            .optional("79", &self.field_79);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("76", "6*35x", "Answers"),
+            FieldSpec::new("77A", "20*35x", "Narrative").optional(),
+            FieldSpec::new(
+                "11R",
+                "3!n6!n[4!n6!n]",
+                "MT and Date of the Original Message",
+            )
+            .optional(),
+            FieldSpec::new(
+                "11S",
+                "3!n6!n[4!n6!n]",
+                "MT and Date of the Original Message",
+            )
+            .optional(),
+            FieldSpec::new(
+                "79",
+                "35*50x",
+                "Narrative Description of the Original Message",
+            )
+            .optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -2,6 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT900: Confirmation of Debit**
 ///
@@ -96,20 +97,87 @@ impl MT900 {
         finalize_mt_string(result, false)
     }
 
+    /// Check that this confirmation matches the transfer it confirms
+    ///
+    /// Field 21 must carry the original's field 20, and field 32A must repeat its value
+    /// date, currency and amount. Works for any [`CommonFields`](crate::CommonFields)
+    /// message, e.g. an MT103 or MT202.
+    pub fn validate_confirmation_against(
+        &self,
+        original: &dyn crate::traits::CommonFields,
+    ) -> crate::ValidationResult {
+        validate_confirmation_link(&self.field_21, &self.field_32a, original)
+    }
+
     // ========================================================================
     // NETWORK VALIDATION RULES (SR 2025 MT900)
     // ========================================================================
 
     /// Main validation method - validates all network rules
     ///
-    /// **Note**: According to SR 2025 specifications, MT900 has no network validated rules.
-    /// This method always returns an empty vector.
+    /// **Note**: SR 2025 defines no network validated rules (C/D/E series) for MT900, so no
+    /// such errors are ever reported. The one check performed is a format check: an empty
+    /// mandatory related reference (field 21) is reported as T14, which matters for
+    /// messages built in code or from JSON.
     ///
     /// Returns array of validation errors, respects stop_on_first_error flag
     pub fn validate_network_rules(&self, _stop_on_first_error: bool) -> Vec<SwiftValidationError> {
-        // MT900 has no network validated rules per SR 2025 specifications
-        Vec::new()
+        self.field_21.validate_present().into_iter().collect()
+    }
+}
+
+/// Match a confirmation's fields 21 and 32A against the confirmed transfer (C21)
+pub(crate) fn validate_confirmation_link(
+    related_reference: &Field21NoOption,
+    field_32a: &Field32A,
+    original: &dyn crate::traits::CommonFields,
+) -> crate::ValidationResult {
+    let mut errors = Vec::new();
+
+    if related_reference.reference != original.reference() {
+        errors.push(
+            SwiftValidationError::relation_error(
+                crate::swift_error_codes::c_series::C21,
+                "21",
+                vec!["20".to_string()],
+                &format!(
+                    "Related reference '{}' does not match the confirmed transfer's reference '{}'",
+                    related_reference.reference,
+                    original.reference()
+                ),
+                "Field 21 of a confirmation must contain field 20 of the confirmed transfer",
+            )
+            .into(),
+        );
     }
+
+    let settled = original.value_date_amount();
+    let same_amount = matches!(
+        (field_32a.to_amount(), settled.to_amount()),
+        (Ok(confirmed), Ok(settled)) if confirmed == settled
+    );
+    if field_32a.value_date != settled.value_date || !same_amount {
+        errors.push(
+            SwiftValidationError::relation_error(
+                crate::swift_error_codes::c_series::C21,
+                "32A",
+                vec!["32A".to_string()],
+                &format!(
+                    "Confirmed {} {} {} does not match the transfer's {} {} {}",
+                    field_32a.value_date,
+                    field_32a.currency,
+                    field_32a.amount,
+                    settled.value_date,
+                    settled.currency,
+                    settled.amount
+                ),
+                "Field 32A of a confirmation must repeat the value date, currency and amount of the confirmed transfer",
+            )
+            .into(),
+        );
+    }
+
+    crate::ValidationResult::with_errors(errors)
 }
 
 impl crate::traits::SwiftMessageBody for MT900 {
@@ -122,6 +190,31 @@ impl crate::traits::SwiftMessageBody for MT900 {
         MT900::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("25", &self.field_25)
+            .optional("13D", &self.field_13d)
+            .field("32A", &self.field_32a)
+            .optional("52", &self.field_52)
+            .optional("72", &self.field_72);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("25a", "No letter option or P", "Account Identification"),
+            FieldSpec::new("13D", "6!n4!n1!x4!n", "Date/Time Indication").optional(),
+            FieldSpec::new("32A", "6!n3!a15d", "Value Date, Currency Code, Amount"),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT900::to_mt_string(self)
@@ -133,6 +226,16 @@ impl crate::traits::SwiftMessageBody for MT900 {
     }
 }
 
+impl crate::traits::CurrencyInfo for MT900 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32a.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        BTreeSet::from([self.field_32a.currency.clone()])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +275,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mt900_confirmation_against_mt103() {
+        let mt103 = <crate::MT103 as crate::traits::SwiftMessageBody>::parse_from_block4(
+            ":20:REF20240719001\n:23B:CRED\n:32A:240719USD1000,00\n:50K:JOHN DOE\n:59:/12345678\nJANE SMITH\n:71A:OUR\n-",
+        )
+        .unwrap();
+        let mut mt900 = MT900::parse_from_block4(
+            ":20:20240719001\n:21:REF20240719001\n:25:12345678901234567890\n:32A:240719USD1000,00\n-",
+        )
+        .unwrap();
+        assert!(mt900.validate_confirmation_against(&mt103).is_valid);
+
+        mt900.field_32a.amount = 999.99;
+        let result = mt900.validate_confirmation_against(&mt103);
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+    }
+
+    #[test]
+    fn test_mt900_related_reference_presence() {
+        let mt900_text =
+            ":20:20240719001\n:21:NONREF\n:25:12345678901234567890\n:32A:240719USD1000,00\n-";
+        let mut mt900 = MT900::parse_from_block4(mt900_text).unwrap();
+        assert!(mt900.field_21.is_nonref());
+        assert!(mt900.validate_network_rules(false).is_empty());
+
+        mt900.field_21.reference.clear();
+        let errors = mt900.validate_network_rules(false);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), "T14");
+    }
+
     #[test]
     fn test_mt900_trait_validate_network_rules() {
         use crate::traits::SwiftMessageBody;
@@ -2,6 +2,7 @@ use crate::errors::SwiftValidationError;
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// **MT910: Confirmation of Credit**
 ///
@@ -110,6 +111,18 @@ impl MT910 {
         finalize_mt_string(result, false)
     }
 
+    /// Check that this confirmation matches the transfer it confirms
+    ///
+    /// Field 21 must carry the original's field 20, and field 32A must repeat its value
+    /// date, currency and amount. Works for any [`CommonFields`](crate::CommonFields)
+    /// message, e.g. an MT103 or MT202.
+    pub fn validate_confirmation_against(
+        &self,
+        original: &dyn crate::traits::CommonFields,
+    ) -> crate::ValidationResult {
+        super::mt900::validate_confirmation_link(&self.field_21, &self.field_32a, original)
+    }
+
     // ========================================================================
     // NETWORK VALIDATION RULES (SR 2025 MT910)
     // ========================================================================
@@ -178,6 +191,35 @@ impl crate::traits::SwiftMessageBody for MT910 {
         MT910::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("21", &self.field_21)
+            .field("25", &self.field_25)
+            .optional("13D", &self.field_13d)
+            .field("32A", &self.field_32a)
+            .optional("50", &self.field_50)
+            .optional("52", &self.field_52)
+            .optional("56", &self.field_56)
+            .optional("72", &self.field_72);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference"),
+            FieldSpec::new("25a", "No letter option or P", "Account Identification"),
+            FieldSpec::new("13D", "6!n4!n1!x4!n", "Date/Time Indication").optional(),
+            FieldSpec::new("32A", "6!n3!a15d", "Value Date, Currency Code, Amount"),
+            FieldSpec::new("50a", "Option A, F or K", "Ordering Customer").optional(),
+            FieldSpec::new("52a", "Option A or D", "Ordering Institution").optional(),
+            FieldSpec::new("56a", "Option A, C or D", "Intermediary").optional(),
+            FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT910::to_mt_string(self)
@@ -189,6 +231,16 @@ impl crate::traits::SwiftMessageBody for MT910 {
     }
 }
 
+impl crate::traits::CurrencyInfo for MT910 {
+    fn currency(&self) -> Option<&str> {
+        Some(&self.field_32a.currency)
+    }
+
+    fn currencies(&self) -> BTreeSet<String> {
+        BTreeSet::from([self.field_32a.currency.clone()])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +262,24 @@ NEW YORK
         assert_eq!(mt910.field_21.reference, "REF20240719001");
     }
 
+    #[test]
+    fn test_mt910_confirmation_against_mt202() {
+        let mt202 = <crate::MT202 as crate::traits::SwiftMessageBody>::parse_from_block4(
+            ":20:FI20240719001\n:21:RELREF\n:32A:240719EUR250000,00\n:58A:DEUTDEFFXXX\n-",
+        )
+        .unwrap();
+        let mut mt910 = MT910::parse_from_block4(
+            ":20:CONF001\n:21:FI20240719001\n:25:12345678901234567890\n:32A:240719EUR250000,00\n:52A:BNPAFRPPXXX\n-",
+        )
+        .unwrap();
+        assert!(mt910.validate_confirmation_against(&mt202).is_valid);
+
+        mt910.field_21.reference = "OTHERREF".to_string();
+        mt910.field_32a.currency = "USD".to_string();
+        let result = mt910.validate_confirmation_against(&mt202);
+        assert_eq!(result.errors.len(), 2);
+    }
+
     #[test]
     fn test_mt910_validation_c1_fails_without_ordering_party() {
         // Test without field 50 and 52 - should fail validation
@@ -309,6 +309,33 @@ impl crate::traits::SwiftMessageBody for MT920 {
         MT920::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor.field("20", &self.field_20);
+        for seq in &self.sequence {
+            visitor
+                .field("12", &seq.field_12)
+                .field("25", &seq.field_25)
+                .optional("34F", &seq.floor_limit_debit)
+                .optional("34F", &seq.floor_limit_credit);
+        }
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let header = [FieldSpec::new("20", "16x", "Transaction Reference Number")];
+        let requests = [
+            FieldSpec::new("12", "3!n", "Message Requested"),
+            FieldSpec::new("25", "35x", "Account Identification"),
+            FieldSpec::new("34F", "3!a[1!a]15d", "Floor Limit Indicator")
+                .optional()
+                .max(2),
+        ]
+        .map(|spec| spec.repeating_sequence("Request"));
+
+        header.into_iter().chain(requests).collect()
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT920::to_mt_string(self)
@@ -31,7 +31,7 @@ pub struct MT935 {
 pub struct MT935RateChange {
     /// Further Identification (Field 23)
     #[serde(rename = "23", skip_serializing_if = "Option::is_none")]
-    pub field_23: Option<Field23>,
+    pub field_23: Option<Field23CurrencyFunction>,
 
     /// Account Identification (Field 25)
     #[serde(rename = "25", skip_serializing_if = "Option::is_none")]
@@ -62,7 +62,7 @@ impl MT935 {
 
         while (parser.detect_field("23") || parser.detect_field("25")) && rate_changes.len() < 10 {
             // Parse optional Field 23 - Further Identification (mutually exclusive with field_25)
-            let field_23 = parser.parse_optional_field::<Field23>("23")?;
+            let field_23 = parser.parse_optional_field::<Field23CurrencyFunction>("23")?;
 
             // Parse optional Field 25 - Account Identification (mutually exclusive with field_23)
             let field_25 = parser.parse_optional_field::<Field25NoOption>("25")?;
@@ -228,33 +228,16 @@ impl MT935 {
 
         for (idx, seq) in self.rate_changes.iter().enumerate() {
             if let Some(ref field_23) = seq.field_23 {
-                // Reconstruct the full value as it appears in SWIFT format
-                let mut value = field_23.function_code.clone();
-                if let Some(days) = field_23.days {
-                    value.push_str(&format!("{:02}", days));
-                }
-                value.push_str(&field_23.reference);
-
-                // Minimum length check: 3 (currency) + at least one function character
-                if value.len() < 4 {
-                    errors.push(SwiftValidationError::format_error(
-                        "T26",
-                        "23",
-                        &value,
-                        "3!a[2!n]11x",
-                        &format!(
-                            "Sequence {}: Field 23 must be at least 4 characters (currency code + function)",
-                            idx + 1
-                        ),
-                    ));
-                    continue;
-                }
-
-                // Extract currency (first 3 characters)
-                let currency = &value[..3];
+                let days = field_23
+                    .days
+                    .map(|d| format!("{:02}", d))
+                    .unwrap_or_default();
+                let value = format!("{}{}{}", field_23.currency, days, field_23.function);
+                let currency = field_23.currency.as_str();
+                let function = field_23.function.as_str();
 
                 // Validate currency is alphabetic
-                if !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+                if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
                     errors.push(SwiftValidationError::format_error(
                         "T26",
                         "23",
@@ -268,20 +251,6 @@ impl MT935 {
                     ));
                 }
 
-                // Extract remaining part (could be [2!n]function or just function)
-                let remaining = &value[3..];
-
-                // Check if next 2 characters are digits (Number of Days)
-                let (num_days, function_start) =
-                    if remaining.len() >= 2 && remaining[..2].chars().all(|c| c.is_ascii_digit()) {
-                        (Some(&remaining[..2]), 2)
-                    } else {
-                        (None, 0)
-                    };
-
-                // Extract function code
-                let function = &remaining[function_start..];
-
                 // Validate function code
                 if !Self::VALID_23_FUNCTION_CODES.contains(&function) {
                     errors.push(SwiftValidationError::content_error(
@@ -302,9 +271,7 @@ impl MT935 {
                 }
 
                 // Validate Number of Days only allowed with NOTICE
-                if let Some(days) = num_days
-                    && function != "NOTICE"
-                {
+                if field_23.days.is_some() && function != "NOTICE" {
                     errors.push(SwiftValidationError::content_error(
                             "T26",
                             "23",
@@ -330,8 +297,8 @@ impl MT935 {
 
         for (seq_idx, seq) in self.rate_changes.iter().enumerate() {
             for (field_idx, field_37h) in seq.field_37h.iter().enumerate() {
-                let indicator = field_37h.rate_indicator;
-                let is_negative = field_37h.is_negative;
+                let indicator = field_37h.indicator;
+                let sign = field_37h.sign;
                 let rate = field_37h.rate;
 
                 // T51: Validate indicator is C or D
@@ -351,7 +318,7 @@ impl MT935 {
                 }
 
                 // T14: Sign must not be used if rate is zero
-                if rate.abs() < 0.00001 && is_negative.is_some() {
+                if rate.abs() < 0.00001 && sign.is_some() {
                     errors.push(SwiftValidationError::content_error(
                         "T14",
                         "37H",
@@ -415,6 +382,38 @@ impl crate::traits::SwiftMessageBody for MT935 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor.field("20", &self.field_20);
+        for rate_change in &self.rate_changes {
+            visitor
+                .optional("23", &rate_change.field_23)
+                .optional("25", &rate_change.field_25)
+                .field("30", &rate_change.field_30)
+                .repeated("37H", &rate_change.field_37h);
+        }
+        visitor.optional("72", &self.field_72);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let header = [FieldSpec::new("20", "16x", "Transaction Reference Number")];
+        let rate_changes = [
+            FieldSpec::new("23", "3!a[2!n]11x", "Further Identification").optional(),
+            FieldSpec::new("25", "35x", "Account Identification").optional(),
+            FieldSpec::new("30", "6!n", "Effective Date of New Rate"),
+            FieldSpec::new("37H", "1!a[N]12d", "New Interest Rate").max(10),
+        ]
+        .map(|spec| spec.repeating_sequence("Rate Change"));
+        let trailer = [FieldSpec::new("72", "6*35x", "Sender to Receiver Information").optional()];
+
+        header
+            .into_iter()
+            .chain(rate_changes)
+            .chain(trailer)
+            .collect()
+    }
+
     fn to_mt_string(&self) -> String {
         use crate::traits::SwiftField;
         let mut result = String::new();
@@ -444,3 +443,26 @@ impl crate::traits::SwiftMessageBody for MT935 {
         MT935::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::SwiftMessageBody;
+
+    #[test]
+    fn test_mt935_bare_field_23() {
+        let block4 = ":20:RATECHG1\n:23:USDBASE\n:30:250101\n:37H:C4,5\n:23:EUR07NOTICE\n:30:250101\n:37H:D1,25\n-";
+        let mt935 = <MT935 as SwiftMessageBody>::parse_from_block4(block4).unwrap();
+
+        assert_eq!(mt935.rate_changes.len(), 2);
+        let notice = mt935.rate_changes[1].field_23.as_ref().unwrap();
+        assert_eq!(notice.days, Some(7));
+        assert_eq!(notice.function, "NOTICE");
+
+        // Serialized under the bare "23" tag, distinct from 23B/23E
+        let json = serde_json::to_value(&mt935).unwrap();
+        assert_eq!(json["#"][0]["23"]["currency"], "USD");
+        assert_eq!(json["#"][0]["23"]["function"], "BASE");
+        assert!(mt935.to_mt_string().contains(":23:EUR07NOTICE"));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::errors::SwiftValidationError;
+use crate::fields::swift_utils::{Amount, max_statement_lines, too_many_statement_lines};
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
@@ -78,10 +79,14 @@ impl MT940 {
         // Enable duplicate field handling for statement lines
         parser = parser.with_duplicates(true);
 
-        // Parse statement lines (1-500)
-        let mut statement_lines = Vec::new();
+        // Parse statement lines, bounded by ParserConfig::max_statement_lines
+        let max_lines = max_statement_lines();
+        let mut statement_lines = Vec::with_capacity(parser.count_remaining("61").min(max_lines));
 
-        while parser.detect_field("61") && statement_lines.len() < 500 {
+        while parser.detect_field("61") {
+            if statement_lines.len() == max_lines {
+                return Err(too_many_statement_lines("940", max_lines));
+            }
             let field_61 = parser.parse_field::<Field61>("61")?;
             let field_86 = parser.parse_optional_field::<Field86>("86")?;
 
@@ -288,6 +293,27 @@ impl MT940 {
 
         all_errors
     }
+
+    /// Check that closing balance 62F equals opening balance 60F plus the net of all field 61 entries
+    ///
+    /// Reports a currency mismatch (C27) or a balance difference (C01) as relation errors.
+    pub fn reconcile_balances(&self) -> crate::ValidationResult {
+        reconcile_statement_balances(
+            StatementBalance {
+                tag: "60F",
+                debit_credit_mark: &self.field_60f.debit_credit_mark,
+                currency: &self.field_60f.currency,
+                amount: self.field_60f.amount,
+            },
+            self.statement_lines.iter().map(|line| &line.field_61),
+            StatementBalance {
+                tag: "62F",
+                debit_credit_mark: &self.field_62f.debit_credit_mark,
+                currency: &self.field_62f.currency,
+                amount: self.field_62f.amount,
+            },
+        )
+    }
 }
 
 impl crate::traits::SwiftMessageBody for MT940 {
@@ -300,6 +326,64 @@ impl crate::traits::SwiftMessageBody for MT940 {
         MT940::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("21", &self.field_21)
+            .field("25", &self.field_25)
+            .field("28C", &self.field_28c)
+            .field("60F", &self.field_60f);
+        for statement_line in &self.statement_lines {
+            visitor
+                .field("61", &statement_line.field_61)
+                .optional("86", &statement_line.field_86);
+        }
+        visitor
+            .field("62F", &self.field_62f)
+            .optional("64", &self.field_64)
+            .optional_repeated("65", &self.field_65);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let header = [
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference").optional(),
+            FieldSpec::new("25", "35x", "Account Identification"),
+            FieldSpec::new("28C", "5n[/5n]", "Statement Number/Sequence Number"),
+            FieldSpec::new("60F", "1!a6!n3!a15d", "Opening Balance"),
+        ];
+        let statement_lines = [
+            FieldSpec::new(
+                "61",
+                "6!n[4!n]2a[1!a]15d1!a3!c16x[//16x][34x]",
+                "Statement Line",
+            )
+            .optional(),
+            FieldSpec::new("86", "6*65x", "Information to Account Owner").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("Statement Line"));
+        let trailer = [
+            FieldSpec::new("62F", "1!a6!n3!a15d", "Closing Balance (Booked Funds)"),
+            FieldSpec::new(
+                "64",
+                "1!a6!n3!a15d",
+                "Closing Available Balance (Available Funds)",
+            )
+            .optional(),
+            FieldSpec::new("65", "1!a6!n3!a15d", "Forward Available Balance")
+                .optional()
+                .repetitive(),
+        ];
+
+        header
+            .into_iter()
+            .chain(statement_lines)
+            .chain(trailer)
+            .collect()
+    }
+
     fn to_mt_string(&self) -> String {
         // Call the existing public method implementation
         MT940::to_mt_string(self)
@@ -335,3 +419,156 @@ impl MT940 {
         finalize_mt_string(result, false)
     }
 }
+
+/// Debit/credit balance taken from a 60a, 62a or similar balance field
+pub(crate) struct StatementBalance<'a> {
+    pub tag: &'a str,
+    pub debit_credit_mark: &'a str,
+    pub currency: &'a str,
+    pub amount: f64,
+}
+
+/// Reconcile an opening balance and statement lines against the stated closing balance
+pub(crate) fn reconcile_statement_balances<'a>(
+    opening: StatementBalance<'_>,
+    lines: impl Iterator<Item = &'a Field61>,
+    closing: StatementBalance<'_>,
+) -> crate::ValidationResult {
+    if opening.currency != closing.currency {
+        return crate::ValidationResult::with_error(
+            SwiftValidationError::relation_error(
+                "C27",
+                closing.tag,
+                vec![opening.tag.to_string()],
+                &format!(
+                    "Closing balance currency {} does not match opening balance currency {}",
+                    closing.currency, opening.currency
+                ),
+                "Opening and closing balances must be in the same currency",
+            )
+            .into(),
+        );
+    }
+
+    let signed = |mark: &str, amount: f64| match mark {
+        "D" | "RC" => -amount,
+        _ => amount,
+    };
+    let expected = Amount::sum(
+        opening.currency,
+        std::iter::once(signed(opening.debit_credit_mark, opening.amount))
+            .chain(lines.map(|line| signed(&line.debit_credit_mark, line.amount))),
+    );
+    let stated = Amount::new(
+        opening.currency,
+        signed(closing.debit_credit_mark, closing.amount),
+    );
+    if let (Ok(expected), Ok(stated)) = (&expected, &stated)
+        && expected == stated
+    {
+        return crate::ValidationResult::valid();
+    }
+
+    let display = |amount: crate::Result<Amount>| match amount {
+        Ok(amount) => format!(
+            "{}{}",
+            if amount.minor_units() < 0 { "D" } else { "C" },
+            Amount::from_minor_units(amount.currency(), amount.minor_units().abs()).to_swift_str()
+        ),
+        Err(error) => error.to_string(),
+    };
+
+    crate::ValidationResult::with_error(
+        SwiftValidationError::relation_error(
+            crate::swift_error_codes::c_series::C01,
+            closing.tag,
+            vec![opening.tag.to_string(), "61".to_string()],
+            &format!(
+                "Closing balance {} {} does not equal opening balance plus statement lines {} {}",
+                display(stated),
+                closing.currency,
+                display(expected),
+                closing.currency
+            ),
+            "Closing balance must equal opening balance plus the net of all statement line amounts",
+        )
+        .into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::SwiftMessageBody;
+
+    const STATEMENT: &str = ":20:STMT001
+:25:123456789
+:28C:1/1
+:60F:C250101EUR1000,00
+:61:250101C500,00NTRFREF1
+:61:250101D200,50NTRFREF2
+:62F:C250101EUR1299,50
+-";
+
+    #[test]
+    fn test_mt940_reconcile_balances() {
+        let mt940 = MT940::parse_from_block4(STATEMENT).unwrap();
+        let result = mt940.reconcile_balances();
+        assert!(result.is_valid, "{:?}", result.errors);
+
+        let off_balance = STATEMENT.replace(":62F:C250101EUR1299,50", ":62F:C250101EUR1300,00");
+        let mt940 = <MT940 as SwiftMessageBody>::parse_from_block4(&off_balance).unwrap();
+        let result = mt940.reconcile_balances();
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+        let message = result.errors[0].to_string();
+        assert!(message.contains("C1300,00"));
+        assert!(message.contains("C1299,50"));
+    }
+
+    #[test]
+    fn test_mt940_reconcile_balances_currency_mismatch() {
+        let mixed = STATEMENT.replace(":62F:C250101EUR1299,50", ":62F:C250101USD1299,50");
+        let mt940 = MT940::parse_from_block4(&mixed).unwrap();
+        let result = mt940.reconcile_balances();
+        assert!(!result.is_valid);
+        assert!(result.errors[0].to_string().contains("currency"));
+    }
+
+    /// Block 4 of a statement with `lines` credit entries of 1,00 each
+    fn large_statement(lines: usize) -> String {
+        let mut block4 =
+            String::from(":20:STMT001\n:25:123456789\n:28C:1/1\n:60F:C250101EUR0,00\n");
+        for i in 0..lines {
+            block4.push_str(&format!(":61:250101C1,00NTRFREF{i}\n:86:LINE {i}\n"));
+        }
+        block4.push_str(&format!(":62F:C250101EUR{lines},00\n-"));
+        block4
+    }
+
+    #[test]
+    fn test_mt940_large_statement_reserves_lines() {
+        let mt940 = MT940::parse_from_block4(&large_statement(5000)).unwrap();
+        assert_eq!(mt940.statement_lines.len(), 5000);
+        assert!(mt940.reconcile_balances().is_valid);
+
+        // Statement lines are reserved up front instead of growing while parsing
+        assert_eq!(mt940.statement_lines.capacity(), 5000);
+    }
+
+    #[test]
+    fn test_mt940_max_statement_lines() {
+        let config = crate::ParserConfig {
+            max_statement_lines: 10,
+            ..Default::default()
+        };
+        crate::fields::swift_utils::with_field_parse_options(&config, || {
+            assert!(MT940::parse_from_block4(&large_statement(10)).is_ok());
+            let err = MT940::parse_from_block4(&large_statement(11)).unwrap_err();
+            assert!(
+                err.to_string().contains("more than 10 statement lines"),
+                "{err}"
+            );
+        });
+    }
+}
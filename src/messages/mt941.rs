@@ -283,6 +283,48 @@ impl crate::traits::SwiftMessageBody for MT941 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("21", &self.field_21)
+            .field("25", &self.field_25)
+            .field("28", &self.field_28)
+            .optional("13D", &self.field_13d)
+            .optional("60F", &self.field_60f)
+            .optional("90D", &self.field_90d)
+            .optional("90C", &self.field_90c)
+            .field("62F", &self.field_62f)
+            .optional("64", &self.field_64)
+            .optional_repeated("65", &self.field_65)
+            .optional("86", &self.field_86);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference").optional(),
+            FieldSpec::new("25a", "No letter option or P", "Account Identification"),
+            FieldSpec::new("28", "5n[/2n]", "Statement Number/Sequence Number"),
+            FieldSpec::new("13D", "6!n4!n1!x4!n", "Date/Time Indication").optional(),
+            FieldSpec::new("60F", "1!a6!n3!a15d", "Opening Balance").optional(),
+            FieldSpec::new("90D", "5n3!a15d", "Number and Sum of Entries").optional(),
+            FieldSpec::new("90C", "5n3!a15d", "Number and Sum of Entries").optional(),
+            FieldSpec::new("62F", "1!a6!n3!a15d", "Book Balance"),
+            FieldSpec::new(
+                "64",
+                "1!a6!n3!a15d",
+                "Closing Available Balance (Available Funds)",
+            )
+            .optional(),
+            FieldSpec::new("65", "1!a6!n3!a15d", "Forward Available Balance")
+                .optional()
+                .repetitive(),
+            FieldSpec::new("86", "6*65x", "Information to Account Owner").optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -1,4 +1,7 @@
 use crate::errors::SwiftValidationError;
+use crate::fields::swift_utils::{
+    Amount, format_swift_amount_for_currency, max_statement_lines, too_many_statement_lines,
+};
 use crate::fields::*;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
@@ -105,10 +108,14 @@ impl MT942 {
         // Enable duplicate field handling for statement lines
         parser = parser.with_duplicates(true);
 
-        // Parse statement lines (optional, repetitive)
-        let mut statement_lines = Vec::new();
+        // Parse statement lines (optional, repetitive), bounded by ParserConfig::max_statement_lines
+        let max_lines = max_statement_lines();
+        let mut statement_lines = Vec::with_capacity(parser.count_remaining("61").min(max_lines));
 
         while parser.detect_field("61") {
+            if statement_lines.len() == max_lines {
+                return Err(too_many_statement_lines("942", max_lines));
+            }
             let field_61 = parser.parse_field::<Field61>("61")?;
             let field_86 = parser.parse_optional_field::<Field86>("86")?;
 
@@ -297,6 +304,80 @@ impl MT942 {
         errors
     }
 
+    /// Check fields 90D/90C against the number and sum of debit and credit statement lines
+    ///
+    /// Debit entries are marks D and RC, credit entries C and RD. Sums are compared in the
+    /// minor units of the summary currency; each mismatching summary is a D80 relation error.
+    pub fn reconcile_summaries(&self) -> crate::ValidationResult {
+        let mut errors = Vec::new();
+        let summaries = [
+            (
+                "90D",
+                "debit",
+                true,
+                self.field_90d
+                    .as_ref()
+                    .map(|f| (f.number, &f.currency, f.amount)),
+            ),
+            (
+                "90C",
+                "credit",
+                false,
+                self.field_90c
+                    .as_ref()
+                    .map(|f| (f.number, &f.currency, f.amount)),
+            ),
+        ];
+
+        for (tag, side, debit, summary) in summaries {
+            let Some((number, currency, amount)) = summary else {
+                continue;
+            };
+            let entries: Vec<&Field61> = self
+                .statement_lines
+                .iter()
+                .map(|line| &line.field_61)
+                .filter(|line| matches!(line.debit_credit_mark.as_str(), "D" | "RC") == debit)
+                .collect();
+            let count = entries.len() as u32;
+            let sum = Amount::sum(currency, entries.iter().map(|line| line.amount));
+
+            let matches = match (&sum, Amount::new(currency, amount)) {
+                (Ok(sum), Ok(stated)) => *sum == stated,
+                _ => false,
+            };
+            if count != number || !matches {
+                let total = sum.map_or_else(|error| error.to_string(), |sum| sum.to_swift_str());
+                errors.push(
+                    SwiftValidationError::relation_error(
+                        crate::swift_error_codes::d_series::D80,
+                        tag,
+                        vec!["61".to_string()],
+                        &format!(
+                            "Field {} states {} {} entries totalling {} {}, statement lines have {} totalling {} {}",
+                            tag,
+                            number,
+                            side,
+                            currency,
+                            format_swift_amount_for_currency(amount, currency),
+                            count,
+                            currency,
+                            total
+                        ),
+                        "Number and sum of entries must match the statement lines",
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            crate::ValidationResult::valid()
+        } else {
+            crate::ValidationResult::with_errors(errors)
+        }
+    }
+
     /// Main validation method - validates all network rules
     /// Returns array of validation errors, respects stop_on_first_error flag
     pub fn validate_network_rules(&self, stop_on_first_error: bool) -> Vec<SwiftValidationError> {
@@ -335,6 +416,60 @@ impl crate::traits::SwiftMessageBody for MT942 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .optional("21", &self.field_21)
+            .field("25", &self.field_25)
+            .field("28C", &self.field_28c)
+            .field("34F", &self.floor_limit_debit)
+            .optional("34F", &self.floor_limit_credit)
+            .field("13D", &self.field_13d);
+        for statement_line in &self.statement_lines {
+            visitor
+                .field("61", &statement_line.field_61)
+                .optional("86", &statement_line.field_86);
+        }
+        visitor
+            .optional("90D", &self.field_90d)
+            .optional("90C", &self.field_90c)
+            .optional("86", &self.field_86);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        let header = [
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("21", "16x", "Related Reference").optional(),
+            FieldSpec::new("25a", "No letter option or P", "Account Identification"),
+            FieldSpec::new("28C", "5n[/5n]", "Statement Number/Sequence Number"),
+            FieldSpec::new("34F", "3!a[1!a]15d", "Floor Limit Indicator").max(2),
+            FieldSpec::new("13D", "6!n4!n1!x4!n", "Date/Time Indication"),
+        ];
+        let statement_lines = [
+            FieldSpec::new(
+                "61",
+                "6!n[4!n]2a[1!a]15d1!a3!c16x[//16x][34x]",
+                "Statement Line",
+            )
+            .optional(),
+            FieldSpec::new("86", "6*65x", "Information to Account Owner").optional(),
+        ]
+        .map(|spec| spec.repeating_sequence("Statement Line"));
+        let trailer = [
+            FieldSpec::new("90D", "5n3!a15d", "Number and Sum of Entries").optional(),
+            FieldSpec::new("90C", "5n3!a15d", "Number and Sum of Entries").optional(),
+            FieldSpec::new("86", "6*65x", "Information to Account Owner").optional(),
+        ];
+
+        header
+            .into_iter()
+            .chain(statement_lines)
+            .chain(trailer)
+            .collect()
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -364,3 +499,73 @@ impl crate::traits::SwiftMessageBody for MT942 {
         MT942::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::SwiftMessageBody;
+
+    #[test]
+    fn test_mt942_from_ordered_fields_assigns_field_86_by_position() {
+        let field =
+            |tag: &str, value: &str, position| (tag.to_string(), value.to_string(), position);
+        // Listed out of order; positions put the first 86 after 61 and the second after 90D
+        let fields = vec![
+            field("86", "ACCOUNT OWNER INFO", 9),
+            field("20", "STMT001", 0),
+            field("25", "123456789", 1),
+            field("28C", "1/1", 2),
+            field("34F", "EURD100,", 3),
+            field("13D", "2501011200+0100", 4),
+            field("61", "250101C500,00NTRFREF1", 5),
+            field("86", "LINE INFO", 6),
+            field("90D", "1EUR100,", 7),
+            field("90C", "1EUR500,00", 8),
+        ];
+
+        let mt942 = <MT942 as SwiftMessageBody>::from_ordered_fields(fields).unwrap();
+        assert_eq!(mt942.statement_lines.len(), 1);
+        assert_eq!(
+            mt942.statement_lines[0]
+                .field_86
+                .as_ref()
+                .unwrap()
+                .narrative,
+            vec!["LINE INFO"]
+        );
+        assert_eq!(
+            mt942.field_86.as_ref().unwrap().narrative,
+            vec!["ACCOUNT OWNER INFO"]
+        );
+    }
+
+    #[test]
+    fn test_mt942_reconcile_summaries() {
+        let block4 = ":20:STMT001\n:25:123456789\n:28C:1/1\n:34F:EURD100,\n:13D:2501011200+0100\n\
+                      :61:250101C500,00NTRFREF1\n:61:250101D200,50NTRFREF2\n:61:250101D99,50NTRFREF3\n\
+                      :90D:2EUR300,00\n:90C:1EUR500,00\n-";
+        let mt942 = <MT942 as SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        let result = mt942.reconcile_summaries();
+        assert!(result.is_valid, "{:?}", result.errors);
+
+        let mismatched = block4.replace(":90D:2EUR300,00", ":90D:3EUR300,01");
+        let mt942 = <MT942 as SwiftMessageBody>::parse_from_block4(&mismatched).unwrap();
+        let result = mt942.reconcile_summaries();
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.len(), 1);
+        let message = result.errors[0].to_string();
+        assert!(message.contains("90D"), "{message}");
+        assert!(message.contains("have 2 totalling EUR 300,"), "{message}");
+
+        // A zero-amount debit line still counts as a debit entry
+        let zero_debit = block4
+            .replace(
+                ":61:250101D99,50NTRFREF3\n",
+                ":61:250101D99,50NTRFREF3\n:61:250101D0,NTRFREF4\n",
+            )
+            .replace(":90D:2EUR300,00", ":90D:3EUR300,00");
+        let mt942 = <MT942 as SwiftMessageBody>::parse_from_block4(&zero_debit).unwrap();
+        let result = mt942.reconcile_summaries();
+        assert!(result.is_valid, "{:?}", result.errors);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::errors::SwiftValidationError;
+use crate::fields::swift_utils::{max_statement_lines, too_many_statement_lines};
 use crate::fields::*;
+use crate::messages::mt940::StatementBalance;
 use crate::parser::utils::*;
 use serde::{Deserialize, Serialize};
 
@@ -68,9 +70,13 @@ impl MT950 {
         // Enable duplicate field handling for statement lines
         parser = parser.with_duplicates(true);
 
-        // Parse optional statement lines (repetitive)
-        let mut field_61_vec = Vec::new();
+        // Parse optional statement lines (repetitive), bounded by ParserConfig::max_statement_lines
+        let max_lines = max_statement_lines();
+        let mut field_61_vec = Vec::with_capacity(parser.count_remaining("61").min(max_lines));
         while parser.detect_field("61") {
+            if field_61_vec.len() == max_lines {
+                return Err(too_many_statement_lines("950", max_lines));
+            }
             if let Ok(field) = parser.parse_field::<Field61>("61") {
                 field_61_vec.push(field);
             } else {
@@ -215,6 +221,56 @@ impl MT950 {
 
         all_errors
     }
+
+    /// Check that closing balance 62a equals opening balance 60a plus the net of all field 61 entries
+    ///
+    /// Reports a currency mismatch (C27) or a balance difference (C01) as relation errors.
+    pub fn reconcile_balances(&self) -> crate::ValidationResult {
+        let opening = match &self.field_60 {
+            Field60::F(field) => (
+                "60F",
+                &field.debit_credit_mark,
+                &field.currency,
+                field.amount,
+            ),
+            Field60::M(field) => (
+                "60M",
+                &field.debit_credit_mark,
+                &field.currency,
+                field.amount,
+            ),
+        };
+        let closing = match &self.field_62 {
+            Field62::F(field) => (
+                "62F",
+                &field.debit_credit_mark,
+                &field.currency,
+                field.amount,
+            ),
+            Field62::M(field) => (
+                "62M",
+                &field.debit_credit_mark,
+                &field.currency,
+                field.amount,
+            ),
+        };
+
+        super::mt940::reconcile_statement_balances(
+            StatementBalance {
+                tag: opening.0,
+                debit_credit_mark: opening.1,
+                currency: opening.2,
+                amount: opening.3,
+            },
+            self.field_61.iter().flatten(),
+            StatementBalance {
+                tag: closing.0,
+                debit_credit_mark: closing.1,
+                currency: closing.2,
+                amount: closing.3,
+            },
+        )
+    }
 }
 
 // Implement the SwiftMessageBody trait for MT950
@@ -227,6 +283,42 @@ impl crate::traits::SwiftMessageBody for MT950 {
         Self::parse_from_block4(block4)
     }
 
+    fn visit_fields<'a>(&'a self, visitor: &mut crate::traits::FieldVisitor<'a, '_>) {
+        visitor
+            .field("20", &self.field_20)
+            .field("25", &self.field_25)
+            .field("28C", &self.field_28c)
+            .field("60", &self.field_60)
+            .optional_repeated("61", &self.field_61)
+            .field("62", &self.field_62)
+            .optional("64", &self.field_64);
+    }
+
+    fn field_specs() -> Vec<crate::traits::FieldSpec> {
+        use crate::traits::FieldSpec;
+
+        vec![
+            FieldSpec::new("20", "16x", "Transaction Reference Number"),
+            FieldSpec::new("25", "35x", "Account Identification"),
+            FieldSpec::new("28C", "5n[/5n]", "Statement Number/Sequence Number"),
+            FieldSpec::new("60a", "Option F or M", "Opening Balance"),
+            FieldSpec::new(
+                "61",
+                "6!n[4!n]2a[1!a]15d1!a3!c16x[//16x][34x]",
+                "Statement Line",
+            )
+            .optional()
+            .repetitive(),
+            FieldSpec::new("62a", "Option F or M", "Closing Balance (Booked Funds)"),
+            FieldSpec::new(
+                "64",
+                "1!a6!n3!a15d",
+                "Closing Available Balance (Available Funds)",
+            )
+            .optional(),
+        ]
+    }
+
     fn to_mt_string(&self) -> String {
         let mut result = String::new();
 
@@ -246,3 +338,28 @@ impl crate::traits::SwiftMessageBody for MT950 {
         MT950::validate_network_rules(self, stop_on_first_error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mt950_reconcile_balances() {
+        let statement = ":20:STMT950
+:25:123456789
+:28C:7/1
+:60M:D250101USD100,00
+:61:250101C350,25NTRFREF1
+:61:250101RC50,25NTRFREF2
+:62M:C250101USD200,00
+-";
+        let mt950 = MT950::parse_from_block4(statement).unwrap();
+        assert!(mt950.reconcile_balances().is_valid);
+
+        let off_balance = statement.replace(":62M:C250101USD200,00", ":62M:C250101USD250,25");
+        let mt950 = MT950::parse_from_block4(&off_balance).unwrap();
+        let result = mt950.reconcile_balances();
+        assert!(!result.is_valid);
+        assert!(result.errors[0].to_string().contains("C200,00"));
+    }
+}
@@ -2,9 +2,39 @@
 //!
 //! Enum for automatic message type detection and parsing. Used by `SwiftParser::parse_auto()`.
 
-use crate::{ValidationResult, messages::*, swift_message::SwiftMessage};
+use crate::headers::{MessageHeaders, Trailer};
+use crate::{
+    SwiftMessageBody, SwiftValidationError, ValidationResult, messages::*,
+    swift_message::SwiftMessage,
+};
 use serde::{Deserialize, Serialize};
 
+/// Option A field tags whose value ends with a BIC line
+const BIC_FIELD_TAGS: &[&str] = &[
+    "50A", "51A", "52A", "53A", "54A", "55A", "56A", "57A", "58A", "59A",
+];
+
+/// A BIC found in a message header or field, with its validation status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BicRef {
+    /// Field tag (e.g. "57A"), or "sender" / "receiver" for the header BICs
+    pub tag: String,
+    /// BIC as it appears in the message
+    pub bic: String,
+    /// Whether the BIC passes format validation
+    pub valid: bool,
+}
+
+impl BicRef {
+    fn new(tag: &str, bic: &str) -> Self {
+        BicRef {
+            tag: tag.to_string(),
+            bic: bic.to_string(),
+            valid: crate::fields::swift_utils::parse_bic(bic).is_ok(),
+        }
+    }
+}
+
 /// Enum of all supported SWIFT message types (30+ types)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
@@ -70,11 +100,45 @@ pub enum ParsedSwiftMessage {
     MT199(Box<SwiftMessage<MT199>>),
     #[serde(rename = "299")]
     MT299(Box<SwiftMessage<MT299>>),
+    /// Unsupported message type kept as raw fields (see `ParserConfig::allow_generic`)
+    #[serde(rename = "generic")]
+    Generic {
+        /// Message type from block 2 (e.g., "300")
+        message_type: String,
+        /// Blocks 1-3
+        headers: Box<MessageHeaders>,
+        /// Block 5, if present
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trailer: Option<Box<Trailer>>,
+        /// Block 4 fields in message order as (tag with option letter, value)
+        fields: Vec<(String, String)>,
+    },
 }
 
 impl ParsedSwiftMessage {
+    /// Deserialize a message from JSON in either the flattened (`"50K"`) or the tagged
+    /// (`{"50": {"K": ...}}`) layout
+    ///
+    /// The layout is detected per field, so JSON from tools that nest option variants can be
+    /// read directly. A missing `mt_type` is taken from `message_type`.
+    pub fn from_json_tagged(value: &serde_json::Value) -> crate::Result<Self> {
+        let mut value = value.clone();
+        if let Some(fields) = value.get_mut("fields") {
+            crate::swift_message::untag_option_fields(fields);
+        }
+        if let Some(object) = value.as_object_mut()
+            && !object.contains_key("mt_type")
+            && let Some(message_type) = object.get("message_type").cloned()
+        {
+            object.insert("mt_type".to_string(), message_type);
+        }
+        serde_json::from_value(value).map_err(|e| crate::ParseError::SerializationError {
+            message: format!("Cannot deserialize message JSON: {}", e),
+        })
+    }
+
     /// Get the message type as a string
-    pub fn message_type(&self) -> &'static str {
+    pub fn message_type(&self) -> &str {
         match self {
             ParsedSwiftMessage::MT101(_) => "101",
             ParsedSwiftMessage::MT103(_) => "103",
@@ -106,6 +170,126 @@ impl ParsedSwiftMessage {
             ParsedSwiftMessage::MT296(_) => "296",
             ParsedSwiftMessage::MT199(_) => "199",
             ParsedSwiftMessage::MT299(_) => "299",
+            ParsedSwiftMessage::Generic { message_type, .. } => message_type,
+        }
+    }
+
+    /// Message type as a typed [`MessageType`]
+    pub fn kind(&self) -> MessageType {
+        self.message_type()
+            .parse()
+            .unwrap_or_else(|_| MessageType::Other(self.message_type().to_string()))
+    }
+
+    /// SWIFT message category, the first digit of the message type
+    ///
+    /// 1 = customer payments, 2 = financial institution transfers, 9 = cash management.
+    /// `None` when a generic message type does not start with a digit.
+    pub fn category(&self) -> Option<u8> {
+        self.kind().category()
+    }
+
+    /// Message function as named in the SWIFT standard (e.g., "Single Customer Credit Transfer")
+    pub fn message_function(&self) -> &'static str {
+        self.kind()
+            .message_function()
+            .unwrap_or("Unsupported Message Type")
+    }
+
+    /// Run the network validation rules of the contained message body
+    pub fn validate_network_rules(&self, stop_on_first_error: bool) -> Vec<SwiftValidationError> {
+        match self {
+            ParsedSwiftMessage::MT101(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT103(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT104(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT107(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT110(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT111(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT112(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT190(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT191(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT200(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT202(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT204(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT205(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT210(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT290(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT291(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT900(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT910(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT920(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT935(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT940(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT941(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT942(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT950(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT192(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT196(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT292(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT296(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT199(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::MT299(msg) => {
+                msg.fields.validate_network_rules(stop_on_first_error)
+            }
+            ParsedSwiftMessage::Generic { .. } => Vec::new(),
         }
     }
 
@@ -506,6 +690,828 @@ impl ParsedSwiftMessage {
             ParsedSwiftMessage::MT941(mt941) => mt941.validate(),
             ParsedSwiftMessage::MT942(mt942) => mt942.validate(),
             ParsedSwiftMessage::MT950(mt950) => mt950.validate(),
+            ParsedSwiftMessage::Generic { .. } => ValidationResult::valid(),
+        }
+    }
+
+    /// Blocks 1-3 of the message
+    pub fn headers(&self) -> MessageHeaders {
+        match self {
+            ParsedSwiftMessage::MT101(msg) => msg.headers(),
+            ParsedSwiftMessage::MT103(msg) => msg.headers(),
+            ParsedSwiftMessage::MT104(msg) => msg.headers(),
+            ParsedSwiftMessage::MT107(msg) => msg.headers(),
+            ParsedSwiftMessage::MT110(msg) => msg.headers(),
+            ParsedSwiftMessage::MT111(msg) => msg.headers(),
+            ParsedSwiftMessage::MT112(msg) => msg.headers(),
+            ParsedSwiftMessage::MT190(msg) => msg.headers(),
+            ParsedSwiftMessage::MT191(msg) => msg.headers(),
+            ParsedSwiftMessage::MT200(msg) => msg.headers(),
+            ParsedSwiftMessage::MT202(msg) => msg.headers(),
+            ParsedSwiftMessage::MT204(msg) => msg.headers(),
+            ParsedSwiftMessage::MT205(msg) => msg.headers(),
+            ParsedSwiftMessage::MT210(msg) => msg.headers(),
+            ParsedSwiftMessage::MT290(msg) => msg.headers(),
+            ParsedSwiftMessage::MT291(msg) => msg.headers(),
+            ParsedSwiftMessage::MT900(msg) => msg.headers(),
+            ParsedSwiftMessage::MT910(msg) => msg.headers(),
+            ParsedSwiftMessage::MT920(msg) => msg.headers(),
+            ParsedSwiftMessage::MT935(msg) => msg.headers(),
+            ParsedSwiftMessage::MT940(msg) => msg.headers(),
+            ParsedSwiftMessage::MT941(msg) => msg.headers(),
+            ParsedSwiftMessage::MT942(msg) => msg.headers(),
+            ParsedSwiftMessage::MT950(msg) => msg.headers(),
+            ParsedSwiftMessage::MT192(msg) => msg.headers(),
+            ParsedSwiftMessage::MT196(msg) => msg.headers(),
+            ParsedSwiftMessage::MT292(msg) => msg.headers(),
+            ParsedSwiftMessage::MT296(msg) => msg.headers(),
+            ParsedSwiftMessage::MT199(msg) => msg.headers(),
+            ParsedSwiftMessage::MT299(msg) => msg.headers(),
+            ParsedSwiftMessage::Generic { headers, .. } => (**headers).clone(),
+        }
+    }
+
+    /// Block 4 fields in message order as (tag with option letter, value)
+    pub fn fields_with_variants(&self) -> Vec<(String, String)> {
+        match self {
+            ParsedSwiftMessage::MT101(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT103(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT104(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT107(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT110(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT111(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT112(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT190(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT191(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT200(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT202(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT204(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT205(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT210(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT290(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT291(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT900(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT910(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT920(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT935(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT940(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT941(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT942(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT950(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT192(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT196(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT292(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT296(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT199(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::MT299(msg) => msg.fields.to_fields_with_variants(),
+            ParsedSwiftMessage::Generic { fields, .. } => fields.clone(),
+        }
+    }
+
+    /// Every BIC in the message: sender and receiver, then option A party fields in order
+    ///
+    /// Covers fields 50A to 59A, including those inside repeated sequences.
+    pub fn all_bics(&self) -> Vec<BicRef> {
+        let headers = self.headers();
+        let mut bics = vec![
+            BicRef::new("sender", headers.sender_bic()),
+            BicRef::new("receiver", headers.receiver_bic()),
+        ];
+
+        for (tag, value) in self.fields_with_variants() {
+            if BIC_FIELD_TAGS.contains(&tag.as_str())
+                && let Some(bic) = value.lines().map(str::trim).rfind(|line| !line.is_empty())
+            {
+                bics.push(BicRef::new(&tag, bic));
+            }
+        }
+
+        bics
+    }
+
+    /// Every date in block 4 as `(tag with option letter, date)`, in message order
+    ///
+    /// Dates come from [`SwiftField::dates`](crate::SwiftField::dates) of the date-bearing
+    /// fields (11a, 13D, 30a, 32a, 60a-65 and the 61 value and entry dates), including
+    /// those inside repeated sequences. Field 13C carries a time only and is not listed.
+    pub fn all_dates(&self) -> Vec<(String, chrono::NaiveDate)> {
+        self.fields_with_variants()
+            .into_iter()
+            .flat_map(|(tag, value)| {
+                field_dates(&tag, &value)
+                    .into_iter()
+                    .map(move |date| (tag.clone(), date))
+            })
+            .collect()
+    }
+
+    /// Service 21 positive acknowledgment (ACK) for this message, dated now (UTC)
+    ///
+    /// See [`build_ack_at`](Self::build_ack_at).
+    pub fn build_ack(&self) -> String {
+        self.build_ack_at(chrono::Utc::now().naive_utc())
+    }
+
+    /// Service 21 positive acknowledgment (ACK) for this message, dated `at`
+    ///
+    /// Block 1 repeats the logical terminal, session and sequence number of the original,
+    /// which together with the date in tag 177 identify it (its MIR). Block 4 carries
+    /// `{451:0}` for acceptance.
+    pub fn build_ack_at(&self, at: chrono::NaiveDateTime) -> String {
+        self.build_service_21(at, "{451:0}".to_string())
+    }
+
+    /// Service 21 negative acknowledgment (NAK) carrying `error_code` in tag 405, dated now (UTC)
+    pub fn build_nak(&self, error_code: &str) -> String {
+        self.build_nak_at(error_code, chrono::Utc::now().naive_utc())
+    }
+
+    /// Service 21 negative acknowledgment (NAK) carrying `error_code` in tag 405, dated `at`
+    pub fn build_nak_at(&self, error_code: &str, at: chrono::NaiveDateTime) -> String {
+        self.build_service_21(at, format!("{{451:1}}{{405:{error_code}}}"))
+    }
+
+    fn build_service_21(&self, at: chrono::NaiveDateTime, status: String) -> String {
+        let basic = self.headers().basic;
+        format!(
+            "{{1:{}21{}{}{}}}{{4:{{177:{}}}{}}}",
+            basic.application_id,
+            basic.logical_terminal,
+            basic.session_number,
+            basic.sequence_number,
+            at.format("%y%m%d%H%M"),
+            status
+        )
+    }
+
+    /// Check that two messages carry the same content, ignoring volatile header data
+    ///
+    /// See [`semantic_diff`](Self::semantic_diff) for what is compared.
+    pub fn semantically_equal(&self, other: &Self) -> bool {
+        self.semantic_diff(other).is_empty()
+    }
+
+    /// List the content differences between two messages, one line per difference
+    ///
+    /// Compares message type, sender and receiver BIC, UETR and every block 4 field in order.
+    /// Session and sequence numbers, output date/time, MIR and the trailer (CHK, MAC) are ignored.
+    pub fn semantic_diff(&self, other: &Self) -> Vec<String> {
+        let mut diff = Vec::new();
+        let (left, right) = (self.headers(), other.headers());
+
+        let mut compare = |what: &str, left: Option<&str>, right: Option<&str>| {
+            if left != right {
+                diff.push(format!(
+                    "{what}: {} != {}",
+                    left.unwrap_or("<none>"),
+                    right.unwrap_or("<none>")
+                ));
+            }
+        };
+        compare(
+            "message type",
+            Some(self.message_type()),
+            Some(other.message_type()),
+        );
+        compare(
+            "sender BIC",
+            Some(left.sender_bic()),
+            Some(right.sender_bic()),
+        );
+        compare(
+            "receiver BIC",
+            Some(left.receiver_bic()),
+            Some(right.receiver_bic()),
+        );
+        compare("UETR", left.uetr(), right.uetr());
+
+        let (left_fields, right_fields) =
+            (self.fields_with_variants(), other.fields_with_variants());
+        for index in 0..left_fields.len().max(right_fields.len()) {
+            match (left_fields.get(index), right_fields.get(index)) {
+                (Some(left), Some(right)) if left == right => {}
+                (Some((tag, value)), Some((other_tag, other_value))) => diff.push(format!(
+                    "field #{}: :{tag}:{value} != :{other_tag}:{other_value}",
+                    index + 1
+                )),
+                (Some((tag, value)), None) => {
+                    diff.push(format!("field #{}: :{tag}:{value} != <none>", index + 1))
+                }
+                (None, Some((tag, value))) => {
+                    diff.push(format!("field #{}: <none> != :{tag}:{value}", index + 1))
+                }
+                (None, None) => {}
+            }
+        }
+
+        diff
+    }
+
+    /// Serialize back to FIN format
+    pub fn to_mt_message(&self) -> String {
+        match self {
+            ParsedSwiftMessage::MT101(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT103(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT104(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT107(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT110(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT111(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT112(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT190(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT191(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT200(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT202(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT204(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT205(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT210(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT290(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT291(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT900(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT910(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT920(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT935(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT940(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT941(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT942(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT950(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT192(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT196(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT292(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT296(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT199(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::MT299(msg) => msg.to_mt_message(),
+            ParsedSwiftMessage::Generic {
+                headers,
+                trailer,
+                fields,
+                ..
+            } => {
+                let mut result =
+                    format!("{{1:{}}}\n{{2:{}}}\n", headers.basic, headers.application);
+                if let Some(user) = &headers.user {
+                    result.push_str(&format!("{{3:{user}}}\n"));
+                }
+                result.push_str("{4:\n");
+                for (tag, value) in fields {
+                    result.push_str(&format!(":{tag}:{value}\n"));
+                }
+                result.push_str("-}\n");
+                if let Some(trailer) = trailer {
+                    result.push_str(&format!("{{5:{trailer}}}\n"));
+                }
+                result
+            }
+        }
+    }
+
+    /// Copy of the message with party data replaced, for sharing as a test fixture
+    ///
+    /// BICs get same-country fakes, names and addresses placeholder text, accounts synthetic
+    /// numbers (IBANs keep a valid check digit) and references (20, 21, block 3 tags 108,
+    /// 121 and 424) values derived from a hash. Replacements are deterministic, so a BIC that
+    /// appears in the header and in a field still matches. Codes, amounts, dates and code words
+    /// are kept so the message reproduces the original behaviour. Block 5 is dropped.
+    ///
+    /// Fails with the parse error when the anonymized text no longer parses as the original
+    /// message type.
+    pub fn anonymize(&self) -> crate::Result<ParsedSwiftMessage> {
+        let headers = self.headers();
+        let mut bics: Vec<(String, String)> = Vec::new();
+        for bic in [headers.sender_bic(), headers.receiver_bic()] {
+            let bic = bic.get(..8).unwrap_or(bic);
+            if bic.len() == 8 && !bics.iter().any(|(original, _)| original == bic) {
+                bics.push((bic.to_string(), Anonymizer::bic(bic)));
+            }
+        }
+        let anonymize_bics = |mut block: String| {
+            for (original, fake) in &bics {
+                block = block.replace(original.as_str(), fake);
+            }
+            block
+        };
+
+        let mut raw = format!(
+            "{{1:{}}}{{2:{}}}",
+            anonymize_bics(headers.basic.to_string()),
+            anonymize_bics(headers.application.to_string())
+        );
+        if let Some(mut user) = headers.user {
+            user.message_user_reference = user
+                .message_user_reference
+                .map(|r| Anonymizer::reference(&r));
+            user.related_reference = user.related_reference.map(|r| Anonymizer::reference(&r));
+            user.unique_end_to_end_reference = user
+                .unique_end_to_end_reference
+                .map(|r| Anonymizer::uetr(&r));
+            raw.push_str(&format!("{{3:{user}}}"));
+        }
+        raw.push_str("{4:\n");
+        for (tag, value) in self.fields_with_variants() {
+            raw.push_str(&format!(":{tag}:{}\n", Anonymizer::field(&tag, &value)));
+        }
+        raw.push_str("-}");
+
+        crate::SwiftParser::with_config(crate::ParserConfig {
+            allow_generic: matches!(self, ParsedSwiftMessage::Generic { .. }),
+            ..Default::default()
+        })
+        .parse_message_auto(&raw)
+    }
+}
+
+/// Deterministic replacements used by [`ParsedSwiftMessage::anonymize`]
+struct Anonymizer;
+
+impl Anonymizer {
+    /// FNV-1a, stable across runs and Rust versions
+    fn hash(value: &str) -> u64 {
+        value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    }
+
+    /// Replace each letter with a letter and each digit with a digit, keeping everything else
+    fn scramble(value: &str) -> String {
+        let mut state = Self::hash(value);
+        value
+            .chars()
+            .map(|c| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                let pick = (state >> 33) as u8;
+                match c {
+                    '0'..='9' => char::from(b'0' + pick % 10),
+                    'A'..='Z' => char::from(b'A' + pick % 26),
+                    'a'..='z' => char::from(b'a' + pick % 26),
+                    _ => c,
+                }
+            })
+            .collect()
+    }
+
+    /// Fake BIC with the original country code and branch "XXX" kept as is
+    fn bic(bic: &str) -> String {
+        let head = Self::scramble(&bic[..8]);
+        let branch = match bic.get(8..) {
+            Some("XXX") | None => bic.get(8..).unwrap_or_default().to_string(),
+            Some(branch) => Self::scramble(branch),
+        };
+        format!("{}{}{}{branch}", &head[..4], &bic[4..6], &head[6..8])
+    }
+
+    /// Hash-based reference of the same length; NONREF stays as is
+    fn reference(reference: &str) -> String {
+        if reference == "NONREF" {
+            return reference.to_string();
+        }
+        let mut hashed = format!("{:016X}", Self::hash(reference));
+        hashed.truncate(reference.len().clamp(1, 16));
+        hashed
+    }
+
+    /// UUID v4 derived from the original UETR
+    fn uetr(uetr: &str) -> String {
+        let high = Self::hash(uetr);
+        let low = Self::hash(&format!("{uetr}#"));
+        format!(
+            "{:08x}-{:04x}-4{:03x}-{:x}{:03x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xffff,
+            high & 0xfff,
+            8 + (low >> 62),
+            (low >> 48) & 0xfff,
+            low & 0xffff_ffff_ffff
+        )
+    }
+
+    /// Synthetic account keeping an optional `/C/` or `/D/` mark
+    fn account(line: &str) -> String {
+        let (mark, account) = match line.get(..3) {
+            Some("/C/" | "/D/") => line.split_at(3),
+            _ => line.split_at(1),
+        };
+        format!("{mark}{}", Self::account_number(account))
+    }
+
+    /// Synthetic account number; IBANs get fresh check digits
+    fn account_number(account: &str) -> String {
+        let is_iban = account.len() >= 15
+            && account.chars().all(|c| c.is_ascii_alphanumeric())
+            && account[..2].chars().all(|c| c.is_ascii_uppercase())
+            && account[2..4].chars().all(|c| c.is_ascii_digit());
+        if !is_iban {
+            return Self::scramble(account);
+        }
+
+        let (country, body) = (&account[..2], Self::scramble(&account[4..]));
+        let remainder = format!("{body}{country}00").chars().fold(0u32, |acc, c| {
+            let value = c.to_digit(36).unwrap_or_default();
+            let acc = if value >= 10 { acc * 100 } else { acc * 10 };
+            (acc + value) % 97
+        });
+        format!("{country}{:02}{body}", 98 - remainder)
+    }
+
+    /// Anonymized value of a block 4 field; fields without party data are returned unchanged
+    fn field(tag: &str, value: &str) -> String {
+        let base = tag.get(..2).unwrap_or(tag);
+        if tag == "20" || base == "21" {
+            return Self::reference(value);
+        }
+        match base {
+            "25" => Self::account_identification(value),
+            "50" | "51" | "52" | "53" | "54" | "55" | "56" | "57" | "58" | "59" => {
+                Self::party(value)
+            }
+            "70" | "72" | "77" | "79" | "86" => Self::narrative(value),
+            _ if tag == "23E" => Self::instruction(value),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Field 25a account, and the BIC line of option P
+    fn account_identification(value: &str) -> String {
+        value
+            .lines()
+            .map(|line| {
+                if line.len() == 11 && crate::fields::swift_utils::parse_bic(line).is_ok() {
+                    Self::bic(line)
+                } else {
+                    Self::account_number(line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Field 23E with the phone number or telecom details of phone and telecom codes scrambled
+    fn instruction(value: &str) -> String {
+        match value.split_once('/') {
+            Some((code @ ("PHOB" | "PHOI" | "PHON" | "TELB" | "TELE" | "TELI"), details)) => {
+                format!("{code}/{}", Self::scramble(details))
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    /// Party field lines: accounts, BICs, numbered (option F) and free name/address lines
+    fn party(value: &str) -> String {
+        let mut names = 0;
+        value
+            .lines()
+            .map(|line| {
+                if line.starts_with('/') {
+                    return Self::account(line);
+                }
+                if (line.len() == 8 || line.len() == 11)
+                    && crate::fields::swift_utils::parse_bic(line).is_ok()
+                {
+                    return Self::bic(line);
+                }
+                let bytes = line.as_bytes();
+                if bytes.len() >= 2 && bytes[0].is_ascii_digit() && bytes[1] == b'/' {
+                    let (number, rest) = line.split_at(2);
+                    let country = rest
+                        .get(..3)
+                        .filter(|c| {
+                            c.ends_with('/') && c[..2].chars().all(|c| c.is_ascii_uppercase())
+                        })
+                        .unwrap_or_default();
+                    return match number {
+                        "1/" => "1/ANONYMIZED NAME".to_string(),
+                        "2/" => "2/ANONYMIZED ADDRESS".to_string(),
+                        "3/" => format!("3/{country}ANONYMIZED TOWN"),
+                        "4/" => "4/19700101".to_string(),
+                        _ => format!(
+                            "{number}{country}{}",
+                            Self::scramble(&rest[country.len()..])
+                        ),
+                    };
+                }
+                names += 1;
+                if names == 1 {
+                    "ANONYMIZED NAME".to_string()
+                } else {
+                    "ANONYMIZED ADDRESS".to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Narrative lines with leading `/CODE/` code words kept
+    fn narrative(value: &str) -> String {
+        value
+            .lines()
+            .map(|line| {
+                let code_end = line
+                    .strip_prefix('/')
+                    .and_then(|rest| rest.find('/'))
+                    .map_or(0, |end| end + 2);
+                let (code, text) = line.split_at(code_end);
+                format!("{code}{}", Self::scramble(text))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Dates of one block 4 field, parsed with the field type its tag denotes
+fn field_dates(tag: &str, value: &str) -> Vec<chrono::NaiveDate> {
+    use crate::fields::*;
+
+    fn dates<F: crate::SwiftField>(value: &str) -> Vec<chrono::NaiveDate> {
+        F::parse(value)
+            .map(|field| field.dates())
+            .unwrap_or_default()
+    }
+
+    match tag {
+        "11R" => dates::<Field11R>(value),
+        "11S" => dates::<Field11S>(value),
+        "13D" => dates::<Field13D>(value),
+        "30" => dates::<Field30>(value),
+        "30T" => dates::<Field30T>(value),
+        "30V" => dates::<Field30V>(value),
+        "30P" => dates::<Field30P>(value),
+        "32A" => dates::<Field32A>(value),
+        "32C" => dates::<Field32C>(value),
+        "32D" => dates::<Field32D>(value),
+        "60F" => dates::<Field60F>(value),
+        "60M" => dates::<Field60M>(value),
+        "61" => dates::<Field61>(value),
+        "62F" => dates::<Field62F>(value),
+        "62M" => dates::<Field62M>(value),
+        "64" => dates::<Field64>(value),
+        "65" => dates::<Field65>(value),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParsedSwiftMessage;
+    use crate::{ParserConfig, SwiftParser};
+
+    #[test]
+    fn test_all_dates() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:13C:/SNDTIME/1249+0100\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let message = SwiftParser::parse_auto(raw).unwrap();
+        assert_eq!(
+            message.all_dates(),
+            vec![(
+                "32A".to_string(),
+                chrono::NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKGB2LXXXXN}{3:{108:MYREF001}{121:eb6305c9-1f7f-49de-aed0-16487c27b42d}}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315EUR1000,00\n:50K:/55501234\nJOHN DOE\nMAIN STREET 1\n:52A:BANKDEFFXXX\n:59:/DE89370400440532013000\nJANE ROE\n:70:/INV/4711 RENT MARCH\n:71A:SHA\n-}";
+        let original = SwiftParser::parse_auto(raw).unwrap();
+        let anonymized = original.anonymize().unwrap();
+        let output = anonymized.to_mt_message();
+
+        for secret in [
+            "BANKDEFF",
+            "BANKGB2L",
+            "MYREF001",
+            "eb6305c9",
+            "TXN123456",
+            "55501234",
+            "JOHN DOE",
+            "MAIN STREET",
+            "370400440532013000",
+            "JANE ROE",
+            "4711",
+        ] {
+            assert!(!output.contains(secret), "{secret} leaked into {output}");
+        }
+
+        // Structure, amounts and code words survive and the result parses as an MT103
+        let reparsed = SwiftParser::parse_auto(&output).unwrap();
+        let mt103 = reparsed.as_mt103().unwrap();
+        assert_eq!(mt103.fields.field_32a.amount, 1000.0);
+        assert!(output.contains(":70:/INV/"));
+        assert_eq!(anonymized.headers().sender_bic()[4..6], *"DE");
+        assert!(crate::headers::UserHeader::is_valid_uetr(
+            anonymized.headers().uetr().unwrap()
+        ));
+        let (account, _) = mt103.fields.field_59.account_and_bic();
+        assert!(account.unwrap().starts_with("DE"));
+        assert_eq!(
+            anonymized.to_mt_message(),
+            original.anonymize().unwrap().to_mt_message()
+        );
+
+        // Phone numbers in 23E, statement accounts and field 86 narratives
+        let raw = raw.replace(":23B:CRED\n", ":23B:CRED\n:23E:PHOB/4420794600\n");
+        let output = SwiftParser::parse_auto(&raw)
+            .unwrap()
+            .anonymize()
+            .unwrap()
+            .to_mt_message();
+        assert!(output.contains(":23E:PHOB/"));
+        assert!(!output.contains("4420794600"));
+
+        let mt940 = SwiftParser::parse_auto("{1:F01BANKDEFFAXXX0123456789}{2:I940BANKDEFFAXXXN}{4:\n:20:STMT001\n:25:DE89370400440532013000\n:28C:1/1\n:60F:C240315EUR1000,00\n:61:2403150315C100,00NTRFNONREF\n:86:/EREF/JOHN DOE SALARY\n:62F:C240315EUR1100,00\n-}").unwrap();
+        let output = mt940.anonymize().unwrap().to_mt_message();
+        for secret in ["370400440532013000", "JOHN DOE"] {
+            assert!(!output.contains(secret), "{secret} leaked into {output}");
+        }
+        assert!(output.contains(":86:/EREF/"));
+    }
+
+    #[test]
+    fn test_from_json_tagged() {
+        use crate::fields::Field50OrderingCustomerAFK;
+
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:/12345678\nJOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let message = SwiftParser::parse::<crate::messages::MT103>(raw).unwrap();
+
+        let tagged = message.to_json_tagged().unwrap();
+        assert!(tagged["fields"]["50"]["K"].is_object());
+        let parsed = ParsedSwiftMessage::from_json_tagged(&tagged).unwrap();
+        let mt103 = parsed.as_mt103().unwrap();
+        assert!(matches!(
+            mt103.fields.field_50,
+            Field50OrderingCustomerAFK::K(ref field) if field.account.as_deref() == Some("12345678")
+        ));
+        assert_eq!(mt103.to_mt_message(), message.to_mt_message());
+
+        // The crate's own flattened layout is accepted as well
+        let flat = serde_json::to_value(&message).unwrap();
+        let parsed = ParsedSwiftMessage::from_json_tagged(&flat).unwrap();
+        assert_eq!(parsed.as_mt103().unwrap().fields, message.fields);
+    }
+
+    #[test]
+    fn test_generic_fallback_round_trip() {
+        let mt300 = "{1:F01BANKDEFFAXXX0123456789}\n{2:I300BANKGB2LXXXXN}\n{3:{108:FXDEAL1}}\n{4:\n:15A:\n:20:FXREF001\n:22A:NEWT\n:22C:BANKDE2240BANKGB\n:82A:BANKDEFFXXX\n:87A:BANKGB2LXXX\n:15B:\n:30T:20240315\n:30V:20240319\n:36:1,0875\n:32B:EUR1000000,00\n:57A:BANKDEFFXXX\n:33B:USD1087500,00\n:57A:BANKUS33XXX\n-}\n";
+
+        assert!(matches!(
+            SwiftParser::parse_auto(mt300),
+            Err(crate::ParseError::UnsupportedMessageType { .. })
+        ));
+
+        let parser = SwiftParser::with_config(ParserConfig {
+            allow_generic: true,
+            ..Default::default()
+        });
+        let parsed = parser.parse_message_auto(mt300).unwrap();
+        assert_eq!(parsed.message_type(), "300");
+        assert_eq!(parsed.category(), Some(3));
+        match &parsed {
+            ParsedSwiftMessage::Generic {
+                headers, fields, ..
+            } => {
+                assert_eq!(headers.receiver_bic(), "BANKGB2L");
+                assert_eq!(fields.len(), 14);
+                assert_eq!(fields[1], ("20".to_string(), "FXREF001".to_string()));
+                assert_eq!(fields[13], ("57A".to_string(), "BANKUS33XXX".to_string()));
+            }
+            other => panic!("expected generic message, got {other:?}"),
+        }
+
+        let dates = parsed.all_dates();
+        assert!(dates.contains(&(
+            "30V".to_string(),
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 19).unwrap()
+        )));
+
+        assert_eq!(parsed.to_mt_message(), mt300);
+        let reparsed = parser.parse_message_auto(&parsed.to_mt_message()).unwrap();
+        assert_eq!(reparsed.to_mt_message(), mt300);
+    }
+
+    #[test]
+    fn test_envelope_field_77e_message_round_trip() {
+        let mt798 = "{1:F01BANKDEFFAXXX0123456789}\n{2:I798BANKGB2LXXXXN}\n{4:\n:20:REF798\n:12:700\n:77E:\n:27A:1/2\n:21A:LC2024001\n:40A:IRREVOCABLE\n-}\n";
+        let parser = SwiftParser::with_config(ParserConfig {
+            allow_generic: true,
+            ..Default::default()
+        });
+
+        let parsed = parser.parse_message_auto(mt798).unwrap();
+        match &parsed {
+            ParsedSwiftMessage::Generic { fields, .. } => {
+                assert_eq!(fields.len(), 3);
+                assert_eq!(
+                    fields[2],
+                    (
+                        "77E".to_string(),
+                        "\n:27A:1/2\n:21A:LC2024001\n:40A:IRREVOCABLE".to_string()
+                    )
+                );
+                let envelope =
+                    <crate::fields::Field77E as crate::SwiftField>::parse(&fields[2].1).unwrap();
+                assert!(envelope.envelope_content.contains(":21A:LC2024001"));
+            }
+            other => panic!("expected generic message, got {other:?}"),
+        }
+
+        assert_eq!(parsed.to_mt_message(), mt798);
+    }
+
+    #[test]
+    fn test_build_ack_and_nak() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let parsed = SwiftParser::parse_auto(message).unwrap();
+
+        let at = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(14, 5, 0)
+            .unwrap();
+
+        // Logical terminal BANKDEFFAXXX, session 0123 and sequence 456789 of the original
+        assert_eq!(
+            parsed.build_ack_at(at),
+            "{1:F21BANKDEFFAXXX0123456789}{4:{177:2403151405}{451:0}}"
+        );
+        assert_eq!(
+            parsed.build_nak_at("T27", at),
+            "{1:F21BANKDEFFAXXX0123456789}{4:{177:2403151405}{451:1}{405:T27}}"
+        );
+
+        let ack = parsed.build_ack();
+        assert!(ack.starts_with("{1:F21BANKDEFFAXXX0123456789}{4:{177:"));
+        assert!(ack.ends_with("}{451:0}}"));
+    }
+
+    #[test]
+    fn test_all_bics() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:57A:/ACC123\nAWIBGB2LXXX\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let parsed = SwiftParser::parse_auto(message).unwrap();
+
+        let bics = parsed.all_bics();
+        let inventory: Vec<(&str, &str, bool)> = bics
+            .iter()
+            .map(|b| (b.tag.as_str(), b.bic.as_str(), b.valid))
+            .collect();
+        assert_eq!(
+            inventory,
+            vec![
+                ("sender", "BANKDEFF", true),
+                ("receiver", "BANKUS33", true),
+                ("57A", "AWIBGB2LXXX", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_semantically_equal_ignores_session_and_sequence() {
+        let golden = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{3:{121:eb6305c9-1f7f-49de-aed0-16487c27b42d}}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}{5:{CHK:123456789ABC}}";
+        let regenerated = golden
+            .replace("0123456789", "9876543210")
+            .replace("123456789ABC", "CBA987654321");
+
+        let golden = SwiftParser::parse_auto(golden).unwrap();
+        let regenerated = SwiftParser::parse_auto(&regenerated).unwrap();
+        assert!(golden.semantically_equal(&regenerated));
+
+        let changed = SwiftParser::parse_auto(
+            &golden
+                .to_mt_message()
+                .replace("USD1000,00", "USD1000,01")
+                .replace("BANKUS33XXXX", "BANKGB22XXXX"),
+        )
+        .unwrap();
+        assert_eq!(
+            golden.semantic_diff(&changed),
+            vec![
+                "receiver BIC: BANKUS33 != BANKGB22",
+                "field #3: :32A:240315USD1000,00 != :32A:240315USD1000,01",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kind() {
+        let parsed = SwiftParser::parse_auto("{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}").unwrap();
+        assert_eq!(parsed.kind(), crate::MessageType::MT103);
+    }
+
+    #[test]
+    fn test_category_and_message_function() {
+        let mt103 = SwiftParser::parse_auto("{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}").unwrap();
+        assert_eq!(mt103.category(), Some(1));
+        assert_eq!(mt103.message_function(), "Single Customer Credit Transfer");
+
+        let mt940 = SwiftParser::parse_auto("{1:F01BANKDEFFAXXX0123456789}{2:I940BANKDEFFAXXXN}{4:\n:20:STMT001\n:25:12345678\n:28C:1/1\n:60F:C240315EUR1000,00\n:61:2403150315C100,00NTRFNONREF\n:62F:C240315EUR1100,00\n-}").unwrap();
+        assert_eq!(mt940.category(), Some(9));
+        assert_eq!(mt940.message_function(), "Customer Statement Message");
+
+        // Generic message types come from untrusted input
+        for message_type in ["", "X00"] {
+            let generic = ParsedSwiftMessage::Generic {
+                message_type: message_type.to_string(),
+                headers: Box::new(mt940.headers()),
+                trailer: None,
+                fields: Vec::new(),
+            };
+            assert_eq!(generic.category(), None);
         }
     }
 }
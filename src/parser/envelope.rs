@@ -0,0 +1,225 @@
+//! # Envelope
+//!
+//! Extraction of a FIN MT message received inside an XML envelope, as used by some
+//! gateways in mixed MT/MX environments (e.g. an MT carried under an ISO application
+//! header). Only the envelope metadata is read; the payload is handed to the MT parser.
+
+use crate::ParsedSwiftMessage;
+use crate::errors::{ParseError, Result};
+use crate::parser::SwiftParser;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Metadata of the XML envelope a FIN message was received in
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MtEnvelope {
+    /// Local name of the root element, without namespace prefix
+    pub root: String,
+    /// Namespace URI bound to the root element, if declared
+    pub namespace: Option<String>,
+    /// Text of the leaf elements outside the FIN payload, by local name
+    ///
+    /// The first occurrence of a name wins.
+    pub elements: BTreeMap<String, String>,
+}
+
+/// Split an XML envelope into its metadata and the embedded FIN message
+///
+/// Namespace prefixes are ignored when naming elements. The payload may be escaped
+/// text or a CDATA section. Returns `None` when `raw` is not XML or carries no `{1:` block.
+pub fn extract_envelope(raw: &str) -> Option<(MtEnvelope, String)> {
+    let raw = raw.trim_start_matches('\u{feff}').trim_start();
+    if !raw.starts_with('<') {
+        return None;
+    }
+
+    let mut envelope = MtEnvelope::default();
+    let mut payload = None;
+    let mut text = String::new();
+    let mut rest = raw;
+
+    while let Some(open) = rest.find('<') {
+        unescape_into(&rest[..open], &mut text);
+        rest = &rest[open..];
+
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>")?;
+            text.push_str(&cdata[..end]);
+            rest = &cdata[end + 3..];
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = &comment[comment.find("-->")? + 3..];
+            continue;
+        }
+
+        let close = rest.find('>')?;
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let value = text.trim();
+            if value.contains("{1:") {
+                payload.get_or_insert_with(|| value.to_string());
+            } else if !value.is_empty() {
+                envelope
+                    .elements
+                    .entry(local_name(name.trim()).to_string())
+                    .or_insert_with(|| value.to_string());
+            }
+        } else if envelope.root.is_empty() {
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            let name = name.trim_end_matches('/');
+            envelope.root = local_name(name).to_string();
+            let declaration = match name.split_once(':') {
+                Some((prefix, _)) => format!("xmlns:{prefix}"),
+                None => "xmlns".to_string(),
+            };
+            envelope.namespace = attribute(tag, &declaration);
+        }
+        text.clear();
+    }
+
+    let payload = payload?;
+    let start = payload.find("{1:")?;
+    Some((envelope, payload[start..].to_string()))
+}
+
+impl SwiftParser {
+    /// Parse a message that may arrive wrapped in an XML envelope
+    ///
+    /// Plain FIN input is parsed as-is and yields no envelope. An XML document without an
+    /// embedded FIN message is rejected.
+    pub fn parse_wrapped(&self, raw: &str) -> Result<(Option<MtEnvelope>, ParsedSwiftMessage)> {
+        match extract_envelope(raw) {
+            Some((envelope, fin)) => Ok((Some(envelope), self.parse_message_auto(&fin)?)),
+            None if raw.trim_start().starts_with('<') => Err(ParseError::InvalidFormat {
+                message: "XML envelope does not contain a FIN message ({1: block)".to_string(),
+            }),
+            None => Ok((None, self.parse_message_auto(raw)?)),
+        }
+    }
+}
+
+/// Element name without its namespace prefix
+fn local_name(name: &str) -> &str {
+    name.rsplit_once(':').map_or(name, |(_, local)| local)
+}
+
+/// Value of attribute `name` in a start tag, quoted with `"` or `'`
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut search = tag;
+    while let Some(index) = search.find(name) {
+        let preceded_by_space = search[..index].ends_with(char::is_whitespace);
+        search = &search[index + name.len()..];
+        let value = search.trim_start().strip_prefix('=').map(str::trim_start);
+        let Some(value) = value.filter(|_| preceded_by_space) else {
+            continue;
+        };
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value = &value[1..];
+        return Some(value[..value.find(quote)?].to_string());
+    }
+    None
+}
+
+/// Append XML character data to `out`, resolving predefined and numeric entities
+fn unescape_into(data: &str, out: &mut String) {
+    let mut rest = data;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let Some(semi) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..semi];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MT103: &str = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+
+    #[test]
+    fn test_parse_wrapped_mt103() {
+        let escaped = MT103.replace('\n', "&#13;&#10;").replace("-}", "-&#125;");
+        let xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <saa:DataPDU xmlns:saa=\"urn:swift:saa:xsd:saa.2.0\">\n\
+             <saa:Header><saa:Message><saa:MessageIdentifier>fin.103</saa:MessageIdentifier>\n\
+             <saa:Sender><saa:BIC12>BANKDEFFAXXX</saa:BIC12></saa:Sender></saa:Message></saa:Header>\n\
+             <!-- payload follows -->\n\
+             <saa:Body>{escaped}</saa:Body>\n\
+             </saa:DataPDU>"
+        );
+
+        let (envelope, message) = SwiftParser::new().parse_wrapped(&xml).unwrap();
+        let envelope = envelope.unwrap();
+        assert_eq!(envelope.root, "DataPDU");
+        assert_eq!(
+            envelope.namespace.as_deref(),
+            Some("urn:swift:saa:xsd:saa.2.0")
+        );
+        assert_eq!(envelope.elements["MessageIdentifier"], "fin.103");
+        assert_eq!(envelope.elements["BIC12"], "BANKDEFFAXXX");
+        assert!(!envelope.elements.contains_key("Body"));
+
+        let ParsedSwiftMessage::MT103(mt103) = message else {
+            panic!("expected MT103");
+        };
+        assert_eq!(mt103.fields.field_20.reference, "TXN123456");
+    }
+
+    #[test]
+    fn test_extract_envelope_cdata_and_plain_input() {
+        let xml =
+            format!("<Envelope xmlns=\"urn:example\"><Fin><![CDATA[{MT103}]]></Fin></Envelope>");
+        let (envelope, fin) = extract_envelope(&xml).unwrap();
+        assert_eq!(envelope.root, "Envelope");
+        assert_eq!(envelope.namespace.as_deref(), Some("urn:example"));
+        assert_eq!(fin, MT103);
+
+        assert!(extract_envelope(MT103).is_none());
+        let (envelope, _) = SwiftParser::new().parse_wrapped(MT103).unwrap();
+        assert!(envelope.is_none());
+
+        assert!(
+            SwiftParser::new()
+                .parse_wrapped("<Envelope><Fin>none</Fin></Envelope>")
+                .is_err()
+        );
+    }
+}
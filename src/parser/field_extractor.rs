@@ -8,8 +8,13 @@
 pub fn extract_field_content(input: &str, tag: &str) -> Option<(String, usize)> {
     let field_marker = format!(":{}:", tag);
 
-    // Find the field marker
-    let field_start = input.find(&field_marker)?;
+    // Find the field marker at the start of the input or of a line, so a narrative line
+    // that merely contains the marker text is not taken for the field
+    let field_start = if input.starts_with(&field_marker) {
+        0
+    } else {
+        input.find(&format!("\n{}", field_marker))? + 1
+    };
 
     // Start of content is after the field marker
     let content_start = field_start + field_marker.len();
@@ -18,7 +23,7 @@ pub fn extract_field_content(input: &str, tag: &str) -> Option<(String, usize)>
     let remaining = &input[content_start..];
 
     // Look for the next field (starts with `:` and has format `:XX:` or `:XXX:`)
-    let content_end = find_next_field_boundary(remaining);
+    let content_end = find_field_end(tag, remaining);
 
     // Extract content
     let (raw_content, has_trailing_newline) = if let Some(end) = content_end {
@@ -70,50 +75,144 @@ pub fn extract_field_content(input: &str, tag: &str) -> Option<(String, usize)>
     Some((content.to_string(), consumed))
 }
 
-/// Find the boundary of the next field
-fn find_next_field_boundary(input: &str) -> Option<usize> {
-    let mut chars = input.char_indices();
-
-    while let Some((i, ch)) = chars.next() {
-        if ch == '\n' {
-            // Check if next character starts a field
-            if let Some((_, ':')) = chars.next() {
-                // This might be a field marker, verify the pattern
-                let rest = &input[i + 1..];
-                if is_field_marker(rest) {
-                    return Some(i);
-                }
+/// Extract every field from SWIFT message text in original order
+///
+/// Returns `(tag, content)` pairs where the tag keeps its option letter (e.g. "50K", "57A").
+/// Line endings are normalized to `\n` and the block terminator (`-`) is dropped.
+pub fn extract_all_fields(input: &str) -> Vec<(String, String)> {
+    let normalized = input.replace("\r\n", "\n");
+    let mut fields = Vec::new();
+
+    // Locate the first field marker (at the very start or after a newline)
+    let mut cursor = if is_field_marker(&normalized) {
+        Some(0)
+    } else {
+        find_next_field_boundary(&normalized).map(|nl| nl + 1)
+    };
+
+    while let Some(start) = cursor {
+        let rest = &normalized[start..];
+        let Some(close) = rest[1..].find(':') else {
+            break;
+        };
+        let tag = &rest[1..close + 1];
+        let content_start = close + 2;
+        let remaining = &rest[content_start..];
+
+        let (content, next) = match find_field_end(tag, remaining) {
+            Some(end) => (&remaining[..end], Some(start + content_start + end + 1)),
+            None => {
+                let trimmed = remaining.trim_end_matches(['\n', '}']);
+                (trimmed.strip_suffix("\n-").unwrap_or(trimmed), None)
             }
-        }
+        };
+
+        fields.push((tag.to_string(), content.trim_end_matches('\n').to_string()));
+        cursor = next;
     }
 
-    None
+    fields
 }
 
-/// Check if the text starts with a valid field marker pattern
-fn is_field_marker(input: &str) -> bool {
-    if !input.starts_with(':') {
-        return false;
+/// Byte range of one field in the original message text
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FieldSpan {
+    /// Field tag with its option letter (e.g. "32A")
+    pub tag: String,
+    /// Offset of the leading `:` of the tag
+    pub start: usize,
+    /// Offset just past the last content character (line endings excluded)
+    pub end: usize,
+}
+
+/// Byte ranges of every field in block 4 text, in original order
+///
+/// Offsets are relative to `input`, which should stop before the block terminator (`-}`).
+pub fn field_spans(input: &str) -> Vec<FieldSpan> {
+    let mut spans = Vec::new();
+    let mut cursor = if is_field_marker(input) {
+        Some(0)
+    } else {
+        find_next_field_boundary(input).map(|nl| nl + 1)
+    };
+
+    while let Some(start) = cursor {
+        let rest = &input[start..];
+        let Some(close) = rest[1..].find(':') else {
+            break;
+        };
+        let content_start = close + 2;
+        let (content_end, next) = match find_field_end(&rest[1..close + 1], &rest[content_start..])
+        {
+            Some(end) => (content_start + end, Some(start + content_start + end + 1)),
+            None => (rest.len(), None),
+        };
+
+        spans.push(FieldSpan {
+            tag: rest[1..close + 1].to_string(),
+            start,
+            end: start + rest[..content_end].trim_end_matches(['\r', '\n']).len(),
+        });
+        cursor = next;
     }
 
-    // Find the closing colon
-    if let Some(close) = input[1..].find(':') {
-        let tag = &input[1..close + 1];
+    spans
+}
 
-        // Valid field tags are 2-4 characters, alphanumeric
-        if (2..=4).contains(&tag.len()) {
-            // Check if all characters are alphanumeric
-            return tag.chars().all(|c| c.is_alphanumeric());
-        }
+/// Find the end of the content of field `tag`, i.e. the boundary of the next field
+///
+/// Envelope fields (77E, 77F) carry an embedded message whose `:27A:`-style lines are
+/// content, so they extend to the end of block 4.
+pub(super) fn find_field_end(tag: &str, content: &str) -> Option<usize> {
+    match tag {
+        "77E" | "77F" => None,
+        _ => find_next_field_boundary(content),
     }
+}
 
-    false
+/// Find the boundary of the next field
+pub(super) fn find_next_field_boundary(input: &str) -> Option<usize> {
+    // Peek at the character after each newline rather than consuming it, so a blank
+    // line directly before a tag (`\n\n:71A:`) is still seen
+    input
+        .match_indices('\n')
+        .map(|(i, _)| i)
+        .find(|&i| is_field_marker(&input[i + 1..]))
+}
+
+/// Check if the text starts with a valid field marker pattern
+///
+/// A tag is two digits optionally followed by an uppercase option letter (`:20:`, `:32A:`).
+/// Anything else after a leading colon (e.g. a field 70 line `:RFB:INV 1`) is content.
+fn is_field_marker(input: &str) -> bool {
+    let Some(rest) = input.strip_prefix(':') else {
+        return false;
+    };
+    let Some((tag, _)) = rest.split_once(':') else {
+        return false;
+    };
+
+    let bytes = tag.as_bytes();
+    match bytes {
+        [a, b] => a.is_ascii_digit() && b.is_ascii_digit(),
+        [a, b, option] => a.is_ascii_digit() && b.is_ascii_digit() && option.is_ascii_uppercase(),
+        _ => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_field_boundary_after_blank_line() {
+        assert_eq!(find_next_field_boundary("SHA\n\n:71A:OUR"), Some(4));
+
+        let fields = extract_all_fields(":20:REF\n\n:71A:SHA\n-");
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1], ("71A".to_string(), "SHA".to_string()));
+    }
+
     #[test]
     fn test_extract_simple_field() {
         let input = ":20:REF123\n:21:RELREF\n-";
@@ -150,6 +249,21 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_extract_all_fields_keeps_variants_and_order() {
+        let input = ":20:REF123\r\n:50K:/12345\r\nJOHN DOE\r\n:57A:DEUTDEFF\r\n:71A:SHA\r\n-";
+        let fields = extract_all_fields(input);
+        assert_eq!(
+            fields,
+            vec![
+                ("20".to_string(), "REF123".to_string()),
+                ("50K".to_string(), "/12345\nJOHN DOE".to_string()),
+                ("57A".to_string(), "DEUTDEFF".to_string()),
+                ("71A".to_string(), "SHA".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_field_marker_detection() {
         assert!(is_field_marker(":20:"));
@@ -158,5 +272,25 @@ mod tests {
         assert!(!is_field_marker(":12345:")); // Too long
         assert!(!is_field_marker(":X:")); // Too short
         assert!(!is_field_marker("20:")); // No starting colon
+        assert!(!is_field_marker(":RFB:INV 1")); // Not a numeric tag
+        assert!(!is_field_marker(":SEE REF")); // No closing colon
+    }
+
+    #[test]
+    fn test_narrative_line_starting_with_colon() {
+        let input = ":20:REF1\n:70:/INV/12345\n:RFB:INV 2024-001\n:SEE REF 12:30\n:71A:OUR\n-";
+
+        let fields = extract_all_fields(input);
+        let tags: Vec<&str> = fields.iter().map(|(tag, _)| tag.as_str()).collect();
+        assert_eq!(tags, vec!["20", "70", "71A"]);
+        assert_eq!(fields[1].1, "/INV/12345\n:RFB:INV 2024-001\n:SEE REF 12:30");
+
+        let (content, _) = extract_field_content(input, "70").unwrap();
+        assert_eq!(content, fields[1].1);
+        assert!(extract_field_content(":70:SEE :20:ABC\n-", "20").is_none());
+
+        let block4 = ":20:REF1\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/12345678\nJANE SMITH\n:70:/INV/12345\n:RFB:INV 2024-001\n:71A:OUR\n-";
+        let mt103 = <crate::MT103 as crate::SwiftMessageBody>::parse_from_block4(block4).unwrap();
+        assert_eq!(mt103.field_70.unwrap().narrative[1], ":RFB:INV 2024-001");
     }
 }
@@ -58,7 +58,9 @@ pub fn parse_block4_fields(block4: &str) -> Result<HashMap<String, Vec<(String,
 
                 // Find the end of field value (next field marker or end of content)
                 let value_start = tag_end + 1;
-                let value_end = if let Some(next_field) = content[value_start..].find("\n:") {
+                let value_end = if let Some(next_field) =
+                    super::field_extractor::find_field_end(raw_field_tag, &content[value_start..])
+                {
                     value_start + next_field
                 } else {
                     content.len()
@@ -83,6 +85,8 @@ pub fn parse_block4_fields(block4: &str) -> Result<HashMap<String, Vec<(String,
             } else {
                 // Last field or malformed - provide detailed error
                 return Err(ParseError::InvalidBlockStructure {
+                    reason: crate::errors::BlockErrorReason::InvalidContent,
+                    offset: None,
                     block: "4".to_string(),
                     message: format!(
                         "Malformed field tag at line {line_number}, position {current_pos}"
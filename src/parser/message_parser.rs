@@ -4,10 +4,11 @@
 //! This replaces the HashMap-based approach with a more efficient single-pass parser.
 
 use crate::errors::{InvalidFieldFormatError, ParseError};
+use crate::fields::swift_utils::{record_missing_mandatory, warn_missing_mandatory};
 use crate::traits::SwiftField;
 use std::collections::HashSet;
 
-use super::field_extractor::extract_field_content;
+use super::field_extractor::{extract_all_fields, extract_field_content};
 
 /// Message parser that tracks position while parsing SWIFT messages
 #[derive(Debug)]
@@ -42,21 +43,31 @@ impl<'a> MessageParser<'a> {
         self
     }
 
+    /// Wrap a field parse failure with its tag and position, keeping any SWIFT error code
+    fn field_error(&self, field_tag: String, value: String, error: ParseError) -> ParseError {
+        ParseError::InvalidFieldFormat(Box::new(InvalidFieldFormatError {
+            field_tag,
+            component_name: "field".to_string(),
+            value,
+            format_spec: "field format".to_string(),
+            position: Some(self.position),
+            code: error.swift_code().map(str::to_string),
+            inner_error: error.to_string(),
+        }))
+    }
+
     /// Parse a required field
+    ///
+    /// Under `MissingMandatoryPolicy::Warn` an absent field that can be empty is parsed as
+    /// [`SwiftField::empty`] and recorded instead of failing.
     pub fn parse_field<T: SwiftField>(&mut self, tag: &str) -> Result<T, ParseError> {
-        let field_content = self.extract_field(tag, false)?;
+        let field_content = match self.extract_field(tag, false) {
+            Err(error) => return missing_mandatory(error),
+            Ok(content) => content,
+        };
 
         // Try to parse the field
-        T::parse(&field_content).map_err(|e| {
-            ParseError::InvalidFieldFormat(Box::new(InvalidFieldFormatError {
-                field_tag: tag.to_string(),
-                component_name: "field".to_string(),
-                value: field_content,
-                format_spec: "field format".to_string(),
-                position: Some(self.position),
-                inner_error: e.to_string(),
-            }))
-        })
+        T::parse(&field_content).map_err(|e| self.field_error(tag.to_string(), field_content, e))
     }
 
     /// Parse an optional field (only checks immediate next field, not searching ahead)
@@ -73,16 +84,8 @@ impl<'a> MessageParser<'a> {
         // If immediate next field matches, extract and parse it
         match self.extract_field(tag, true) {
             Ok(content) => {
-                let parsed = T::parse(&content).map_err(|e| {
-                    ParseError::InvalidFieldFormat(Box::new(InvalidFieldFormatError {
-                        field_tag: tag.to_string(),
-                        component_name: "field".to_string(),
-                        value: content,
-                        format_spec: "field format".to_string(),
-                        position: Some(self.position),
-                        inner_error: e.to_string(),
-                    }))
-                })?;
+                let parsed = T::parse(&content)
+                    .map_err(|e| self.field_error(tag.to_string(), content, e))?;
                 Ok(Some(parsed))
             }
             Err(_) => Ok(None), // Field not found, return None for optional
@@ -95,16 +98,8 @@ impl<'a> MessageParser<'a> {
 
         // Keep parsing until no more instances found
         while let Ok(content) = self.extract_field(tag, true) {
-            let parsed = T::parse(&content).map_err(|e| {
-                ParseError::InvalidFieldFormat(Box::new(InvalidFieldFormatError {
-                    field_tag: tag.to_string(),
-                    component_name: "field".to_string(),
-                    value: content,
-                    format_spec: "field format".to_string(),
-                    position: Some(self.position),
-                    inner_error: e.to_string(),
-                }))
-            })?;
+            let parsed =
+                T::parse(&content).map_err(|e| self.field_error(tag.to_string(), content, e))?;
             results.push(parsed);
         }
 
@@ -112,23 +107,20 @@ impl<'a> MessageParser<'a> {
     }
 
     /// Parse a field with variant detection (for enum fields)
+    ///
+    /// An absent field follows `MissingMandatoryPolicy` like [`Self::parse_field`].
     pub fn parse_variant_field<T: SwiftField>(&mut self, base_tag: &str) -> Result<T, ParseError> {
         // Look ahead to find which variant is present
-        let variant = self.detect_variant(base_tag)?;
+        let variant = match self.detect_variant(base_tag) {
+            Err(error) => return missing_mandatory(error),
+            Ok(variant) => variant,
+        };
         let full_tag = format!("{}{}", base_tag, variant);
         let field_content = self.extract_field(&full_tag, false)?;
 
         // Use parse_with_variant for enum fields
-        T::parse_with_variant(&field_content, Some(&variant), Some(base_tag)).map_err(|e| {
-            ParseError::InvalidFieldFormat(Box::new(InvalidFieldFormatError {
-                field_tag: full_tag,
-                component_name: "field".to_string(),
-                value: field_content,
-                format_spec: "field format".to_string(),
-                position: Some(self.position),
-                inner_error: e.to_string(),
-            }))
-        })
+        T::parse_with_variant(&field_content, Some(&variant), Some(base_tag))
+            .map_err(|e| self.field_error(full_tag, field_content, e))
     }
 
     /// Parse an optional field with variant detection
@@ -141,16 +133,7 @@ impl<'a> MessageParser<'a> {
                 let full_tag = format!("{}{}", base_tag, variant);
                 if let Ok(content) = self.extract_field(&full_tag, true) {
                     let parsed = T::parse_with_variant(&content, Some(&variant), Some(base_tag))
-                        .map_err(|e| {
-                            ParseError::InvalidFieldFormat(Box::new(InvalidFieldFormatError {
-                                field_tag: full_tag,
-                                component_name: "field".to_string(),
-                                value: content,
-                                format_spec: "field format".to_string(),
-                                position: Some(self.position),
-                                inner_error: e.to_string(),
-                            }))
-                        })?;
+                        .map_err(|e| self.field_error(full_tag, content, e))?;
                     Ok(Some(parsed))
                 } else {
                     Ok(None)
@@ -267,6 +250,30 @@ impl<'a> MessageParser<'a> {
         &self.input[self.position..]
     }
 
+    /// Consume the fields copied from the original message after field 79 of an n92
+    ///
+    /// Every remaining field must be accepted by `is_copied`; the first that is not is
+    /// reported as unexpected. The fields are returned as raw `:TAG:value` lines.
+    pub fn take_copied_fields(
+        &mut self,
+        is_copied: impl Fn(&str) -> bool,
+    ) -> Result<Vec<String>, ParseError> {
+        let mut lines = Vec::new();
+        for (tag, value) in extract_all_fields(self.remaining()) {
+            if !is_copied(&tag) {
+                return Err(ParseError::InvalidFormat {
+                    message: format!(
+                        "MT{}: unexpected field {} after field 79",
+                        self.message_type, tag
+                    ),
+                });
+            }
+            lines.extend(format!(":{}:{}", tag, value).lines().map(str::to_string));
+        }
+        self.position = self.input.len();
+        Ok(lines)
+    }
+
     /// Check if we've reached the end of input
     pub fn is_complete(&self) -> bool {
         self.position >= self.input.len()
@@ -274,6 +281,11 @@ impl<'a> MessageParser<'a> {
             || self.remaining().trim() == "-"
     }
 
+    /// Upper bound on how many more times `tag` occurs, for pre-reserving repeated fields
+    pub fn count_remaining(&self, tag: &str) -> usize {
+        self.remaining().matches(&format!(":{}:", tag)).count()
+    }
+
     /// Check if a field exists in the remaining content
     pub fn detect_field(&self, tag: &str) -> bool {
         let remaining = self.remaining();
@@ -307,3 +319,17 @@ impl<'a> MessageParser<'a> {
         None
     }
 }
+
+/// Empty stand-in for a missing mandatory field under `MissingMandatoryPolicy::Warn`
+///
+/// Any other error, or a field that cannot be empty, is returned as is.
+fn missing_mandatory<T: SwiftField>(error: ParseError) -> Result<T, ParseError> {
+    if let ParseError::MissingRequiredField { .. } = error
+        && warn_missing_mandatory()
+        && let Some(empty) = T::empty()
+    {
+        record_missing_mandatory(error);
+        return Ok(empty);
+    }
+    Err(error)
+}
@@ -5,11 +5,18 @@
 //! - **SwiftParser**: Main entry point for complete message parsing
 //! - **MessageParser**: Field-level sequential parsing
 //! - **SequenceParser**: Repetitive sequence handling (MT101, MT104, etc.)
+//! - **SwiftStreamParser**: Incremental parsing of messages arriving in arbitrary chunks
+//! - **envelope**: Extraction of FIN messages received inside an XML envelope
+//! - **ebcdic** (feature `ebcdic`): EBCDIC byte stream transcoding
 
+#[cfg(feature = "ebcdic")]
+pub mod ebcdic;
+mod envelope;
 pub mod field_extractor;
 mod generated;
 pub mod message_parser;
 pub mod sequence_parser;
+mod stream_parser;
 mod swift_parser;
 pub mod utils;
 
@@ -23,6 +30,12 @@ pub use swift_parser::{
     parse_sequences,
 };
 
+// Re-export envelope extraction
+pub use envelope::{MtEnvelope, extract_envelope};
+
+// Re-export stream parser
+pub use stream_parser::{BatchReport, SwiftStreamParser};
+
 // Re-export sequence parser types
 pub use sequence_parser::{
     ParsedSequences, SequenceConfig, get_sequence_config, parse_repetitive_sequence,
@@ -30,8 +43,11 @@ pub use sequence_parser::{
 };
 
 // Re-export message parser for internal use
-pub use field_extractor::extract_field_content;
+pub use field_extractor::{FieldSpan, extract_all_fields, extract_field_content, field_spans};
 pub use message_parser::MessageParser;
 
+#[cfg(feature = "ebcdic")]
+pub use ebcdic::{Ebcdic, ebcdic_to_string};
+
 // Re-export utility functions
 pub use utils::*;
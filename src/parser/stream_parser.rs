@@ -0,0 +1,287 @@
+//! # Stream Parser
+//!
+//! Incremental parser for SWIFT messages read from a byte stream (e.g. a TCP socket),
+//! where chunk boundaries may fall anywhere, including mid-field.
+
+use crate::ParsedSwiftMessage;
+use crate::errors::{ParseError, ParserConfig, Result};
+use crate::parser::SwiftParser;
+
+/// Summary of a batch validated with [`SwiftParser::validate_file`]
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// Number of messages found in the input
+    pub total: usize,
+    /// Number of messages that parsed and passed validation
+    pub valid: usize,
+    /// Zero-based message index and the error reported for it
+    pub errors: Vec<(usize, ParseError)>,
+}
+
+/// Stateful parser that buffers partial input across [`feed`](Self::feed) calls
+///
+/// A message is complete once its block 4 terminator `-}` has been received together with
+/// the next non-whitespace input, which shows whether a block 5 trailer follows. A message
+/// at the very end of the input is held until more bytes arrive or [`finish`](Self::finish)
+/// is called.
+#[derive(Debug, Default)]
+pub struct SwiftStreamParser {
+    parser: SwiftParser,
+    buffer: Vec<u8>,
+}
+
+impl SwiftStreamParser {
+    /// Create a stream parser with the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a stream parser; `config.max_stream_buffer` bounds the pending buffer
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self {
+            parser: SwiftParser::with_config(config),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Number of bytes buffered for a message that is not yet complete
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Append a chunk and return every message completed by it, in arrival order
+    ///
+    /// A message that fails to parse is returned as an error without affecting the
+    /// messages around it. If the pending data exceeds `max_stream_buffer`, it is
+    /// discarded and an error is returned in its place.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<Result<ParsedSwiftMessage>> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut messages = Vec::new();
+        while let Some(end) = self.next_message_end() {
+            let chunk: Vec<u8> = self.buffer.drain(..end).collect();
+            if let Some(message) = self.parse_chunk(&chunk) {
+                messages.push(message);
+            }
+        }
+
+        if self.buffer.len() > self.parser.config.max_stream_buffer {
+            messages.push(Err(ParseError::InvalidFormat {
+                message: format!(
+                    "Stream buffer exceeded {} bytes without a complete message; {} bytes discarded",
+                    self.parser.config.max_stream_buffer,
+                    self.buffer.len()
+                ),
+            }));
+            self.buffer.clear();
+        }
+
+        messages
+    }
+
+    /// Flush input left over at end of stream as one final message
+    ///
+    /// Returns `None` when only whitespace or a stray trailer remains. A held complete
+    /// message is parsed as usual; an incomplete one is parsed so its structural error is
+    /// reported.
+    pub fn finish(&mut self) -> Option<Result<ParsedSwiftMessage>> {
+        let chunk = std::mem::take(&mut self.buffer);
+        if chunk.iter().all(u8::is_ascii_whitespace) {
+            return None;
+        }
+        self.parse_chunk(&chunk)
+    }
+
+    /// End offset of the first complete unit in the buffer (a message or a stray trailer)
+    fn next_message_end(&self) -> Option<usize> {
+        let start = self.buffer.iter().position(|b| !b.is_ascii_whitespace())?;
+        if self.buffer[start..].starts_with(b"{5:") {
+            return block_end(&self.buffer, start);
+        }
+
+        let terminator = find(&self.buffer[start..], b"-}")? + start + 2;
+        let rest = &self.buffer[terminator..];
+
+        // Until the next non-whitespace input arrives, a trailer may still follow
+        let offset = rest.iter().position(|b| !b.is_ascii_whitespace())?;
+        let next = &rest[offset..];
+        if next.starts_with(b"{5:") {
+            block_end(&self.buffer, terminator + offset)
+        } else if b"{5:".starts_with(next) {
+            None
+        } else {
+            Some(terminator)
+        }
+    }
+
+    /// Parse one complete unit; stray trailers yield nothing
+    fn parse_chunk(&self, chunk: &[u8]) -> Option<Result<ParsedSwiftMessage>> {
+        let text = match std::str::from_utf8(chunk) {
+            Ok(text) => text.trim(),
+            Err(e) => {
+                return Some(Err(ParseError::InvalidFormat {
+                    message: format!("Stream message is not valid UTF-8: {e}"),
+                }));
+            }
+        };
+        if text.starts_with("{5:") {
+            return None;
+        }
+        Some(self.parser.parse_message_auto(text))
+    }
+}
+
+/// Offset just past the brace block opening at `start`, if it has been fully received
+fn block_end(buffer: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (offset, byte) in buffer[start..].iter().enumerate() {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + offset + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const MT103: &str = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+
+    #[test]
+    fn test_feed_one_byte_at_a_time() {
+        let mut stream = SwiftStreamParser::new();
+        let mut messages = Vec::new();
+        for byte in MT103.as_bytes() {
+            messages.extend(stream.feed(std::slice::from_ref(byte)));
+        }
+        assert!(messages.is_empty());
+        messages.extend(stream.finish());
+
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0], Ok(ParsedSwiftMessage::MT103(_))));
+        assert_eq!(stream.buffered_len(), 0);
+    }
+
+    #[test]
+    fn test_feed_keeps_trailer_and_splits_messages() {
+        let with_trailer = format!("{MT103}{{5:{{CHK:123456789ABC}}}}\r\n");
+        let input = format!("{with_trailer}{MT103}");
+        let (first, second) = input.split_at(with_trailer.len() + 40);
+
+        let mut stream = SwiftStreamParser::new();
+        let messages = stream.feed(first.as_bytes());
+        assert_eq!(messages.len(), 1);
+        match &messages[0] {
+            Ok(ParsedSwiftMessage::MT103(message)) => assert!(message.trailer.is_some()),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert_eq!(stream.buffered_len(), "\r\n".len() + 40);
+
+        assert!(stream.feed(second.as_bytes()).is_empty());
+        assert!(matches!(
+            stream.finish(),
+            Some(Ok(ParsedSwiftMessage::MT103(_)))
+        ));
+    }
+
+    #[test]
+    fn test_feed_holds_message_until_trailer_arrives() {
+        let input = format!("{MT103}\r\n{{5:{{CHK:123456789ABC}}}}");
+        for split in ["-}", "-}\r\n", "-}\r\n{", "-}\r\n{5"] {
+            let mut stream = SwiftStreamParser::new();
+            let at = input.find(split).unwrap() + split.len();
+            let (first, second) = input.split_at(at);
+
+            assert!(
+                stream.feed(first.as_bytes()).is_empty(),
+                "split after {split:?}"
+            );
+            let messages = stream.feed(second.as_bytes());
+            assert_eq!(messages.len(), 1, "split after {split:?}");
+            match &messages[0] {
+                Ok(ParsedSwiftMessage::MT103(message)) => assert!(message.trailer.is_some()),
+                other => panic!("unexpected result: {other:?}"),
+            }
+            assert!(stream.finish().is_none());
+        }
+
+        // A following message releases the held one without a trailer
+        let mut stream = SwiftStreamParser::new();
+        assert!(stream.feed(MT103.as_bytes()).is_empty());
+        assert_eq!(stream.feed(b"\r\n{1:").len(), 1);
+    }
+
+    #[test]
+    fn test_feed_bounds_buffer() {
+        let mut stream = SwiftStreamParser::with_config(ParserConfig {
+            max_stream_buffer: 16,
+            ..Default::default()
+        });
+
+        let messages = stream.feed(&MT103.as_bytes()[..32]);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].is_err());
+        assert_eq!(stream.buffered_len(), 0);
+    }
+
+    #[test]
+    fn test_validate_file() {
+        let broken = MT103.replace(":32A:240315USD1000,00", ":32A:241315USD1000,00");
+        let truncated = &MT103[..MT103.len() - 2];
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "{MT103}\r\n{broken}\r\n{MT103}\r\n{truncated}").unwrap();
+
+        let report = SwiftParser::new().validate_file(file.path()).unwrap();
+        assert_eq!(report.total, 4);
+        assert_eq!(report.valid, 2);
+        let indexes: Vec<usize> = report.errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indexes, vec![1, 3]);
+
+        assert!(
+            SwiftParser::new()
+                .validate_file(file.path().join("missing"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_file_streams_with_buffer_limit() {
+        // Larger than the reader's buffer, so messages straddle read boundaries
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        for _ in 0..200 {
+            write!(file, "{MT103}\r\n").unwrap();
+        }
+
+        let report = SwiftParser::new().validate_file(file.path()).unwrap();
+        assert_eq!((report.total, report.valid), (200, 200));
+
+        // Unterminated input is dropped once it exceeds the configured limit
+        write!(file, "{}", "X".repeat(64 * 1024)).unwrap();
+        let report = SwiftParser::with_config(ParserConfig {
+            max_stream_buffer: 16 * 1024,
+            ..Default::default()
+        })
+        .validate_file(file.path())
+        .unwrap();
+        assert_eq!(report.valid, 200);
+        assert!(report.errors.iter().any(|(_, error)| matches!(
+            error,
+            ParseError::InvalidFormat { message } if message.starts_with("Stream buffer exceeded")
+        )));
+    }
+}
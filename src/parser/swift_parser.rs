@@ -42,14 +42,19 @@
 //! # }
 //! ```
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
-use crate::errors::{ParseError, Result, SwiftValidationError};
-use crate::headers::{ApplicationHeader, BasicHeader, Trailer, UserHeader};
+use super::{BatchReport, SwiftStreamParser};
+use crate::errors::{BlockErrorReason, ParseError, ParserConfig, Result, SwiftValidationError};
+use crate::fields::swift_utils::{
+    collect_missing_mandatory, sanitize_swift_text, with_field_parse_options,
+};
+use crate::headers::{ApplicationHeader, BasicHeader, MessageHeaders, Trailer, UserHeader};
 use crate::messages::{
     MT101, MT103, MT104, MT107, MT110, MT111, MT112, MT190, MT191, MT192, MT196, MT199, MT200,
     MT202, MT204, MT205, MT210, MT290, MT291, MT292, MT296, MT299, MT900, MT910, MT920, MT935,
-    MT940, MT941, MT942, MT950,
+    MT940, MT941, MT942, MT950, MessageType,
 };
 use crate::swift_error_codes::t_series;
 use crate::{ParsedSwiftMessage, SwiftMessage, SwiftMessageBody};
@@ -475,20 +480,179 @@ pub fn find_field_with_variant_sequential_constrained(
 /// 6. **Validation**: Applies format and business rule validation
 ///
 /// ## Thread Safety
-/// SwiftParser is stateless and thread-safe. All methods are static and can be called
-/// concurrently from multiple threads.
-pub struct SwiftParser {}
-
-impl Default for SwiftParser {
-    fn default() -> Self {
-        Self::new()
-    }
+/// SwiftParser holds only its configuration and is thread-safe. Static methods use the
+/// default configuration and can be called concurrently from multiple threads.
+#[derive(Debug, Clone, Default)]
+pub struct SwiftParser {
+    /// Parser behavior configuration
+    pub config: ParserConfig,
 }
 
 impl SwiftParser {
     /// Create a new parser with default configuration
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Create a parser with the given configuration
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self { config }
+    }
+
+    /// Check the layout between top-level blocks
+    ///
+    /// Strict mode allows only line breaks between blocks. Lenient mode removes any
+    /// whitespace between blocks and upper-cases the basic and application headers.
+    fn normalize_block_layout<'a>(&self, raw_message: &'a str) -> Result<Cow<'a, str>> {
+        let lenient = self.config.lenient_block_format;
+        let mut normalized = String::with_capacity(raw_message.len());
+        let mut changed = false;
+        let mut depth = 0usize;
+        let mut current_block = None;
+        let mut blocks_seen = 0usize;
+
+        let mut chars = raw_message.char_indices().peekable();
+        while let Some((position, ch)) = chars.next() {
+            match ch {
+                '{' => {
+                    if depth == 0 {
+                        current_block = chars.peek().map(|&(_, id)| id);
+                        blocks_seen += 1;
+                    }
+                    depth += 1;
+                }
+                '}' => depth = depth.saturating_sub(1),
+                _ if depth == 0 && blocks_seen > 0 && ch.is_whitespace() => {
+                    let at_end = raw_message[position..].trim().is_empty();
+                    if !at_end && ch != '\n' && ch != '\r' {
+                        if !lenient {
+                            return Err(ParseError::InvalidBlockStructure {
+                                reason: BlockErrorReason::InvalidContent,
+                                offset: Some(position),
+                                block: current_block.map(String::from).unwrap_or_default(),
+                                message: format!(
+                                    "Unexpected whitespace {:?} at position {} after block {}; only line breaks are allowed between blocks",
+                                    ch,
+                                    position,
+                                    current_block.unwrap_or('?')
+                                ),
+                            });
+                        }
+                        changed = true;
+                        continue;
+                    }
+                    if lenient && !at_end {
+                        changed = true;
+                        continue;
+                    }
+                }
+                _ if lenient
+                    && depth == 1
+                    && matches!(current_block, Some('1' | '2'))
+                    && ch.is_ascii_lowercase() =>
+                {
+                    normalized.push(ch.to_ascii_uppercase());
+                    changed = true;
+                    continue;
+                }
+                _ => {}
+            }
+            normalized.push(ch);
+        }
+
+        Ok(if changed {
+            Cow::Owned(normalized)
+        } else {
+            Cow::Borrowed(raw_message)
+        })
+    }
+
+    /// Run `parse` and report it to `ParserConfig::metrics`
+    ///
+    /// `describe` yields the message type and field count of a parsed message, or `None`
+    /// to report a failure. Without a hook `parse` runs untimed.
+    fn measured<R>(
+        &self,
+        parse: impl FnOnce() -> Result<R>,
+        describe: impl FnOnce(&R) -> Option<(String, usize)>,
+    ) -> Result<R> {
+        let Some(metrics) = &self.config.metrics else {
+            return parse();
+        };
+
+        let start = std::time::Instant::now();
+        let result = parse();
+        let duration = start.elapsed();
+        match result.as_ref().ok().and_then(describe) {
+            Some((message_type, field_count)) => {
+                metrics.record_parse(&message_type, field_count, duration)
+            }
+            None => metrics.record_failure(duration),
+        }
+        result
+    }
+
+    /// Walk the top-level blocks and report the first structural break
+    ///
+    /// Catches content outside `{...}`, block identifiers other than 1-5 and unterminated
+    /// blocks, which `extract_block` would otherwise silently treat as absent.
+    fn check_block_structure(raw_message: &str) -> Result<()> {
+        let structure_error = |block: &str, reason, offset, message: String| {
+            Err(ParseError::InvalidBlockStructure {
+                block: block.to_string(),
+                message,
+                reason,
+                offset: Some(offset),
+            })
+        };
+
+        let mut position = 0;
+        while let Some(skipped) = raw_message[position..].find(|c: char| !c.is_whitespace()) {
+            let start = position + skipped;
+            let rest = &raw_message[start..];
+            if !rest.starts_with('{') {
+                return structure_error(
+                    "",
+                    BlockErrorReason::MissingOpenBrace,
+                    start,
+                    format!(
+                        "Expected '{{' at position {start}, found {:?}",
+                        rest.chars().next().unwrap_or_default()
+                    ),
+                );
+            }
+
+            let id = rest[1..].split(':').next().unwrap_or_default();
+            if !matches!(id, "1" | "2" | "3" | "4" | "5" | "S") || !rest[1..].contains(':') {
+                return structure_error(
+                    id,
+                    BlockErrorReason::UnexpectedBlockId,
+                    start,
+                    format!("Unexpected block identifier {id:?} at position {start}; expected 1-5"),
+                );
+            }
+
+            let end = if id == "4" {
+                rest.find("-}").map(|end| end + 2)
+            } else {
+                Self::find_matching_brace(rest).map(|end| end + 1)
+            };
+            match end {
+                Some(end) => position = start + end,
+                None => {
+                    let terminator = if id == "4" { "-}" } else { "}" };
+                    return structure_error(
+                        id,
+                        BlockErrorReason::MissingTerminator,
+                        start,
+                        format!(
+                            "Block {id} starting at position {start} is not closed with '{terminator}'"
+                        ),
+                    );
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Parse a message and return ParseResult with all errors collected
@@ -496,6 +660,25 @@ impl SwiftParser {
         &self,
         raw_message: &str,
     ) -> Result<crate::errors::ParseResult<SwiftMessage<T>>> {
+        self.measured(
+            || self.parse_with_errors_unmeasured(raw_message),
+            |result: &crate::errors::ParseResult<SwiftMessage<T>>| match result {
+                crate::errors::ParseResult::Success(message)
+                | crate::errors::ParseResult::PartialSuccess(message, _) => Some((
+                    message.message_type.clone(),
+                    message.fields.to_fields_with_variants().len(),
+                )),
+                crate::errors::ParseResult::Failure(_) => None,
+            },
+        )
+    }
+
+    fn parse_with_errors_unmeasured<T: SwiftMessageBody>(
+        &self,
+        raw_message: &str,
+    ) -> Result<crate::errors::ParseResult<SwiftMessage<T>>> {
+        let raw_message = &*self.normalize_block_layout(raw_message)?;
+        Self::check_block_structure(raw_message)?;
         let block1 = Self::extract_block(raw_message, 1)?;
         let block2 = Self::extract_block(raw_message, 2)?;
         let block3 = Self::extract_block(raw_message, 3)?;
@@ -503,7 +686,7 @@ impl SwiftParser {
         let block5 = Self::extract_block(raw_message, 5)?;
 
         // Parse headers
-        let basic_header = BasicHeader::parse(&block1.unwrap_or_default())?;
+        let basic_header = self.parse_basic_header(&block1.unwrap_or_default())?;
         let application_header = ApplicationHeader::parse(&block2.unwrap_or_default())?;
         let user_header = block3.map(|b| UserHeader::parse(&b)).transpose()?;
         let trailer = block5.map(|b| Trailer::parse(&b)).transpose()?;
@@ -528,18 +711,73 @@ impl SwiftParser {
             )));
         }
 
-        // Parse block 4 using MessageParser-based approach
-        let fields = T::parse_from_block4(&block4.unwrap_or_default())?;
+        // Parse block 4 using MessageParser-based approach, reporting mandatory fields parsed empty
+        let (fields, missing) = self.parse_block4::<T>(block4);
 
-        Ok(crate::errors::ParseResult::Success(SwiftMessage {
+        let message = SwiftMessage {
             basic_header,
             application_header,
             user_header,
             trailer,
             message_type,
-            fields,
-        }))
+            fields: fields?,
+        };
+        if missing.is_empty() {
+            Ok(crate::errors::ParseResult::Success(message))
+        } else {
+            Ok(crate::errors::ParseResult::PartialSuccess(message, missing))
+        }
+    }
+
+    /// Parse only blocks 1-3 of a raw SWIFT message
+    ///
+    /// Everything from block 4 onwards is ignored, so routing decisions don't pay for field parsing.
+    pub fn parse_headers(&self, raw_message: &str) -> Result<MessageHeaders> {
+        let headers_end = raw_message.find("{4:").unwrap_or(raw_message.len());
+        let raw_headers = &*self.normalize_block_layout(&raw_message[..headers_end])?;
+
+        let block1 = Self::extract_block(raw_headers, 1)?;
+        let block2 = Self::extract_block(raw_headers, 2)?;
+        let block3 = Self::extract_block(raw_headers, 3)?;
+
+        Ok(MessageHeaders {
+            basic: self.parse_basic_header(&block1.unwrap_or_default())?,
+            application: ApplicationHeader::parse(&block2.unwrap_or_default())?,
+            user: block3.map(|b| UserHeader::parse(&b)).transpose()?,
+        })
+    }
+
+    /// Parse block 4 with the field-level options applied, returning the mandatory fields
+    /// recorded as missing under `MissingMandatoryPolicy::Warn`
+    fn parse_block4<T: SwiftMessageBody>(
+        &self,
+        block4: Option<String>,
+    ) -> (Result<T>, Vec<ParseError>) {
+        with_field_parse_options(&self.config, || {
+            collect_missing_mandatory(|| {
+                T::parse_from_block4(&self.prepare_block4(block4.unwrap_or_default()))
+            })
+        })
+    }
+
+    /// Apply `ParserConfig::sanitize_text` to block 4 before field parsing
+    fn prepare_block4(&self, block4: String) -> String {
+        if self.config.sanitize_text {
+            sanitize_swift_text(&block4).0
+        } else {
+            block4
+        }
     }
+
+    /// Parse block 1, honouring `ParserConfig::lenient_block1_length`
+    fn parse_basic_header(&self, block1: &str) -> Result<BasicHeader> {
+        if self.config.lenient_block1_length {
+            BasicHeader::parse_lenient(block1)
+        } else {
+            BasicHeader::parse(block1)
+        }
+    }
+
     /// Parse a raw SWIFT message string into a typed message (static method for backward compatibility)
     pub fn parse<T: SwiftMessageBody>(raw_message: &str) -> Result<SwiftMessage<T>> {
         Self::new().parse_message(raw_message)
@@ -547,6 +785,23 @@ impl SwiftParser {
 
     /// Parse a raw SWIFT message string into a typed message with configuration support
     pub fn parse_message<T: SwiftMessageBody>(&self, raw_message: &str) -> Result<SwiftMessage<T>> {
+        self.measured(
+            || self.parse_message_unmeasured(raw_message),
+            |message: &SwiftMessage<T>| {
+                Some((
+                    message.message_type.clone(),
+                    message.fields.to_fields_with_variants().len(),
+                ))
+            },
+        )
+    }
+
+    fn parse_message_unmeasured<T: SwiftMessageBody>(
+        &self,
+        raw_message: &str,
+    ) -> Result<SwiftMessage<T>> {
+        let raw_message = &*self.normalize_block_layout(raw_message)?;
+        Self::check_block_structure(raw_message)?;
         let block1 = Self::extract_block(raw_message, 1)?;
         let block2 = Self::extract_block(raw_message, 2)?;
         let block3 = Self::extract_block(raw_message, 3)?;
@@ -554,7 +809,7 @@ impl SwiftParser {
         let block5 = Self::extract_block(raw_message, 5)?;
 
         // Parse headers
-        let basic_header = BasicHeader::parse(&block1.unwrap_or_default())?;
+        let basic_header = self.parse_basic_header(&block1.unwrap_or_default())?;
         let application_header = ApplicationHeader::parse(&block2.unwrap_or_default())?;
         let user_header = block3.map(|b| UserHeader::parse(&b)).transpose()?;
         let trailer = block5.map(|b| Trailer::parse(&b)).transpose()?;
@@ -579,8 +834,13 @@ impl SwiftParser {
             )));
         }
 
-        // Parse block 4 using MessageParser-based approach
-        let fields = T::parse_from_block4(&block4.unwrap_or_default())?;
+        // Parse block 4 using MessageParser-based approach; without a way to return warnings,
+        // a mandatory field parsed empty under `MissingMandatoryPolicy::Warn` fails the parse
+        let (fields, missing) = self.parse_block4::<T>(block4);
+        let fields = fields?;
+        if let Some(error) = missing.into_iter().next() {
+            return Err(error);
+        }
 
         Ok(SwiftMessage {
             basic_header,
@@ -597,8 +857,55 @@ impl SwiftParser {
         Self::new().parse_message_auto(raw_message)
     }
 
+    /// Parse with automatic type detection and report where each block 4 field sits in `raw_message`
+    ///
+    /// Spans are byte offsets into `raw_message` itself and include the `:tag:` prefix.
+    pub fn parse_with_spans(
+        &self,
+        raw_message: &str,
+    ) -> Result<(ParsedSwiftMessage, Vec<crate::parser::FieldSpan>)> {
+        let message = self.parse_message_auto(raw_message)?;
+
+        let spans = match raw_message.find("{4:") {
+            Some(block_start) => {
+                let offset = block_start + 3;
+                let block4 = &raw_message[offset..];
+                let block_end = block4
+                    .find("\n-}")
+                    .or_else(|| block4.find("-}"))
+                    .unwrap_or(block4.len());
+                crate::parser::field_spans(&block4[..block_end])
+                    .into_iter()
+                    .map(|span| crate::parser::FieldSpan {
+                        start: span.start + offset,
+                        end: span.end + offset,
+                        ..span
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        Ok((message, spans))
+    }
+
     /// Parse a raw SWIFT message string with automatic message type detection and configuration support
     pub fn parse_message_auto(&self, raw_message: &str) -> Result<ParsedSwiftMessage> {
+        self.measured(
+            || self.parse_message_auto_unmeasured(raw_message),
+            |message: &ParsedSwiftMessage| {
+                Some((
+                    message.message_type().to_string(),
+                    message.fields_with_variants().len(),
+                ))
+            },
+        )
+    }
+
+    fn parse_message_auto_unmeasured(&self, raw_message: &str) -> Result<ParsedSwiftMessage> {
+        let raw_message = &*self.normalize_block_layout(raw_message)?;
+        Self::check_block_structure(raw_message)?;
+
         // First, extract blocks to get the message type
         let block2 = Self::extract_block(raw_message, 2)?;
 
@@ -607,133 +914,295 @@ impl SwiftParser {
         let message_type = application_header.message_type();
 
         // Route to appropriate parser based on message type
-        match message_type {
-            "101" => {
-                let parsed = self.parse_message::<MT101>(raw_message)?;
+        match application_header
+            .kind()
+            .unwrap_or_else(|_| MessageType::Other(message_type.to_string()))
+        {
+            MessageType::MT101 => {
+                let parsed = self.parse_message_unmeasured::<MT101>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT101(Box::new(parsed)))
             }
-            "103" => {
-                let parsed = self.parse_message::<MT103>(raw_message)?;
+            MessageType::MT103 => {
+                let parsed = self.parse_message_unmeasured::<MT103>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT103(Box::new(parsed)))
             }
-            "104" => {
-                let parsed = self.parse_message::<MT104>(raw_message)?;
+            MessageType::MT104 => {
+                let parsed = self.parse_message_unmeasured::<MT104>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT104(Box::new(parsed)))
             }
-            "107" => {
-                let parsed = self.parse_message::<MT107>(raw_message)?;
+            MessageType::MT107 => {
+                let parsed = self.parse_message_unmeasured::<MT107>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT107(Box::new(parsed)))
             }
-            "110" => {
-                let parsed = self.parse_message::<MT110>(raw_message)?;
+            MessageType::MT110 => {
+                let parsed = self.parse_message_unmeasured::<MT110>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT110(Box::new(parsed)))
             }
-            "111" => {
-                let parsed = self.parse_message::<MT111>(raw_message)?;
+            MessageType::MT111 => {
+                let parsed = self.parse_message_unmeasured::<MT111>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT111(Box::new(parsed)))
             }
-            "112" => {
-                let parsed = self.parse_message::<MT112>(raw_message)?;
+            MessageType::MT112 => {
+                let parsed = self.parse_message_unmeasured::<MT112>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT112(Box::new(parsed)))
             }
-            "190" => {
-                let parsed = self.parse_message::<MT190>(raw_message)?;
+            MessageType::MT190 => {
+                let parsed = self.parse_message_unmeasured::<MT190>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT190(Box::new(parsed)))
             }
-            "191" => {
-                let parsed = self.parse_message::<MT191>(raw_message)?;
+            MessageType::MT191 => {
+                let parsed = self.parse_message_unmeasured::<MT191>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT191(Box::new(parsed)))
             }
-            "200" => {
-                let parsed = self.parse_message::<MT200>(raw_message)?;
+            MessageType::MT200 => {
+                let parsed = self.parse_message_unmeasured::<MT200>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT200(Box::new(parsed)))
             }
-            "202" => {
-                let parsed = self.parse_message::<MT202>(raw_message)?;
+            MessageType::MT202 => {
+                let parsed = self.parse_message_unmeasured::<MT202>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT202(Box::new(parsed)))
             }
-            "204" => {
-                let parsed = self.parse_message::<MT204>(raw_message)?;
+            MessageType::MT204 => {
+                let parsed = self.parse_message_unmeasured::<MT204>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT204(Box::new(parsed)))
             }
-            "205" => {
-                let parsed = self.parse_message::<MT205>(raw_message)?;
+            MessageType::MT205 => {
+                let parsed = self.parse_message_unmeasured::<MT205>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT205(Box::new(parsed)))
             }
-            "210" => {
-                let parsed = self.parse_message::<MT210>(raw_message)?;
+            MessageType::MT210 => {
+                let parsed = self.parse_message_unmeasured::<MT210>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT210(Box::new(parsed)))
             }
-            "290" => {
-                let parsed = self.parse_message::<MT290>(raw_message)?;
+            MessageType::MT290 => {
+                let parsed = self.parse_message_unmeasured::<MT290>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT290(Box::new(parsed)))
             }
-            "291" => {
-                let parsed = self.parse_message::<MT291>(raw_message)?;
+            MessageType::MT291 => {
+                let parsed = self.parse_message_unmeasured::<MT291>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT291(Box::new(parsed)))
             }
-            "900" => {
-                let parsed = self.parse_message::<MT900>(raw_message)?;
+            MessageType::MT900 => {
+                let parsed = self.parse_message_unmeasured::<MT900>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT900(Box::new(parsed)))
             }
-            "910" => {
-                let parsed = self.parse_message::<MT910>(raw_message)?;
+            MessageType::MT910 => {
+                let parsed = self.parse_message_unmeasured::<MT910>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT910(Box::new(parsed)))
             }
-            "920" => {
-                let parsed = self.parse_message::<MT920>(raw_message)?;
+            MessageType::MT920 => {
+                let parsed = self.parse_message_unmeasured::<MT920>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT920(Box::new(parsed)))
             }
-            "935" => {
-                let parsed = self.parse_message::<MT935>(raw_message)?;
+            MessageType::MT935 => {
+                let parsed = self.parse_message_unmeasured::<MT935>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT935(Box::new(parsed)))
             }
-            "940" => {
-                let parsed = self.parse_message::<MT940>(raw_message)?;
+            MessageType::MT940 => {
+                let parsed = self.parse_message_unmeasured::<MT940>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT940(Box::new(parsed)))
             }
-            "941" => {
-                let parsed = self.parse_message::<MT941>(raw_message)?;
+            MessageType::MT941 => {
+                let parsed = self.parse_message_unmeasured::<MT941>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT941(Box::new(parsed)))
             }
-            "942" => {
-                let parsed = self.parse_message::<MT942>(raw_message)?;
+            MessageType::MT942 => {
+                let parsed = self.parse_message_unmeasured::<MT942>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT942(Box::new(parsed)))
             }
-            "950" => {
-                let parsed = self.parse_message::<MT950>(raw_message)?;
+            MessageType::MT950 => {
+                let parsed = self.parse_message_unmeasured::<MT950>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT950(Box::new(parsed)))
             }
-            "192" => {
-                let parsed = self.parse_message::<MT192>(raw_message)?;
+            MessageType::MT192 => {
+                let parsed = self.parse_message_unmeasured::<MT192>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT192(Box::new(parsed)))
             }
-            "196" => {
-                let parsed = self.parse_message::<MT196>(raw_message)?;
+            MessageType::MT196 => {
+                let parsed = self.parse_message_unmeasured::<MT196>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT196(Box::new(parsed)))
             }
-            "292" => {
-                let parsed = self.parse_message::<MT292>(raw_message)?;
+            MessageType::MT292 => {
+                let parsed = self.parse_message_unmeasured::<MT292>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT292(Box::new(parsed)))
             }
-            "296" => {
-                let parsed = self.parse_message::<MT296>(raw_message)?;
+            MessageType::MT296 => {
+                let parsed = self.parse_message_unmeasured::<MT296>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT296(Box::new(parsed)))
             }
-            "199" => {
-                let parsed = self.parse_message::<MT199>(raw_message)?;
+            MessageType::MT199 => {
+                let parsed = self.parse_message_unmeasured::<MT199>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT199(Box::new(parsed)))
             }
-            "299" => {
-                let parsed = self.parse_message::<MT299>(raw_message)?;
+            MessageType::MT299 => {
+                let parsed = self.parse_message_unmeasured::<MT299>(raw_message)?;
                 Ok(ParsedSwiftMessage::MT299(Box::new(parsed)))
             }
-            _ => Err(ParseError::UnsupportedMessageType {
+            MessageType::Other(_) if self.config.allow_generic => self.parse_generic(raw_message),
+            MessageType::Other(_) => Err(ParseError::UnsupportedMessageType {
                 message_type: message_type.to_string(),
             }),
         }
     }
 
+    /// Parse a message of any type into raw block 4 fields
+    fn parse_generic(&self, raw_message: &str) -> Result<ParsedSwiftMessage> {
+        let headers = self.parse_headers(raw_message)?;
+        let block4 = Self::extract_block(raw_message, 4)?;
+        let block5 = Self::extract_block(raw_message, 5)?;
+
+        Ok(ParsedSwiftMessage::Generic {
+            message_type: headers.application.message_type().to_string(),
+            headers: Box::new(headers),
+            trailer: block5
+                .map(|b| Trailer::parse(&b).map(Box::new))
+                .transpose()?,
+            fields: crate::parser::extract_all_fields(&block4.unwrap_or_default()),
+        })
+    }
+
+    /// Parse an RJE (Remote Job Entry) file: FIN messages separated by `$` lines
+    ///
+    /// The separator must stand on its own line; empty segments (e.g. a leading or trailing
+    /// `$`) are skipped. Fails on the first message that does not parse.
+    pub fn parse_rje(&self, raw: &str) -> Result<Vec<ParsedSwiftMessage>> {
+        let mut messages = Vec::new();
+        let mut segment = String::new();
+        for line in raw.split_inclusive('\n').chain(std::iter::once("$")) {
+            if line.trim() != "$" {
+                segment.push_str(line);
+                continue;
+            }
+            if !segment.trim().is_empty() {
+                messages.push(self.parse_message_auto(segment.trim())?);
+            }
+            segment.clear();
+        }
+        Ok(messages)
+    }
+
+    /// Parse and validate every message in a file, collecting failures instead of stopping
+    ///
+    /// The file is read in chunks through a [`SwiftStreamParser`], so pending input is
+    /// bounded by `max_stream_buffer` rather than the file size; unterminated input beyond
+    /// it is reported as an error. Each message that parses is checked with [`ParsedSwiftMessage::validate`],
+    /// and a failed check is recorded as [`ParseError::ValidationFailed`]. Only an
+    /// unreadable file returns an error.
+    pub fn validate_file(&self, path: impl AsRef<std::path::Path>) -> Result<BatchReport> {
+        use std::io::BufRead;
+
+        let mut reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        let mut stream = SwiftStreamParser::with_config(self.config.clone());
+        let mut report = BatchReport::default();
+        let mut record = |result: Result<ParsedSwiftMessage>| {
+            let index = report.total;
+            report.total += 1;
+            match result.map(|message| message.validate()) {
+                Ok(validation) if validation.is_valid => report.valid += 1,
+                Ok(validation) => report.errors.push((
+                    index,
+                    ParseError::ValidationFailed {
+                        errors: validation.errors,
+                    },
+                )),
+                Err(error) => report.errors.push((index, error)),
+            }
+        };
+
+        loop {
+            let chunk = reader.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+            let len = chunk.len();
+            stream.feed(chunk).into_iter().for_each(&mut record);
+            reader.consume(len);
+        }
+        stream.finish().into_iter().for_each(&mut record);
+        Ok(report)
+    }
+
+    /// Parse as much of a message as possible, returning it as JSON with the errors found
+    ///
+    /// A field that fails to parse is dropped and the message re-parsed, one field at a
+    /// time. The JSON is the typed message when the remaining fields still form one, and
+    /// the generic layout of [`ParsedSwiftMessage::Generic`] with the surviving fields
+    /// otherwise (e.g. a mandatory field was dropped). It is an empty object only when the
+    /// headers cannot be parsed.
+    pub fn parse_partial_json(&self, raw_message: &str) -> (serde_json::Value, Vec<ParseError>) {
+        let mut errors = Vec::new();
+        let empty = serde_json::Value::Object(Default::default());
+
+        let headers = match self.parse_headers(raw_message) {
+            Ok(headers) => headers,
+            Err(e) => {
+                errors.push(e);
+                return (empty, errors);
+            }
+        };
+        let mut fields = match Self::extract_block(raw_message, 4) {
+            Ok(block4) => crate::parser::extract_all_fields(&block4.unwrap_or_default()),
+            Err(e) => {
+                errors.push(e);
+                Vec::new()
+            }
+        };
+        let trailer = Self::extract_block(raw_message, 5)
+            .and_then(|block5| block5.map(|b| Trailer::parse(&b).map(Box::new)).transpose())
+            .unwrap_or_else(|e| {
+                errors.push(e);
+                None
+            });
+
+        loop {
+            let candidate = ParsedSwiftMessage::Generic {
+                message_type: headers.application.message_type().to_string(),
+                headers: Box::new(headers.clone()),
+                trailer: trailer.clone(),
+                fields: fields.clone(),
+            };
+            let error = match self.parse_message_auto(&candidate.to_mt_message()) {
+                Ok(parsed) => return (serde_json::to_value(parsed).unwrap_or(empty), errors),
+                Err(e) => e,
+            };
+
+            // Drop the occurrence that failed: matched on its value, or on the tag alone when
+            // the error carries no value and the tag occurs only once
+            let failed = match &error {
+                ParseError::InvalidFieldFormat(e) => {
+                    Some((e.field_tag.clone(), Some(e.value.clone())))
+                }
+                ParseError::FieldParsingFailed { field_tag, .. }
+                | ParseError::ComponentParseError { field_tag, .. } => {
+                    Some((field_tag.clone(), None))
+                }
+                _ => None,
+            }
+            .and_then(|(tag, value)| {
+                let mut occurrences = fields.iter().enumerate().filter(|(_, (field, _))| {
+                    *field == tag || crate::parser::extract_base_tag(field) == tag
+                });
+                match value {
+                    Some(value) => occurrences
+                        .find(|(_, (_, content))| content.trim() == value.trim())
+                        .map(|(index, _)| index),
+                    None => match (occurrences.next(), occurrences.next()) {
+                        (Some((index, _)), None) => Some(index),
+                        _ => None,
+                    },
+                }
+            });
+            errors.push(error);
+
+            match failed {
+                Some(index) => {
+                    fields.remove(index);
+                }
+                None => return (serde_json::to_value(candidate).unwrap_or(empty), errors),
+            }
+        }
+    }
+
     /// Extract a specific message block from raw SWIFT message with SWIFT validation
     pub fn extract_block(raw_message: &str, block_index: u8) -> Result<Option<String>> {
         // Validate block index using SWIFT error codes
@@ -1252,3 +1721,472 @@ where
 
     Ok(sequences)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::SwiftField;
+    use chrono::Datelike;
+
+    const SPACED_MESSAGE: &str = "{1:F01BANKDEFFAXXX0123456789} {2:I103BANKDEFFAXXXU3003}\t{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+
+    #[test]
+    fn test_lenient_block1_length() {
+        let message = |block1: &str| {
+            format!(
+                "{{1:{block1}}}{{2:I103BANKUS33XXXXN}}{{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}}"
+            )
+        };
+        let lenient = SwiftParser::with_config(ParserConfig {
+            lenient_block1_length: true,
+            ..Default::default()
+        });
+
+        for block1 in [
+            "F01DEUTDEFF0000123456",
+            "F01DEUTDEFFAXXX0000123456",
+            "F01DEUTDEFF500AXXX0000123456",
+        ] {
+            let strict = SwiftParser::parse_auto(&message(block1));
+            assert_eq!(strict.is_ok(), block1.len() == 25, "strict, {block1}");
+            let parsed = lenient.parse_message_auto(&message(block1)).unwrap();
+            assert_eq!(parsed.headers().basic.logical_terminal.len(), 12);
+        }
+    }
+
+    #[test]
+    fn test_parse_partial_json() {
+        let message = |field_70: &str, field_32a: &str| {
+            format!(
+                "{{1:F01BANKDEFFAXXX0123456789}}{{2:I103BANKUS33XXXXN}}{{4:\n:20:TXN123456\n:23B:CRED\n:32A:{field_32a}\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:70:{field_70}\n:71A:SHA\n-}}"
+            )
+        };
+        let parser = SwiftParser::new();
+
+        // A bad optional field is dropped and the rest still parses as a typed MT103
+        let long_line = "X".repeat(40);
+        let (json, errors) = parser.parse_partial_json(&message(&long_line, "240315USD1000,00"));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(json["fields"]["32A"]["currency"], "USD");
+        assert!(json["fields"]["70"].is_null());
+
+        // A bad mandatory field leaves the good fields in the generic layout
+        let (json, errors) = parser.parse_partial_json(&message("INVOICE 42", "240315USD-1000"));
+        assert!(!errors.is_empty());
+        assert_eq!(json["message_type"], "103");
+        let tags: Vec<&str> = json["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field[0].as_str().unwrap())
+            .collect();
+        assert_eq!(tags, vec!["20", "23B", "50K", "59", "70", "71A"]);
+
+        // Only the failing occurrence of a repeated field is dropped
+        let transaction = |reference: &str, amount: &str| {
+            format!(
+                ":21:{reference}\n:32B:{amount}\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n"
+            )
+        };
+        let raw = format!(
+            "{{1:F01BANKDEFFAXXX0123456789}}{{2:I101BANKUS33XXXXN}}{{4:\n:20:REQ001\n:28D:1/1\n:30:240315\n{}{}-}}",
+            transaction("TXN1", "EUR100,00"),
+            transaction("TXN2", "EUR-5")
+        );
+        let (json, errors) = parser.parse_partial_json(&raw);
+        assert!(!errors.is_empty());
+        let amounts: Vec<&serde_json::Value> = json["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|field| field[0] == "32B")
+            .map(|field| &field[1])
+            .collect();
+        assert_eq!(amounts, vec!["EUR100,00"]);
+
+        let (json, errors) = parser.parse_partial_json("{1:BROKEN}");
+        assert_eq!(json, serde_json::json!({}));
+        assert_eq!(errors.len(), 1);
+    }
+
+    fn lenient_parser() -> SwiftParser {
+        SwiftParser::with_config(ParserConfig {
+            lenient_block_format: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_inter_block_whitespace() {
+        let error = SwiftParser::new()
+            .parse_message::<MT103>(SPACED_MESSAGE)
+            .unwrap_err();
+        match error {
+            ParseError::InvalidBlockStructure {
+                block,
+                message,
+                offset,
+                ..
+            } => {
+                assert_eq!(block, "1");
+                assert_eq!(offset, Some(29));
+                assert!(message.contains("position 29"));
+            }
+            other => panic!("Expected InvalidBlockStructure, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_metrics_hook() {
+        use crate::errors::ParseMetrics;
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        #[derive(Debug, Default)]
+        struct Recorder {
+            parses: Mutex<Vec<(String, usize)>>,
+            failures: Mutex<usize>,
+        }
+
+        impl ParseMetrics for Recorder {
+            fn record_parse(&self, message_type: &str, field_count: usize, _duration: Duration) {
+                self.parses
+                    .lock()
+                    .unwrap()
+                    .push((message_type.to_string(), field_count));
+            }
+
+            fn record_failure(&self, _duration: Duration) {
+                *self.failures.lock().unwrap() += 1;
+            }
+        }
+
+        let recorder = Arc::new(Recorder::default());
+        let parser =
+            SwiftParser::with_config(ParserConfig::default().with_metrics(recorder.clone()));
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:REF\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:JANE ROE\n:71A:SHA\n-}";
+
+        parser.parse_message_auto(raw).unwrap();
+        assert_eq!(
+            *recorder.parses.lock().unwrap(),
+            vec![("103".to_string(), 6)]
+        );
+
+        assert!(parser.parse_message::<MT103>("{1:broken").is_err());
+        assert_eq!(*recorder.failures.lock().unwrap(), 1);
+        assert_eq!(recorder.parses.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_block_structure_errors() {
+        let message = "{1:F01BANKBEBBAXXX0000000000}{2:I103BANKDEFFXXXXN}{4:\r\n:20:REF\r\n";
+        match SwiftParser::parse::<MT103>(message).unwrap_err() {
+            ParseError::InvalidBlockStructure {
+                block,
+                reason,
+                offset,
+                ..
+            } => {
+                assert_eq!(block, "4");
+                assert_eq!(reason, BlockErrorReason::MissingTerminator);
+                assert_eq!(offset, Some(50));
+            }
+            other => panic!("Expected InvalidBlockStructure, got {other:?}"),
+        }
+
+        let message = "{1:F01BANKBEBBAXXX0000000000}{7:X}{4:\r\n:20:REF\r\n-}";
+        match SwiftParser::parse::<MT103>(message).unwrap_err() {
+            ParseError::InvalidBlockStructure {
+                block,
+                reason,
+                offset,
+                ..
+            } => {
+                assert_eq!(block, "7");
+                assert_eq!(reason, BlockErrorReason::UnexpectedBlockId);
+                assert_eq!(offset, Some(29));
+            }
+            other => panic!("Expected InvalidBlockStructure, got {other:?}"),
+        }
+
+        let message = "{1:F01BANKBEBBAXXX0000000000}X{2:I103BANKDEFFXXXXN}";
+        assert!(matches!(
+            SwiftParser::parse::<MT103>(message).unwrap_err(),
+            ParseError::InvalidBlockStructure {
+                reason: BlockErrorReason::MissingOpenBrace,
+                offset: Some(29),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_strict_mode_allows_line_breaks_between_blocks() {
+        let message = SPACED_MESSAGE
+            .replace("} {", "}\r\n{")
+            .replace("}\t{", "}\n{");
+        assert!(SwiftParser::parse::<MT103>(&message).is_ok());
+    }
+
+    #[test]
+    fn test_lenient_mode_normalizes_whitespace_and_lowercase_headers() {
+        let parsed = lenient_parser()
+            .parse_message::<MT103>(SPACED_MESSAGE)
+            .unwrap();
+        assert_eq!(parsed.fields.field_20.reference, "TXN123456");
+
+        let lowercase = SPACED_MESSAGE
+            .replace("{1:F01BANKDEFFAXXX", "{1:f01bankdeffaxxx")
+            .replace("{2:I103BANKDEFFAXXX", "{2:i103bankdeffaxxx");
+        let parsed = lenient_parser().parse_message_auto(&lowercase).unwrap();
+        let mt103 = parsed.as_mt103().unwrap();
+        assert_eq!(mt103.basic_header.logical_terminal, "BANKDEFFAXXX");
+        assert_eq!(mt103.application_header.message_type(), "103");
+    }
+
+    #[test]
+    fn test_field_errors_carry_swift_codes() {
+        let message = |field_32a: &str| {
+            format!(
+                "{{1:F01BANKDEFFAXXX0123456789}}{{2:I103BANKDEFFAXXXU3003}}{{4:\n:20:TXN123456\n:23B:CRED\n:32A:{field_32a}\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}}"
+            )
+        };
+
+        let bad_amount = SwiftParser::parse::<MT103>(&message("240315USD1O0,00")).unwrap_err();
+        assert_eq!(bad_amount.swift_code(), Some("T40"));
+
+        let bad_date = SwiftParser::parse::<MT103>(&message("240230USD100,00")).unwrap_err();
+        assert_eq!(bad_date.swift_code(), Some("T50"));
+
+        let mut report = crate::ValidationReport::new("103");
+        report.push_parse_error(bad_date);
+        assert_eq!(report.errors[0].code(), "T50");
+        assert!(report.errors[0].to_string().contains("32A"));
+    }
+
+    #[test]
+    fn test_sanitize_text() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:REF\n:23B:CRED\n:32A:240315USD1000,00\n:50K:Jos\u{e9} M\u{fc}ller\n:59:/DE89370400440532013000\nBen\u{2019}s \u{201C}Caf\u{e9}\u{201D}\n:71A:SHA\n-}";
+        assert!(SwiftParser::parse::<MT103>(message).is_err());
+
+        let parser = SwiftParser::with_config(ParserConfig {
+            sanitize_text: true,
+            ..Default::default()
+        });
+        let parsed = parser.parse_message::<MT103>(message).unwrap();
+        let mt = parsed.fields.to_mt_string();
+        assert!(mt.contains(":50K:JosE MUller"), "{mt}");
+        assert!(mt.contains("Ben's 'CafE'"), "{mt}");
+    }
+
+    #[test]
+    fn test_strip_thousands_separators() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:REF\n:23B:CRED\n:32A:240315USD1'234,56\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nBENEFICIARY\n:71A:SHA\n-}";
+        assert!(SwiftParser::parse::<MT103>(message).is_err());
+
+        let parser = SwiftParser::with_config(ParserConfig {
+            strip_thousands_separators: true,
+            ..Default::default()
+        });
+        let parsed = parser.parse_message::<MT103>(message).unwrap();
+        assert_eq!(parsed.fields.field_32a.amount, 1234.56);
+    }
+
+    #[test]
+    fn test_trim_fixed_fields() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:REF\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:52A:DEUTDEFF   \n:59:/DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let err = SwiftParser::parse::<MT103>(message).unwrap_err();
+        assert!(
+            format!("{err:?}").contains("padded with 3 trailing space(s)"),
+            "{err:?}"
+        );
+
+        let parser = SwiftParser::with_config(ParserConfig {
+            trim_fixed_fields: true,
+            ..Default::default()
+        });
+        let parsed = parser.parse_message::<MT103>(message).unwrap();
+        let mt = parsed.fields.to_mt_string();
+        assert!(mt.lines().any(|line| line == ":52A:DEUTDEFF"), "{mt}");
+    }
+
+    #[test]
+    fn test_missing_mandatory_policy_warn() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\n:20:REF\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:/DE89370400440532013000\nBENEFICIARY\n:71A:OUR\n-}";
+        assert!(matches!(
+            SwiftParser::parse::<MT103>(message),
+            Err(ParseError::MissingRequiredField { .. })
+        ));
+
+        let parser = SwiftParser::with_config(ParserConfig {
+            missing_mandatory_policy: crate::MissingMandatoryPolicy::Warn,
+            ..Default::default()
+        });
+        let crate::ParseResult::PartialSuccess(parsed, warnings) =
+            parser.parse_with_errors::<MT103>(message).unwrap()
+        else {
+            panic!("expected a partial success");
+        };
+        assert_eq!(parsed.fields.field_23b.instruction_code, "");
+        assert_eq!(
+            parsed.fields.field_71a.code,
+            crate::fields::ChargeBearer::Our
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            &warnings[0],
+            ParseError::MissingRequiredField { field_tag, .. } if field_tag == "23B"
+        ));
+
+        // Variant fields are parsed empty as well
+        let without_59 = message.replace(":59:/DE89370400440532013000\nBENEFICIARY\n", "");
+        let crate::ParseResult::PartialSuccess(parsed, warnings) =
+            parser.parse_with_errors::<MT103>(&without_59).unwrap()
+        else {
+            panic!("expected a partial success");
+        };
+        assert!(matches!(
+            &parsed.fields.field_59,
+            crate::fields::Field59::NoOption(field) if field.name_and_address.is_empty()
+        ));
+        assert_eq!(warnings.len(), 2);
+
+        // A code cannot be empty, so a missing 71A still fails
+        assert!(matches!(
+            parser.parse_with_errors::<MT103>(&message.replace(":71A:OUR\n", "")),
+            Err(ParseError::MissingRequiredField { field_tag, .. }) if field_tag == "71A"
+        ));
+
+        // Entry points without warnings report the omission instead of an empty field
+        assert!(matches!(
+            parser.parse_message::<MT103>(message),
+            Err(ParseError::MissingRequiredField { field_tag, .. }) if field_tag == "23B"
+        ));
+        assert!(matches!(
+            parser.parse_message_auto(message),
+            Err(ParseError::MissingRequiredField { field_tag, .. }) if field_tag == "23B"
+        ));
+
+        // A complete message is still a plain success, with nothing left over from earlier parses
+        let complete = message.replace(":32A:", ":23B:SPRI\n:32A:");
+        assert!(matches!(
+            parser.parse_with_errors::<MT103>(&complete).unwrap(),
+            crate::ParseResult::Success(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_spans() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXN}{4:\r\n:20:REF\r\n:23B:CRED\r\n:32A:240315USD1000,00\r\n:50K:JOHN DOE\r\n1 MAIN ST\r\n:59:/DE89370400440532013000\r\nBENEFICIARY\r\n:71A:SHA\r\n-}{5:{CHK:123456789ABC}}";
+        let (message, spans) = SwiftParser::new().parse_with_spans(raw).unwrap();
+        assert_eq!(message.message_type(), "103");
+
+        let tags: Vec<&str> = spans.iter().map(|span| span.tag.as_str()).collect();
+        assert_eq!(tags, vec!["20", "23B", "32A", "50K", "59", "71A"]);
+
+        let span_32a = &spans[2];
+        assert_eq!(span_32a.start, raw.find(":32A:").unwrap());
+        assert_eq!(&raw[span_32a.start..span_32a.end], ":32A:240315USD1000,00");
+        assert_eq!(
+            &raw[spans[3].start..spans[3].end],
+            ":50K:JOHN DOE\r\n1 MAIN ST"
+        );
+        assert_eq!(&raw[spans[5].start..spans[5].end], ":71A:SHA");
+    }
+
+    #[test]
+    fn test_retain_raw_fields_preserves_original_text() {
+        let message = SPACED_MESSAGE
+            .replace("} {", "}\n{")
+            .replace("}\t{", "}\n{")
+            .replace(
+                ":32A:240315USD1000,00",
+                ":32A:240315USD1000,00\n:33B:EUR926,5\n:36:1234,50",
+            );
+        let parser = SwiftParser::with_config(ParserConfig {
+            retain_raw_fields: true,
+            ..Default::default()
+        });
+
+        let retained = parser.parse_message::<MT103>(&message).unwrap();
+        let field_36 = retained.fields.field_36.as_ref().unwrap();
+        assert_eq!(field_36.raw(), Some("1234,50"));
+        assert_eq!(field_36.to_swift_string(), ":36:1234,50");
+        assert!(retained.fields.to_mt_string().contains(":33B:EUR926,5\r\n"));
+
+        // Without the flag the typed value is re-formatted
+        let parsed = SwiftParser::parse::<MT103>(&message).unwrap();
+        assert_eq!(
+            parsed.fields.field_36.as_ref().unwrap().to_swift_string(),
+            ":36:1234,5"
+        );
+
+        // Retained text does not take part in equality
+        assert_eq!(parsed.fields, retained.fields);
+
+        // A changed value no longer matches its raw text
+        let mut field_36 = parser
+            .parse_message::<MT103>(&message)
+            .unwrap()
+            .fields
+            .field_36
+            .unwrap();
+        field_36.rate = 1.5;
+        assert_eq!(field_36.raw(), None);
+        assert_eq!(field_36.to_swift_string(), ":36:1,5");
+    }
+
+    #[test]
+    fn test_date_pivot_year_applies_to_block4_dates() {
+        let message = SPACED_MESSAGE
+            .replace("} {", "}\n{")
+            .replace("}\t{", "}\n{")
+            .replace(":32A:240315", ":32A:790315");
+
+        let parsed = SwiftParser::parse::<MT103>(&message).unwrap();
+        assert_eq!(parsed.fields.field_32a.value_date.year(), 1979);
+
+        let parser = SwiftParser::with_config(ParserConfig {
+            date_pivot_year: 80,
+            ..Default::default()
+        });
+        let parsed = parser.parse_message::<MT103>(&message).unwrap();
+        assert_eq!(parsed.fields.field_32a.value_date.year(), 2079);
+    }
+
+    #[test]
+    fn test_parse_headers_skips_block4() {
+        let message = "{1:F01BANKDEFFAXXX0123456789}{2:I103CHASUS33XXXXN}{3:{121:eb6305c9-1f7f-49de-aed0-16487c27b42d}}{4:\n:20:TXN123456\n:32A:NOT A VALID FIELD";
+
+        // Block 4 is unterminated and invalid, so a full parse fails
+        assert!(SwiftParser::parse_auto(message).is_err());
+
+        let headers = SwiftParser::new().parse_headers(message).unwrap();
+        assert_eq!(headers.sender_bic(), "BANKDEFF");
+        assert_eq!(headers.receiver_bic(), "CHASUS33");
+        assert_eq!(headers.application.message_type(), "103");
+        assert_eq!(headers.uetr(), Some("eb6305c9-1f7f-49de-aed0-16487c27b42d"));
+
+        // Block 3 is optional
+        let headers = SwiftParser::new()
+            .parse_headers("{1:F01BANKDEFFAXXX0123456789}{2:I103CHASUS33XXXXN}{4:\n-}")
+            .unwrap();
+        assert!(headers.user.is_none());
+        assert_eq!(headers.uetr(), None);
+
+        // Repeated header parsing over a large block 4 stays independent of its size
+        let large = format!(
+            "{{1:F01BANKDEFFAXXX0123456789}}{{2:I103CHASUS33XXXXN}}{{4:\n{}-}}",
+            ":70:REMITTANCE\n".repeat(10_000)
+        );
+        for _ in 0..100 {
+            assert_eq!(
+                SwiftParser::new()
+                    .parse_headers(&large)
+                    .unwrap()
+                    .sender_bic(),
+                "BANKDEFF"
+            );
+        }
+    }
+}
@@ -230,6 +230,7 @@ impl Validate {
             ParsedSwiftMessage::MT941(msg) => msg.fields.validate_network_rules(false),
             ParsedSwiftMessage::MT942(msg) => msg.fields.validate_network_rules(false),
             ParsedSwiftMessage::MT950(msg) => msg.fields.validate_network_rules(false),
+            ParsedSwiftMessage::Generic { .. } => Vec::new(),
         }
     }
 
@@ -3,13 +3,37 @@
 //! Complete SWIFT message with headers (Blocks 1-3, 5) and typed message body (Block 4).
 
 use crate::{
-    ValidationError, ValidationResult,
-    headers::{ApplicationHeader, BasicHeader, Trailer, UserHeader},
+    ParseError, ValidationError, ValidationResult,
+    headers::{ApplicationHeader, BasicHeader, MessageHeaders, Trailer, UserHeader},
     traits::SwiftMessageBody,
 };
 use serde::{Deserialize, Serialize};
 use std::any::Any;
 
+/// Base tags of option-letter fields that are flattened to their full tag (e.g. "50K") in JSON
+const OPTION_FIELD_TAGS: &[&str] = &["32", "50", "52", "53", "54", "55", "56", "57", "58", "59"];
+
+/// JSON layout used by [`SwiftMessage::to_json`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SerializationMode {
+    /// Default serde output: option-letter fields keyed by full tag, e.g. `{"50K": {...}}`
+    #[default]
+    Flat,
+    /// Option-letter fields nested under base tag and variant, e.g. `{"50": {"K": {...}}}`
+    ///
+    /// Fields without an option letter (e.g. `59`) use the variant key `NoOption`.
+    /// Read it back with `ParsedSwiftMessage::from_json_tagged`.
+    Tagged,
+}
+
+/// Options for FIN serialization with [`SwiftMessage::to_mt_message_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SerializeOptions {
+    /// If true, fixed-length header and field values that do not fit their format are
+    /// rejected instead of being truncated or written unchanged (default: false)
+    pub validate_before_serialize: bool,
+}
+
 /// Complete SWIFT message (headers + typed body)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
@@ -120,9 +144,16 @@ impl<T: SwiftMessageBody> SwiftMessage<T> {
     }
 
     /// Validate message using SWIFT SR2025 network validation rules
+    ///
+    /// A malformed UETR in block 3 is reported first (see [`UserHeader::validate_uetr`]).
     pub fn validate(&self) -> ValidationResult {
         // Use the new validate_network_rules method
-        let validation_errors = self.fields.validate_network_rules(false);
+        let validation_errors = self
+            .user_header
+            .as_ref()
+            .and_then(UserHeader::validate_uetr)
+            .into_iter()
+            .chain(self.fields.validate_network_rules(false));
 
         // Convert SwiftValidationError to ValidationError for backward compatibility
         let errors: Vec<ValidationError> = validation_errors
@@ -143,49 +174,731 @@ impl<T: SwiftMessageBody> SwiftMessage<T> {
         }
     }
 
+    /// Stamp a UETR (block 3 tag 121) on the message if it does not carry one yet
+    ///
+    /// Creates the user header when absent. An existing UETR is never overwritten.
+    /// Returns the UETR now on the message.
+    pub fn ensure_uetr(&mut self) -> &str {
+        let user_header = self.user_header.get_or_insert_with(UserHeader::default);
+        user_header
+            .unique_end_to_end_reference
+            .get_or_insert_with(crate::utils::generate_uetr)
+    }
+
+    /// Blocks 1-3 of the message
+    pub fn headers(&self) -> MessageHeaders {
+        MessageHeaders {
+            basic: self.basic_header.clone(),
+            application: self.application_header.clone(),
+            user: self.user_header.clone(),
+        }
+    }
+
+    /// Sanity-check the sender and receiver BICs of blocks 1 and 2
+    ///
+    /// See [`MessageHeaders::validate_routing`].
+    pub fn validate_routing(&self) -> ValidationResult {
+        self.headers().validate_routing()
+    }
+
+    /// Copy blocks 1-3 from `other`, keeping this message's type in block 2
+    pub fn with_headers_from<U: SwiftMessageBody>(&mut self, other: &SwiftMessage<U>) {
+        self.basic_header = other.basic_header.clone();
+        self.application_header = other.application_header.clone();
+        match &mut self.application_header {
+            ApplicationHeader::Input(header) => header.message_type = self.message_type.clone(),
+            ApplicationHeader::Output(header) => header.message_type = self.message_type.clone(),
+        }
+        self.user_header = other.user_header.clone();
+    }
+
+    /// Set blocks 1-3 for a reply to `other`: an input message from its receiver to its sender
+    ///
+    /// Priority and block 3 are carried over; session and sequence numbers are reset to zero.
+    pub fn reply_headers_from<U: SwiftMessageBody>(
+        &mut self,
+        other: &SwiftMessage<U>,
+    ) -> crate::Result<()> {
+        let (own_terminal, counterparty_terminal) = match &other.application_header {
+            ApplicationHeader::Input(header) => (
+                header.destination_address.as_str(),
+                other.basic_header.logical_terminal.as_str(),
+            ),
+            ApplicationHeader::Output(header) => (
+                other.basic_header.logical_terminal.as_str(),
+                header.mir.lt_identifier.as_str(),
+            ),
+        };
+
+        self.basic_header = BasicHeader::parse(&format!(
+            "{}{}{}0000000000",
+            other.basic_header.application_id, other.basic_header.service_id, own_terminal
+        ))?;
+        self.application_header = ApplicationHeader::parse(&format!(
+            "I{}{}{}",
+            self.message_type,
+            counterparty_terminal,
+            other.application_header.priority().unwrap_or("N")
+        ))?;
+        self.user_header = other.user_header.clone();
+        Ok(())
+    }
+
+    /// Serialize the message to a JSON value in the given layout
+    pub fn to_json(&self, mode: SerializationMode) -> serde_json::Result<serde_json::Value> {
+        let mut value = serde_json::to_value(self)?;
+        if mode == SerializationMode::Tagged
+            && let Some(fields) = value.get_mut("fields")
+        {
+            tag_option_fields(fields);
+        }
+        Ok(value)
+    }
+
+    /// Serialize the message to JSON with option-letter fields nested by variant
+    pub fn to_json_tagged(&self) -> serde_json::Result<serde_json::Value> {
+        self.to_json(SerializationMode::Tagged)
+    }
+
     pub fn to_mt_message(&self) -> String {
         // Pre-allocate capacity based on typical message size
         // Headers ~200 chars + typical message body ~2000 chars
-        let mut swift_message = String::with_capacity(2200);
+        let mut buffer = String::with_capacity(2200);
+        self.write_blocks(&mut buffer)
+            .expect("writing to a String cannot fail");
+        buffer
+    }
+
+    /// Serialize to FIN format with block 4 in SWIFT network field order
+    ///
+    /// Fields are ordered within each sequence occurrence (see
+    /// [`sequence_occurrences`](crate::traits::sequence_occurrences)), so the fields of
+    /// one MT101 transaction never move into another. Inside an occurrence they are
+    /// stably sorted by spec position: repeated fields keep their relative order and
+    /// tags without a spec go last. Fails for message types without `field_specs()`,
+    /// since their network order is unknown.
+    pub fn to_fin_canonical(&self) -> crate::Result<String> {
+        let specs = T::field_specs();
+        if specs.is_empty() {
+            return Err(ParseError::SerializationError {
+                message: format!(
+                    "MT{} has no field specs, canonical field order is unknown",
+                    T::message_type()
+                ),
+            });
+        }
+
+        let fields = self.fields.to_fields_with_variants();
+        let tags: Vec<&str> = fields.iter().map(|(tag, _)| tag.as_str()).collect();
+        let mut block4_content = String::new();
+        for mut occurrence in crate::traits::sequence_occurrences(&specs, &tags) {
+            occurrence
+                .fields
+                .sort_by_key(|&(_, spec)| spec.unwrap_or(specs.len()));
+            for (index, _) in occurrence.fields {
+                let (tag, value) = &fields[index];
+                if !block4_content.is_empty() {
+                    block4_content.push('\n');
+                }
+                block4_content.push_str(&format!(":{tag}:{value}"));
+            }
+        }
+
+        let mut buffer = String::with_capacity(2200);
+        self.write_text_blocks_with(&mut buffer, &block4_content)
+            .expect("writing to a String cannot fail");
+        if let Some(ref trailer) = self.trailer {
+            buffer.push_str(&format!("{{5:{}}}\n", trailer));
+        }
+        Ok(buffer)
+    }
+
+    /// Serialize to FIN format, padding fixed-length values and optionally rejecting malformed ones
+    ///
+    /// Headers are padded as by [`to_mt_message`](Self::to_mt_message): numeric components
+    /// with zeros, alphanumeric ones with `X`. Block 4 values are then passed through
+    /// [`FieldSpec::pad_value`](crate::traits::FieldSpec::pad_value) for their spec in
+    /// `T::field_specs()`, which zero-pads short single-component numeric fields
+    /// (`3!n`: "94" -> "094"). With `validate_before_serialize`, header components that
+    /// would be truncated, fields that would be cut off (see
+    /// [`SwiftField::check_serialization`](crate::traits::SwiftField::check_serialization),
+    /// e.g. a field 72 wrapping past 6 lines) and field values that cannot be brought to
+    /// their format are errors instead of being written unchanged.
+    pub fn to_mt_message_with(&self, options: SerializeOptions) -> crate::Result<String> {
+        if options.validate_before_serialize {
+            self.basic_header.validate_padding()?;
+            self.application_header.validate_padding()?;
+            for view in self.fields.field_views() {
+                view.check_serialization()?;
+            }
+        }
+
+        let specs = T::field_specs();
+        let fields = self.fields.to_fields_with_variants();
+        let tags: Vec<&str> = fields.iter().map(|(tag, _)| tag.as_str()).collect();
+        let mut padded: Vec<std::borrow::Cow<'_, str>> = fields
+            .iter()
+            .map(|(_, value)| std::borrow::Cow::Borrowed(value.as_str()))
+            .collect();
+        for occurrence in crate::traits::sequence_occurrences(&specs, &tags) {
+            for (index, spec) in occurrence.fields {
+                let Some(spec) = spec.map(|spec| &specs[spec]) else {
+                    continue;
+                };
+                let (tag, value) = &fields[index];
+                match spec.pad_value(value) {
+                    Some(value) => padded[index] = value,
+                    None if options.validate_before_serialize => {
+                        return Err(ParseError::SerializationError {
+                            message: format!(
+                                "Field {} value '{}' does not fit format {}",
+                                tag, value, spec.format
+                            ),
+                        });
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        if padded
+            .iter()
+            .all(|value| matches!(value, std::borrow::Cow::Borrowed(_)))
+        {
+            return Ok(self.to_mt_message());
+        }
+
+        let block4_content = fields
+            .iter()
+            .zip(&padded)
+            .map(|((tag, _), value)| format!(":{tag}:{value}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut buffer = String::with_capacity(2200);
+        self.write_text_blocks_with(&mut buffer, &block4_content)
+            .expect("writing to a String cannot fail");
+        if let Some(ref trailer) = self.trailer {
+            buffer.push_str(&format!("{{5:{}}}\n", trailer));
+        }
+        Ok(buffer)
+    }
 
+    /// Stream the message in FIN format to any writer
+    ///
+    /// Produces exactly the same bytes as [`to_mt_message`](Self::to_mt_message)
+    /// without building the whole message in memory first.
+    pub fn write_fin<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        /// Forwards `fmt::Write` output to an `io::Write`, keeping the first I/O error
+        struct IoAdapter<'a, W> {
+            inner: &'a mut W,
+            error: Option<std::io::Error>,
+        }
+
+        impl<W: std::io::Write> core::fmt::Write for IoAdapter<'_, W> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.inner.write_all(s.as_bytes()).map_err(|e| {
+                    self.error = Some(e);
+                    core::fmt::Error
+                })
+            }
+        }
+
+        let mut adapter = IoAdapter {
+            inner: w,
+            error: None,
+        };
+        match self.write_blocks(&mut adapter) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(adapter
+                .error
+                .unwrap_or_else(|| std::io::Error::other("formatter error"))),
+        }
+    }
+
+    /// Write all blocks in FIN format; shared by [`to_mt_message`](Self::to_mt_message)
+    /// and [`write_fin`](Self::write_fin)
+    fn write_blocks<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        self.write_text_blocks(w)?;
+
+        // Block 5: Trailer (if present)
+        if let Some(ref trailer) = self.trailer {
+            writeln!(w, "{{5:{}}}", trailer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write blocks 1 to 4, the part of the message covered by the checksum
+    fn write_text_blocks<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        // Use the message type's to_mt_string() implementation
+        self.write_text_blocks_with(w, &self.fields.to_mt_string())
+    }
+
+    /// Write blocks 1 to 4 with the given block 4 content
+    fn write_text_blocks_with<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        block4_content: &str,
+    ) -> core::fmt::Result {
         // Block 1: Basic Header
-        let block1 = &self.basic_header.to_string();
-        swift_message.push_str(&format!("{{1:{block1}}}\n"));
+        writeln!(w, "{{1:{}}}", self.basic_header)?;
 
         // Block 2: Application Header
-        let block2 = &self.application_header.to_string();
-        swift_message.push_str(&format!("{{2:{block2}}}\n"));
+        writeln!(w, "{{2:{}}}", self.application_header)?;
 
         // Block 3: User Header (if present)
         if let Some(ref user_header) = self.user_header {
-            let block3 = &user_header.to_string();
-            swift_message.push_str(&format!("{{3:{block3}}}\n"));
+            writeln!(w, "{{3:{}}}", user_header)?;
         }
 
         // Block 4: Text Block with fields
-        // Use the message type's to_mt_string() implementation
-        let mut block4_content = self.fields.to_mt_string();
+        // Add leading newline if content doesn't already have one
+        w.write_str("{4:")?;
+        if !block4_content.starts_with('\n') && !block4_content.starts_with("\r\n") {
+            w.write_str("\n")?;
+        }
 
         // Convert \r\n to \n for consistency with existing format
-        if block4_content.contains("\r\n") {
-            block4_content = block4_content.replace("\r\n", "\n");
+        let mut lines = block4_content.split("\r\n");
+        if let Some(first) = lines.next() {
+            w.write_str(first)?;
+        }
+        for line in lines {
+            w.write_str("\n")?;
+            w.write_str(line)?;
         }
+        w.write_str("\n-}\n")
+    }
 
-        // Add leading newline if content doesn't already have one
-        let block4 = if block4_content.starts_with('\n') {
-            block4_content
-        } else {
-            format!("\n{}", block4_content)
+    /// Local integrity stamp over blocks 1 to 4, in the 12 hex digit CHK format
+    ///
+    /// This is not the SWIFT CHK: the network algorithm is not published, so this is a
+    /// CRC-64/XZ over the serialized FIN text truncated to its low 48 bits. Use it to stamp
+    /// and check messages within one system; it never matches a CHK assigned by SWIFT.
+    pub fn compute_local_checksum(&self) -> String {
+        let mut text = String::with_capacity(2200);
+        self.write_text_blocks(&mut text)
+            .expect("writing to a String cannot fail");
+        format!("{:012X}", crc64_xz(text.as_bytes()) & 0xFFFF_FFFF_FFFF)
+    }
+
+    /// Whether the trailer CHK holds this message's [`compute_local_checksum`](Self::compute_local_checksum)
+    ///
+    /// Only meaningful for messages stamped locally; a CHK set by the SWIFT network does not
+    /// verify. Messages without a trailer or CHK tag do not verify.
+    pub fn verify_local_checksum(&self) -> bool {
+        self.trailer
+            .as_ref()
+            .and_then(|trailer| trailer.checksum.as_deref())
+            .is_some_and(|checksum| checksum.eq_ignore_ascii_case(&self.compute_local_checksum()))
+    }
+}
+
+/// CRC-64/XZ (ECMA-182 polynomial, reflected, init and xorout all ones)
+fn crc64_xz(bytes: &[u8]) -> u64 {
+    const POLY: u64 = 0xC96C_5795_D787_0F42;
+    let mut crc = u64::MAX;
+    for &byte in bytes {
+        crc ^= u64::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLY
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Split a flattened key such as "50K" into ("50", "K"); bare "59" yields ("59", "NoOption")
+fn split_option_key(key: &str) -> Option<(&str, &str)> {
+    let base = OPTION_FIELD_TAGS
+        .iter()
+        .find(|base| key.starts_with(*base))?;
+    match &key[base.len()..] {
+        "" => Some((base, "NoOption")),
+        letter if letter.len() == 1 && letter.chars().all(|c| c.is_ascii_uppercase()) => {
+            Some((base, letter))
+        }
+        _ => None,
+    }
+}
+
+/// Rewrite flattened option-letter keys into `{base: {variant: value}}`, descending into sequences
+fn tag_option_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let entries = std::mem::take(map);
+            for (key, mut field) in entries {
+                match split_option_key(&key) {
+                    Some((base, variant)) => {
+                        let mut tagged = serde_json::Map::new();
+                        tagged.insert(variant.to_string(), field);
+                        map.insert(base.to_string(), serde_json::Value::Object(tagged));
+                    }
+                    None => {
+                        tag_option_fields(&mut field);
+                        map.insert(key, field);
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(tag_option_fields),
+        _ => {}
+    }
+}
+
+/// Whether `value` is a `{variant: field}` wrapper produced by [`SerializationMode::Tagged`]
+fn tagged_variant(value: &serde_json::Value) -> Option<&str> {
+    let map = value.as_object()?;
+    let (variant, field) = map.iter().next()?;
+    let is_variant = variant == "NoOption"
+        || (variant.len() == 1 && variant.chars().all(|c| c.is_ascii_uppercase()));
+    (map.len() == 1 && is_variant && field.is_object()).then_some(variant.as_str())
+}
+
+/// Inverse of [`tag_option_fields`]: flatten `{base: {variant: value}}` back to `{"50K": value}`
+///
+/// Keys already in the flattened layout are left alone, so either layout (or a mix) is accepted.
+pub(crate) fn untag_option_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let entries = std::mem::take(map);
+            for (key, mut field) in entries {
+                let variant = OPTION_FIELD_TAGS
+                    .contains(&key.as_str())
+                    .then(|| tagged_variant(&field).map(str::to_string))
+                    .flatten();
+                match variant {
+                    Some(variant) => {
+                        let inner = field
+                            .as_object_mut()
+                            .and_then(|wrapper| wrapper.remove(&variant))
+                            .unwrap_or_default();
+                        let flat_key = if variant == "NoOption" {
+                            key
+                        } else {
+                            format!("{key}{variant}")
+                        };
+                        map.insert(flat_key, inner);
+                    }
+                    None => {
+                        untag_option_fields(&mut field);
+                        map.insert(key, field);
+                    }
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(untag_option_fields),
+        _ => {}
+    }
+}
+
+/// Write messages as an RJE (Remote Job Entry) file
+///
+/// Each message is written in FIN format, with a `$` line between consecutive messages.
+/// Read the file back with [`SwiftParser::parse_rje`](crate::SwiftParser::parse_rje).
+pub fn write_rje<T: SwiftMessageBody, W: std::io::Write>(
+    messages: &[SwiftMessage<T>],
+    w: &mut W,
+) -> std::io::Result<()> {
+    for (index, message) in messages.iter().enumerate() {
+        if index > 0 {
+            w.write_all(b"$\n")?;
+        }
+        message.write_fin(w)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UserHeader;
+    use crate::messages::{MT103, MT199};
+    use crate::parser::SwiftParser;
+    use crate::swift_message::{SerializationMode, SerializeOptions, write_rje};
+    use serde_json::json;
+
+    #[test]
+    fn test_ensure_uetr() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+
+        let mut message = SwiftParser::parse::<MT103>(raw).unwrap();
+        assert!(message.user_header.is_none());
+        let uetr = message.ensure_uetr().to_string();
+        assert!(UserHeader::is_valid_uetr(&uetr), "{uetr}");
+        assert!(message.to_mt_message().contains(&format!("{{121:{uetr}}}")));
+
+        let existing = "eb6305c9-1f7f-49de-aed0-16487c27b42d";
+        let with_uetr = raw.replace("U3003}", &format!("U3003}}{{3:{{121:{existing}}}}}"));
+        let mut message = SwiftParser::parse::<MT103>(&with_uetr).unwrap();
+        assert_eq!(message.ensure_uetr(), existing);
+    }
+
+    #[test]
+    fn test_validate_reports_uppercase_uetr() {
+        let uetr = "EB6305C9-1F7F-49DE-AED0-16487C27B42D";
+        let raw = format!(
+            "{{1:F01BANKDEFFAXXX0123456789}}{{2:I103BANKDEFFAXXXU3003}}{{3:{{121:{uetr}}}}}{{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}}"
+        );
+
+        let message = SwiftParser::parse::<MT103>(&raw).unwrap();
+        assert_eq!(message.headers().uetr(), Some(uetr));
+        let result = message.validate();
+        assert!(!result.is_valid);
+        assert!(matches!(
+            &result.errors[..],
+            [crate::ValidationError::BusinessRuleValidation { rule_name, .. }] if rule_name == "T04"
+        ));
+    }
+
+    #[test]
+    fn test_with_headers_from_and_reply_headers_from() {
+        let inbound = SwiftParser::parse::<MT103>("{1:F01BANKDEFFAXXX0123456789}{2:I103BANKUS33XXXXU}{3:{108:MUR123}}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}").unwrap();
+        let raw_199 = "{1:F01OTHRGB22AXXX1111222222}{2:I199OTHRFRPPXXXXN}{4:\n:20:REPLY1\n:21:TXN123456\n:79:RECEIVED\n-}";
+
+        let mut copy = SwiftParser::parse::<MT199>(raw_199).unwrap();
+        copy.with_headers_from(&inbound);
+        assert_eq!(copy.basic_header, inbound.basic_header);
+        assert_eq!(copy.user_header, inbound.user_header);
+        assert_eq!(copy.application_header.message_type(), "199");
+        assert_eq!(copy.headers().receiver_bic(), "BANKUS33");
+
+        let mut reply = SwiftParser::parse::<MT199>(raw_199).unwrap();
+        reply.reply_headers_from(&inbound).unwrap();
+        let headers = reply.headers();
+        assert_eq!(headers.sender_bic(), "BANKUS33");
+        assert_eq!(headers.receiver_bic(), "BANKDEFF");
+        assert_eq!(reply.application_header.priority(), Some("U"));
+        assert_eq!(reply.basic_header.sequence_number, "000000");
+        assert!(
+            reply
+                .to_mt_message()
+                .starts_with("{1:F01BANKUS33XXXX0000000000}\n{2:I199BANKDEFFAXXXU}")
+        );
+    }
+
+    #[test]
+    fn test_to_mt_message_with_padding_validation() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let strict = SerializeOptions {
+            validate_before_serialize: true,
         };
 
-        swift_message.push_str(&format!("{{4:{block4}\n-}}\n"));
+        let mut message = SwiftParser::parse::<MT103>(raw).unwrap();
+        message.basic_header.session_number = "123".to_string();
+        message.basic_header.sequence_number = "42".to_string();
+        let fin = message.to_mt_message_with(strict).unwrap();
+        assert!(fin.starts_with("{1:F01BANKDEFFAXXX0123000042}"), "{fin}");
+        assert_eq!(fin, message.to_mt_message());
 
-        // Block 5: Trailer (if present)
-        if let Some(ref trailer) = self.trailer {
-            let block5 = &trailer.to_string();
-            swift_message.push_str(&format!("{{5:{block5}}}\n"));
+        message.basic_header.session_number = "12345".to_string();
+        let err = message.to_mt_message_with(strict).unwrap_err();
+        assert!(err.to_string().contains("Session number '12345'"), "{err}");
+        // Default options keep the lenient pad/truncate behaviour
+        assert!(
+            message
+                .to_mt_message_with(SerializeOptions::default())
+                .unwrap()
+                .starts_with("{1:F01BANKDEFFAXXX1234000042}")
+        );
+
+        let mut message = SwiftParser::parse::<MT103>(raw).unwrap();
+        message.application_header = crate::ApplicationHeader::parse("I103BANKDEFFAXXXU").unwrap();
+        if let crate::ApplicationHeader::Input(header) = &mut message.application_header {
+            header.destination_address = "BANKDEFFAXXXX".to_string();
         }
+        assert!(message.to_mt_message_with(strict).is_err());
+    }
+
+    #[test]
+    fn test_to_mt_message_with_field_padding() {
+        use crate::messages::mt920::MT920;
+
+        let strict = SerializeOptions {
+            validate_before_serialize: true,
+        };
+
+        // A short 3!n message type is zero-padded from the field spec
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I920BANKDEFFAXXXN}{4:\n:20:REQ123456\n:12:940\n:25:/GB12ABCD12345678901234\n-}";
+        let mut message = SwiftParser::parse::<MT920>(raw).unwrap();
+        assert_eq!(
+            message.to_mt_message_with(strict).unwrap(),
+            message.to_mt_message()
+        );
+        message.fields.sequence[0].field_12.type_code = "94".to_string();
+        let fin = message.to_mt_message_with(strict).unwrap();
+        assert!(fin.contains("\n:12:094\n"), "{fin}");
+        assert!(message.to_mt_message().contains("\n:12:94\n"));
+
+        // An over-length fixed-length value is an error, or written unchanged when lenient
+        message.fields.sequence[0].field_12.type_code = "9400".to_string();
+        let err = message.to_mt_message_with(strict).unwrap_err();
+        assert!(err.to_string().contains("Field 12 value '9400'"), "{err}");
+        assert!(
+            message
+                .to_mt_message_with(SerializeOptions::default())
+                .unwrap()
+                .contains("\n:12:9400\n")
+        );
+    }
+
+    #[test]
+    fn test_to_mt_message_with_field72_line_limit() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n:72:/ACC/INFO\n-}";
+        let strict = SerializeOptions {
+            validate_before_serialize: true,
+        };
+        let mut message = SwiftParser::parse::<MT103>(raw).unwrap();
+        assert!(message.to_mt_message_with(strict).is_ok());
+
+        // Four code words that each wrap onto a `//` line need 8 lines
+        let narrative = format!("/ACC/{}", "RELEASE UPON RECEIPT OF COVER ".repeat(2));
+        message.fields.field_72.as_mut().unwrap().information = vec![narrative; 4];
+        let err = message.to_mt_message_with(strict).unwrap_err();
+        assert!(err.to_string().contains("maximum is 6"), "{err}");
+        assert!(
+            message
+                .to_mt_message_with(SerializeOptions::default())
+                .is_ok()
+        );
+
+        // Without validation the field is cut off at 6 lines rather than written invalid
+        let fin = message.to_mt_message();
+        let field_72 = &fin[fin.find(":72:").unwrap()..fin.find("\n-}").unwrap()];
+        assert_eq!(field_72.lines().count(), 6);
+        assert!(SwiftParser::parse::<MT103>(&fin).is_ok());
+    }
+
+    #[test]
+    fn test_compute_and_verify_local_checksum() {
+        assert_eq!(super::crc64_xz(b"123456789"), 0x995D_C9BB_DF19_39FA);
+
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}{5:{CHK:BCC9171E3074}}";
+        let mut message = SwiftParser::parse::<MT103>(raw).unwrap();
+        assert_eq!(message.compute_local_checksum(), "BCC9171E3074");
+        assert!(message.verify_local_checksum());
+
+        message.fields.field_20.reference = "TXN123457".to_string();
+        assert!(!message.verify_local_checksum());
+
+        message.trailer = None;
+        assert!(!message.verify_local_checksum());
+    }
+
+    #[test]
+    fn test_to_fin_canonical_orders_fields() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:13C:/SNDTIME/1249+0200\n:23B:CRED\n:23E:SDVA\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n:72:/ACC/INFO\n-}";
+        let message = SwiftParser::parse::<MT103>(raw).unwrap();
+
+        let canonical = message.to_fin_canonical().unwrap();
+        let tags: Vec<&str> = canonical
+            .lines()
+            .filter_map(|line| line.strip_prefix(':')?.split(':').next())
+            .collect();
+        assert_eq!(
+            tags,
+            vec!["20", "13C", "23B", "23E", "32A", "50K", "59", "71A", "72"]
+        );
+        assert_eq!(
+            SwiftParser::parse::<MT103>(&canonical).unwrap().fields,
+            message.fields
+        );
+    }
+
+    #[test]
+    fn test_to_fin_canonical_keeps_sequence_occurrences() {
+        use crate::messages::mt101::MT101;
+
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I101BANKDEFFAXXXN}{4:\n:20:REQ001\n:28D:1/1\n:30:240315\n:21:TXN1\n:32B:EUR100,00\n:59:/DE89370400440532013000\nJANE ROE\n:71A:SHA\n:21:TXN2\n:23E:CHQB\n:32B:EUR200,00\n:59:/DE89370400440532013000\nJOHN ROE\n:71A:OUR\n-}";
+        let message = SwiftParser::parse::<MT101>(raw).unwrap();
+
+        let canonical = message.to_fin_canonical().unwrap();
+        let tags: Vec<&str> = canonical
+            .lines()
+            .filter_map(|line| line.strip_prefix(':')?.split(':').next())
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                "20", "28D", "30", "21", "32B", "59", "71A", "21", "23E", "32B", "59", "71A"
+            ]
+        );
+        assert_eq!(
+            SwiftParser::parse::<MT101>(&canonical).unwrap().fields,
+            message.fields
+        );
+    }
+
+    #[test]
+    fn test_write_fin_matches_to_mt_message() {
+        let raw = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{3:{108:MUR123}}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n123 MAIN ST\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}{5:{CHK:123456789ABC}}";
+        let message = SwiftParser::parse::<MT103>(raw).unwrap();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        message.write_fin(&mut buffer).unwrap();
+
+        let expected = "{1:F01BANKDEFFAXXX0123456789}\n{2:I103BANKDEFFAXXXU3003}\n{3:{108:MUR123}}\n{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n123 MAIN ST\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}\n{5:{CHK:123456789ABC}}\n";
+        assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+        assert_eq!(message.to_mt_message(), expected);
+    }
+
+    #[test]
+    fn test_rje_round_trip() {
+        let first = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN1\n:23B:CRED\n:32A:240315USD1000,00\n:50K:JOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let second = first.replace(":20:TXN1", ":20:TXN2");
+        let messages = vec![
+            SwiftParser::parse::<MT103>(first).unwrap(),
+            SwiftParser::parse::<MT103>(&second).unwrap(),
+        ];
+
+        let mut buffer: Vec<u8> = Vec::new();
+        write_rje(&messages, &mut buffer).unwrap();
+        let rje = String::from_utf8(buffer).unwrap();
+        assert_eq!(rje.lines().filter(|line| *line == "$").count(), 1);
+
+        let parsed = SwiftParser::new().parse_rje(&rje).unwrap();
+        assert_eq!(parsed.len(), 2);
+        for (parsed, original) in parsed.iter().zip(&messages) {
+            assert_eq!(parsed.as_mt103().unwrap().fields, original.fields);
+        }
+
+        let crlf = format!("$\r\n{}\r\n$\r\n$\r\n", rje.replace('\n', "\r\n"));
+        assert_eq!(SwiftParser::new().parse_rje(&crlf).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_to_json_tagged_nests_option_fields() {
+        let raw_50k = "{1:F01BANKDEFFAXXX0123456789}{2:I103BANKDEFFAXXXU3003}{4:\n:20:TXN123456\n:23B:CRED\n:32A:240315USD1000,00\n:50K:/12345678\nJOHN DOE\n:59:DE89370400440532013000\nBENEFICIARY NAME\n:71A:SHA\n-}";
+        let raw_50a = raw_50k.replace(":50K:/12345678\nJOHN DOE", ":50A:/12345678\n1/ACME CORP");
+
+        let flat = SwiftParser::parse::<MT103>(raw_50k)
+            .unwrap()
+            .to_json(SerializationMode::Flat)
+            .unwrap();
+        assert!(flat["fields"]["50K"].is_object());
+
+        let tagged_k = SwiftParser::parse::<MT103>(raw_50k)
+            .unwrap()
+            .to_json_tagged()
+            .unwrap();
+        let tagged_a = SwiftParser::parse::<MT103>(&raw_50a)
+            .unwrap()
+            .to_json_tagged()
+            .unwrap();
 
-        swift_message
+        assert_eq!(
+            tagged_k["fields"]["50"],
+            json!({"K": {"account": "12345678", "name_and_address": ["JOHN DOE"]}})
+        );
+        assert_eq!(
+            tagged_a["fields"]["50"],
+            json!({"A": {"party_identifier": "12345678", "name_and_address": ["ACME CORP"]}})
+        );
+        assert!(tagged_k["fields"].get("50K").is_none());
+        assert!(tagged_k["fields"]["59"]["NoOption"].is_object());
+        assert_eq!(tagged_k["fields"]["23B"], flat["fields"]["23B"]);
+        assert_eq!(tagged_k["basic_header"], flat["basic_header"]);
     }
 }
@@ -4,9 +4,14 @@
 //!
 //! - **SwiftField**: Field-level parsing and serialization
 //! - **SwiftMessageBody**: Message-level operations and validation
+//! - **FieldSpec**: Static field metadata for message introspection, grouped into sequences
+//! - **FieldView**: Borrowed, allocation-free access to the fields of a parsed message
+//! - **CurrencyInfo**: Settlement and multi-currency detection for payment messages
+//! - **CommonFields**: Reference and settlement fields that confirmations are matched on
 
 use crate::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 
 /// Trait for SWIFT field types
@@ -34,10 +39,460 @@ pub trait SwiftField: Serialize + for<'de> Deserialize<'de> + Clone + std::fmt::
     /// Convert to SWIFT format (includes `:TAG:` prefix)
     fn to_swift_string(&self) -> String;
 
+    /// Dates carried by the field (value, entry, statement dates); empty by default
+    fn dates(&self) -> Vec<chrono::NaiveDate> {
+        Vec::new()
+    }
+
+    /// Fail when `to_swift_string` cannot write the field unchanged (e.g. it would be cut
+    /// off at its line limit); fields that always serialize faithfully return `Ok`
+    fn check_serialization(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Field with no content, used when it is missing under `MissingMandatoryPolicy::Warn`
+    ///
+    /// Fields that cannot be empty (dates, amounts, codes) return `None` and keep failing.
+    fn empty() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
     /// Get variant tag (e.g., "A", "K") for enum fields, None for simple fields
     fn get_variant_tag(&self) -> Option<&'static str> {
         None
     }
+
+    /// Original text the field was parsed from (without `:TAG:` prefix)
+    ///
+    /// Only available for lossy fields parsed with `ParserConfig::retain_raw_fields`, and
+    /// only while the typed values still match it.
+    fn raw(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Object-safe subset of [`SwiftField`], implemented for every field type
+pub trait AnyField: Debug {
+    /// See [`SwiftField::to_swift_string`]
+    fn swift_string(&self) -> String;
+    /// See [`SwiftField::get_variant_tag`]
+    fn variant_tag(&self) -> Option<&'static str>;
+    /// See [`SwiftField::check_serialization`]
+    fn serialization_check(&self) -> Result<()>;
+    /// The field as `Any`, for downcasting to its concrete type
+    fn as_any(&self) -> &dyn std::any::Any;
+}
+
+impl<T: SwiftField + 'static> AnyField for T {
+    fn swift_string(&self) -> String {
+        self.to_swift_string()
+    }
+
+    fn variant_tag(&self) -> Option<&'static str> {
+        self.get_variant_tag()
+    }
+
+    fn serialization_check(&self) -> Result<()> {
+        self.check_serialization()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Borrowed reference to one field of a parsed message
+#[derive(Debug, Clone, Copy)]
+pub struct FieldView<'a> {
+    /// Tag without option letter for option fields ("50", "57"), full tag otherwise ("32A")
+    pub tag: &'static str,
+    field: &'a dyn AnyField,
+}
+
+impl<'a> FieldView<'a> {
+    /// Wrap a reference to a typed field
+    pub fn new<T: SwiftField + 'static>(tag: &'static str, field: &'a T) -> Self {
+        Self { tag, field }
+    }
+
+    /// Option letter of the field, if any
+    pub fn variant(&self) -> Option<&'static str> {
+        self.field.variant_tag()
+    }
+
+    /// Whether `tag` names this field, either by its bare tag ("50") or with the option ("50K")
+    pub fn matches(&self, tag: &str) -> bool {
+        tag.strip_prefix(self.tag)
+            .is_some_and(|rest| rest.is_empty() || Some(rest) == self.variant())
+    }
+
+    /// The typed field, if it is a `T`
+    pub fn downcast<T: 'static>(&self) -> Option<&'a T> {
+        self.field.as_any().downcast_ref::<T>()
+    }
+
+    /// Serialize the field (includes `:TAG:` prefix)
+    pub fn to_swift_string(&self) -> String {
+        self.field.swift_string()
+    }
+
+    /// See [`SwiftField::check_serialization`]
+    pub fn check_serialization(&self) -> Result<()> {
+        self.field.serialization_check()
+    }
+}
+
+/// Receives the fields of a message in order, see [`SwiftMessageBody::visit_fields`]
+///
+/// Once the callback returns `true` the visit is over and further fields are skipped.
+pub struct FieldVisitor<'a, 'v> {
+    visit: &'v mut dyn FnMut(FieldView<'a>) -> bool,
+    done: bool,
+}
+
+impl<'a, 'v> FieldVisitor<'a, 'v> {
+    /// Visit fields with `visit`, which returns `true` to stop
+    pub fn new(visit: &'v mut dyn FnMut(FieldView<'a>) -> bool) -> Self {
+        Self { visit, done: false }
+    }
+
+    /// Visit a mandatory field
+    pub fn field<T: SwiftField + 'static>(&mut self, tag: &'static str, field: &'a T) -> &mut Self {
+        if !self.done {
+            self.done = (self.visit)(FieldView::new(tag, field));
+        }
+        self
+    }
+
+    /// Visit an optional field, if present
+    pub fn optional<T: SwiftField + 'static>(
+        &mut self,
+        tag: &'static str,
+        field: &'a Option<T>,
+    ) -> &mut Self {
+        if let Some(field) = field {
+            self.field(tag, field);
+        }
+        self
+    }
+
+    /// Visit each occurrence of a repetitive field
+    pub fn repeated<T: SwiftField + 'static>(
+        &mut self,
+        tag: &'static str,
+        fields: &'a [T],
+    ) -> &mut Self {
+        for field in fields {
+            self.field(tag, field);
+        }
+        self
+    }
+
+    /// Visit each occurrence of an optional repetitive field
+    pub fn optional_repeated<T: SwiftField + 'static>(
+        &mut self,
+        tag: &'static str,
+        fields: &'a Option<Vec<T>>,
+    ) -> &mut Self {
+        if let Some(fields) = fields {
+            self.repeated(tag, fields);
+        }
+        self
+    }
+}
+
+/// Static metadata describing one field of a message type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldSpec {
+    /// Field tag, with `a` for option-letter fields (e.g., "20", "50a")
+    pub tag: String,
+    /// Whether the field may be omitted
+    pub optional: bool,
+    /// Whether the field may occur more than once
+    pub repetitive: bool,
+    /// Maximum number of occurrences of a repetitive field, if the standard limits it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_occurrences: Option<usize>,
+    /// SWIFT format specification (e.g., "16x", "6!n3!a15d")
+    pub format: &'static str,
+    /// Field name as given in the SWIFT standard
+    pub description: &'static str,
+    /// Sequence the field belongs to ("A", "B"), for message types with sequences
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<&'static str>,
+    /// Whether the field's sequence may occur more than once (e.g. MT101 sequence B)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub repeating_sequence: bool,
+}
+
+impl FieldSpec {
+    /// Create a mandatory, non-repetitive field spec
+    pub fn new(tag: &str, format: &'static str, description: &'static str) -> Self {
+        Self {
+            tag: tag.to_string(),
+            optional: false,
+            repetitive: false,
+            max_occurrences: None,
+            format,
+            description,
+            sequence: None,
+            repeating_sequence: false,
+        }
+    }
+
+    /// Whether a field tag with option letter (e.g. "50K") belongs to this spec
+    pub fn matches(&self, tag: &str) -> bool {
+        match self.tag.strip_suffix('a') {
+            Some(base) => tag.starts_with(base) && tag.len() <= base.len() + 1,
+            None => tag == self.tag,
+        }
+    }
+
+    /// Mark the field as optional
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Mark the field as repetitive
+    pub fn repetitive(mut self) -> Self {
+        self.repetitive = true;
+        self
+    }
+
+    /// Mark the field as repetitive with at most `max` occurrences
+    pub fn max(mut self, max: usize) -> Self {
+        self.repetitive = true;
+        self.max_occurrences = Some(max);
+        self
+    }
+
+    /// Place the field in a sequence that occurs at most once
+    pub fn sequence(mut self, sequence: &'static str) -> Self {
+        self.sequence = Some(sequence);
+        self
+    }
+
+    /// Place the field in a sequence that may occur more than once
+    ///
+    /// `optional` and `repetitive` then apply to each occurrence of the sequence.
+    pub fn repeating_sequence(mut self, sequence: &'static str) -> Self {
+        self.sequence = Some(sequence);
+        self.repeating_sequence = true;
+        self
+    }
+
+    /// Bring a serialized value (without `:TAG:`) to the fixed-length components of `format`
+    ///
+    /// Only formats made entirely of fixed-length components (`3!n`, `6!n4!n1!x4!n`,
+    /// `3!n6!n[4!n6!n]`) and multi-line formats (`6*35x`) are checked; other values are
+    /// returned as is. A short value of a single numeric component is zero-padded (`3!n`:
+    /// "94" -> "094"). Returns `None` for values that cannot be brought to the format: too
+    /// long, too short to pad without guessing component boundaries, with characters
+    /// outside a component's class, or with more or longer lines than allowed.
+    pub fn pad_value<'v>(&self, value: &'v str) -> Option<std::borrow::Cow<'v, str>> {
+        if let Some((max_lines, width)) = line_format(self.format) {
+            let fits = value.lines().count() <= max_lines
+                && value.lines().all(|line| line.chars().count() <= width);
+            return fits.then_some(std::borrow::Cow::Borrowed(value));
+        }
+
+        let Some((required, optional)) = fixed_components(self.format) else {
+            return Some(std::borrow::Cow::Borrowed(value));
+        };
+
+        let fits = |components: &[FixedComponent]| {
+            let mut rest = value;
+            for &(width, class) in components {
+                if rest.len() < width || !rest.is_char_boundary(width) {
+                    return false;
+                }
+                let (component, tail) = rest.split_at(width);
+                if !component.chars().all(|c| in_class(c, class)) {
+                    return false;
+                }
+                rest = tail;
+            }
+            rest.is_empty()
+        };
+        let all: Vec<FixedComponent> = required.iter().chain(&optional).copied().collect();
+        if fits(&required) || (!optional.is_empty() && fits(&all)) {
+            return Some(std::borrow::Cow::Borrowed(value));
+        }
+
+        match (required.as_slice(), optional.is_empty()) {
+            ([(width, 'n')], true)
+                if !value.is_empty()
+                    && value.len() < *width
+                    && value.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                Some(std::borrow::Cow::Owned(format!("{value:0>width$}")))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Fixed-length format component: width and character class, e.g. `(4, 'n')` for `4!n`
+type FixedComponent = (usize, char);
+
+/// Split a format into its required and optional (`[...]`) fixed-length components
+///
+/// Returns `None` unless every component has a fixed length (`4!n`) and optional
+/// components only follow the required ones.
+fn fixed_components(format: &str) -> Option<(Vec<FixedComponent>, Vec<FixedComponent>)> {
+    let mut required = Vec::new();
+    let mut optional = Vec::new();
+    let mut in_brackets = false;
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '[' if !in_brackets => in_brackets = true,
+            ']' if in_brackets => in_brackets = false,
+            '0'..='9' => {
+                let mut width = c.to_digit(10)? as usize;
+                while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                    width = width * 10 + digit as usize;
+                    chars.next();
+                }
+                if chars.next()? != '!' {
+                    return None;
+                }
+                let class = chars.next().filter(|c| "nacxd".contains(*c))?;
+                if in_brackets {
+                    optional.push((width, class));
+                } else if optional.is_empty() {
+                    required.push((width, class));
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+    (!in_brackets && !required.is_empty()).then_some((required, optional))
+}
+
+/// Line count and width of a multi-line format such as `6*35x`
+fn line_format(format: &str) -> Option<(usize, usize)> {
+    let (lines, width) = format.split_once('*')?;
+    let width = width.strip_suffix(['x', 'z'])?;
+    Some((lines.parse().ok()?, width.parse().ok()?))
+}
+
+/// Whether `c` belongs to a SWIFT character class (`n`, `a`, `c`, `x` or `d`)
+fn in_class(c: char, class: char) -> bool {
+    match class {
+        'n' => c.is_ascii_digit(),
+        'a' => c.is_ascii_uppercase(),
+        'c' => c.is_ascii_uppercase() || c.is_ascii_digit(),
+        'd' => c.is_ascii_digit() || c == ',',
+        _ => c.is_ascii_alphanumeric() || "/-?:().,'+ ".contains(c),
+    }
+}
+
+/// One occurrence of a sequence within a message body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceOccurrence {
+    /// Indices into the spec list of the specs making up the sequence
+    pub specs: std::ops::Range<usize>,
+    /// `(field index, spec index)` for each field of the occurrence, in input order
+    ///
+    /// The spec index is `None` for tags the sequence does not define.
+    pub fields: Vec<(usize, Option<usize>)>,
+}
+
+/// Spec index ranges of the sequences, from runs of consecutive specs with the same `sequence`
+fn sequence_ranges(specs: &[FieldSpec]) -> Vec<std::ops::Range<usize>> {
+    let mut ranges: Vec<std::ops::Range<usize>> = Vec::new();
+    for (index, spec) in specs.iter().enumerate() {
+        match ranges.last_mut() {
+            Some(range) if specs[range.start].sequence == spec.sequence => range.end = index + 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+    ranges
+}
+
+/// Split fields (tags with option letter, in message order) into sequence occurrences
+///
+/// Consecutive specs with the same `sequence` form one sequence. Each field is assigned
+/// to the first spec that follows, in the current sequence, the last assigned one. A
+/// repeating sequence starts again at any of its fields up to and including its first
+/// mandatory field, and a later sequence starts at the first field only it defines.
+/// Fields that fit nowhere stay with the current occurrence: as an extra occurrence of a
+/// matching spec (reported by the T11 check) or, for unknown tags, without a spec.
+pub fn sequence_occurrences(specs: &[FieldSpec], tags: &[&str]) -> Vec<SequenceOccurrence> {
+    let ranges = sequence_ranges(specs);
+    let Some(first) = ranges.first().cloned() else {
+        return Vec::new();
+    };
+
+    let matching = |range: std::ops::Range<usize>, tag: &str| {
+        range.into_iter().find(|&index| specs[index].matches(tag))
+    };
+
+    let mut occurrences = vec![SequenceOccurrence {
+        specs: first,
+        fields: Vec::new(),
+    }];
+    let mut current = 0;
+    let mut last: Option<usize> = None;
+
+    for (field_index, tag) in tags.iter().enumerate() {
+        let range = ranges[current].clone();
+        let next_in_order = match last {
+            Some(last) if specs[last].repetitive && specs[last].matches(tag) => Some(last),
+            Some(last) => matching(last + 1..range.end, tag),
+            None => matching(range.clone(), tag),
+        };
+
+        let (spec, new_range) = if let Some(spec) = next_in_order {
+            (Some(spec), None)
+        } else if let Some(spec) = specs[range.start]
+            .repeating_sequence
+            .then(|| {
+                let lead_end = (range.start..range.end)
+                    .find(|&index| !specs[index].optional)
+                    .map_or(range.end, |index| index + 1);
+                matching(range.start..lead_end, tag)
+            })
+            .flatten()
+        {
+            (Some(spec), Some(current))
+        } else if let Some((later, spec)) = ranges
+            .iter()
+            .enumerate()
+            .skip(current + 1)
+            .find_map(|(index, later)| matching(later.clone(), tag).map(|spec| (index, spec)))
+        {
+            (Some(spec), Some(later))
+        } else {
+            (matching(range, tag), None)
+        };
+
+        if let Some(new_range) = new_range {
+            current = new_range;
+            occurrences.push(SequenceOccurrence {
+                specs: ranges[current].clone(),
+                fields: Vec::new(),
+            });
+        }
+        if spec.is_some() && (new_range.is_some() || spec > last) {
+            last = spec;
+        }
+        occurrences
+            .last_mut()
+            .expect("at least one occurrence")
+            .fields
+            .push((field_index, spec));
+    }
+
+    occurrences
 }
 
 /// Trait for SWIFT message types (MT103, MT202, etc.)
@@ -55,9 +510,179 @@ pub trait SwiftMessageBody: Debug + Clone + Send + Sync + Serialize + std::any::
         panic!("parse_from_block4 not implemented for message type")
     }
 
+    /// Parse message from `(tag, value, position)` triples, e.g. from `extract_all_fields`
+    ///
+    /// Fields are ordered by position (ties keep their list order), so the appearance order
+    /// across tags drives sequence and statement-line assignment exactly as in block 4.
+    /// Tags carry their option letter ("50K") and values omit the `:TAG:` prefix.
+    fn from_ordered_fields(mut fields: Vec<(String, String, usize)>) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        fields.sort_by_key(|(_, _, position)| *position);
+        let mut block4 = String::new();
+        for (tag, value, _) in &fields {
+            block4.push_str(&format!(":{}:{}\n", tag, value));
+        }
+        block4.push('-');
+        Self::parse_from_block4(&block4)
+    }
+
     /// Convert to SWIFT MT format (Block 4 content, no wrapper braces)
     fn to_mt_string(&self) -> String;
 
+    /// Get fields in message order with their full tag (e.g., "50K", "57A") and SWIFT value
+    ///
+    /// Built from `to_mt_string()`, so duplicates and variant letters are preserved exactly as serialized.
+    fn to_fields_with_variants(&self) -> Vec<(String, String)> {
+        crate::parser::extract_all_fields(&self.to_mt_string())
+    }
+
+    /// Get static metadata for each field of this message type, in message order
+    ///
+    /// Message types without introspection support return an empty list.
+    fn field_specs() -> Vec<FieldSpec>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// Pass each populated field to `visitor`, in message order
+    ///
+    /// Repetitive fields and repeated sequences yield one view per occurrence. Message types
+    /// without borrow support visit nothing, so `field_views` is empty and `field` is `None`.
+    fn visit_fields<'a>(&'a self, _visitor: &mut FieldVisitor<'a, '_>) {}
+
+    /// Borrowed references to the populated fields, in message order
+    fn field_views(&self) -> Vec<FieldView<'_>> {
+        let mut views = Vec::new();
+        self.visit_fields(&mut FieldVisitor::new(&mut |view| {
+            views.push(view);
+            false
+        }));
+        views
+    }
+
+    /// First populated field named `tag` ("20", "50" or "50K"), without cloning it
+    ///
+    /// The visit stops at the first match, so no list of views is built.
+    fn field(&self, tag: &str) -> Option<FieldView<'_>> {
+        let mut found = None;
+        self.visit_fields(&mut FieldVisitor::new(&mut |view| {
+            found = view.matches(tag).then_some(view);
+            found.is_some()
+        }));
+        found
+    }
+
+    /// List mandatory field tags (from `field_specs()`) that are absent or empty
+    ///
+    /// Option-letter tags such as "50a" are satisfied by any variant (50A, 50F, 50K).
+    /// Mandatory fields of a sequence are checked in each of its occurrences, so a tag
+    /// is listed once per occurrence that lacks it.
+    fn missing_required_fields(&self) -> Vec<String>
+    where
+        Self: Sized,
+    {
+        let specs = Self::field_specs();
+        let populated = self.to_fields_with_variants();
+        let tags: Vec<&str> = populated.iter().map(|(tag, _)| tag.as_str()).collect();
+        let occurrences = sequence_occurrences(&specs, &tags);
+
+        let mut missing = Vec::new();
+        for range in sequence_ranges(&specs) {
+            let mut in_sequence: Vec<&[(usize, Option<usize>)]> = occurrences
+                .iter()
+                .filter(|occurrence| occurrence.specs == range)
+                .map(|occurrence| occurrence.fields.as_slice())
+                .collect();
+            // A sequence that never occurs lacks all of its mandatory fields
+            if in_sequence.is_empty() {
+                in_sequence.push(&[]);
+            }
+
+            for fields in in_sequence {
+                missing.extend(
+                    range
+                        .clone()
+                        .filter(|&index| !specs[index].optional)
+                        .filter(|&index| {
+                            !fields.iter().any(|&(field, spec)| {
+                                spec == Some(index) && !populated[field].1.trim().is_empty()
+                            })
+                        })
+                        .map(|index| specs[index].tag.clone()),
+                );
+            }
+        }
+        missing
+    }
+
+    /// Flag fields occurring more often than `FieldSpec::max_occurrences` (T11)
+    ///
+    /// Non-repetitive fields allow a single occurrence per message, or per sequence
+    /// occurrence for fields in a repeating sequence. Only message types with
+    /// `field_specs()` are checked.
+    fn validate_occurrence_limits(&self) -> Vec<crate::errors::SwiftValidationError>
+    where
+        Self: Sized,
+    {
+        let specs = Self::field_specs();
+        let fields = self.to_fields_with_variants();
+        let tags: Vec<&str> = fields.iter().map(|(tag, _)| tag.as_str()).collect();
+
+        let mut errors: Vec<crate::errors::SwiftValidationError> = Vec::new();
+        for occurrence in sequence_occurrences(&specs, &tags) {
+            for index in occurrence.specs.clone() {
+                let spec = &specs[index];
+                let max = match (spec.repetitive, spec.max_occurrences) {
+                    (false, _) => 1,
+                    (true, Some(max)) => max,
+                    (true, None) => continue,
+                };
+                let count = occurrence
+                    .fields
+                    .iter()
+                    .filter(|(_, spec)| *spec == Some(index))
+                    .count();
+                if count > max {
+                    errors.push(crate::errors::SwiftValidationError::format_error(
+                        crate::swift_error_codes::t_series::T11,
+                        &spec.tag,
+                        &count.to_string(),
+                        &format!("at most {max} occurrence(s)"),
+                        &format!(
+                            "Field {} occurs {} times, maximum is {}",
+                            spec.tag, count, max
+                        ),
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Run the complete rule set for this message type and collect every violation
+    ///
+    /// Format (T) rules are checked by re-parsing the serialized block 4, which catches
+    /// messages built in code or from JSON; C/D/E rules come from `validate_network_rules`.
+    fn validate_all(&self) -> crate::ValidationReport
+    where
+        Self: Sized,
+    {
+        let mut report = crate::ValidationReport::new(Self::message_type());
+
+        if let Err(error) = Self::parse_from_block4(&self.to_mt_string()) {
+            report.push_parse_error(error);
+        }
+
+        report.errors.extend(self.validate_occurrence_limits());
+
+        report.errors.extend(self.validate_network_rules(false));
+        report
+    }
+
     /// Validate SWIFT network rules (C/D/E series) for this message
     fn validate_network_rules(
         &self,
@@ -66,3 +691,31 @@ pub trait SwiftMessageBody: Debug + Clone + Send + Sync + Serialize + std::any::
         Vec::new()
     }
 }
+
+/// Trait for message types carrying currency amounts (payments, notifications, statements of charges)
+///
+/// Enables routing by settlement currency and detection of cross-currency messages.
+pub trait CurrencyInfo {
+    /// Primary settlement currency (from field 32A/32B, or the first transaction for batches)
+    fn currency(&self) -> Option<&str>;
+
+    /// Every currency used across the amount fields (32a, 33B, 71F, 71G)
+    fn currencies(&self) -> BTreeSet<String>;
+
+    /// Check if more than one currency is used in the message
+    fn is_multi_currency(&self) -> bool {
+        self.currencies().len() > 1
+    }
+}
+
+/// Trait for transfers a confirmation (MT900/MT910) can refer to
+///
+/// Exposes the fields a confirmation is reconciled against, so linkage checks work for
+/// customer (MT103) and FI (MT202, MT205) transfers alike.
+pub trait CommonFields {
+    /// Sender's reference (field 20)
+    fn reference(&self) -> &str;
+
+    /// Value date, currency and settled amount (field 32A)
+    fn value_date_amount(&self) -> &crate::fields::Field32A;
+}
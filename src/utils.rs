@@ -19,3 +19,20 @@ pub fn is_numbered_field(field_name: &str) -> bool {
 pub fn map_variant_to_numbered(_variant: &str) -> Option<String> {
     None
 }
+
+/// Generate a UETR (block 3 tag 121): a lowercase UUID version 4 as required by SWIFT gpi
+pub fn generate_uetr() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40; // version 4
+    bytes[8] = (bytes[8] & 0x3f) | 0x80; // RFC 4122 variant
+
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
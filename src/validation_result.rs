@@ -1,6 +1,8 @@
 //! Validation result types for field and message validation
 
 use crate::ValidationError;
+use crate::errors::{ParseError, SwiftValidationError};
+use crate::swift_error_codes::t_series;
 use serde::{Deserialize, Serialize};
 
 /// Validation result for field and message validation
@@ -36,3 +38,84 @@ impl ValidationResult {
         }
     }
 }
+
+/// Full network validation report for a message, carrying official SWIFT error codes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Message type the rules were run for (e.g., "103")
+    pub message_type: String,
+    /// All rule violations, format (T) errors first, then C/D/E network rules
+    pub errors: Vec<SwiftValidationError>,
+}
+
+impl ValidationReport {
+    /// Create an empty report for a message type (e.g., "103")
+    pub fn new(message_type: &str) -> Self {
+        Self {
+            message_type: message_type.to_string(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// True when no rule was violated
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Error codes of all violations, in report order
+    pub fn codes(&self) -> Vec<&str> {
+        self.errors.iter().map(|e| e.code()).collect()
+    }
+
+    /// Check whether a specific error code was reported
+    pub fn has_code(&self, code: &str) -> bool {
+        self.errors.iter().any(|e| e.code() == code)
+    }
+
+    /// Violations whose code belongs to a series ('T', 'C', 'D', 'E' or 'G')
+    pub fn errors_in_series(&self, series: char) -> Vec<&SwiftValidationError> {
+        self.errors
+            .iter()
+            .filter(|e| e.code().starts_with(series))
+            .collect()
+    }
+
+    /// Record a parse error from the format pass as T-series violations
+    pub(crate) fn push_parse_error(&mut self, error: ParseError) {
+        match error {
+            ParseError::SwiftValidation(e) => self.errors.push(*e),
+            ParseError::MultipleErrors(errors) => {
+                for error in errors {
+                    self.push_parse_error(error);
+                }
+            }
+            ParseError::InvalidFieldFormat(e) => {
+                self.errors.push(SwiftValidationError::format_error(
+                    e.code.as_deref().unwrap_or(t_series::T12),
+                    &e.field_tag,
+                    &e.value,
+                    &e.format_spec,
+                    &e.inner_error,
+                ));
+            }
+            ParseError::MissingRequiredField { field_tag, .. } => {
+                self.errors.push(SwiftValidationError::format_error(
+                    t_series::T14,
+                    &field_tag,
+                    "",
+                    "",
+                    &format!("Mandatory field {} is missing", field_tag),
+                ));
+            }
+            other => {
+                self.errors.push(SwiftValidationError::format_error(
+                    t_series::T01,
+                    "",
+                    "",
+                    "",
+                    &other.to_string(),
+                ));
+            }
+        }
+    }
+}
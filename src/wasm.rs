@@ -0,0 +1,96 @@
+//! # WebAssembly Bindings
+//!
+//! `wasm-bindgen` entry points for parsing and validating MT messages in the browser.
+//! Build with `--no-default-features --features wasm` to leave out the dataflow plugin
+//! and sample generation, which pull in dependencies that do not target `wasm32`.
+
+use crate::SwiftParser;
+use crate::validation_result::ValidationReport;
+use wasm_bindgen::prelude::*;
+
+/// Parse a raw MT message and return it as JSON
+#[wasm_bindgen]
+pub fn parse_to_json(raw: &str) -> Result<String, JsValue> {
+    parse_message_json(raw).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Parse and validate a raw MT message, returning a JSON `ValidationReport`
+///
+/// Parse failures are reported as T-series errors rather than thrown.
+#[wasm_bindgen]
+pub fn validate(raw: &str) -> String {
+    let report = validation_report(raw);
+    serde_json::to_string(&report).unwrap_or_else(|e| {
+        serde_json::json!({
+            "message_type": "",
+            "errors": [],
+            "serialization_error": e.to_string(),
+        })
+        .to_string()
+    })
+}
+
+fn parse_message_json(raw: &str) -> Result<String, String> {
+    let parsed = SwiftParser::parse_auto(raw).map_err(|e| e.to_string())?;
+    serde_json::to_string(&parsed).map_err(|e| e.to_string())
+}
+
+fn validation_report(raw: &str) -> ValidationReport {
+    match SwiftParser::parse_auto(raw) {
+        Ok(parsed) => {
+            let mut report = ValidationReport::new(parsed.message_type());
+            report.errors = parsed.validate_network_rules(false);
+            report
+        }
+        Err(error) => {
+            let message_type = SwiftParser::new()
+                .parse_headers(raw)
+                .map(|headers| headers.application.message_type().to_string())
+                .unwrap_or_default();
+            let mut report = ValidationReport::new(&message_type);
+            report.push_parse_error(error);
+            report
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) const MT103: &str = "{1:F01BANKDEFFAXXX0000000000}{2:I103BANKDEFFAXXXU3003}{4:\r\n:20:REF123\r\n:23B:CRED\r\n:32A:240719USD1234,56\r\n:50K:/12345678\r\nJOHN DOE\r\n:59:/98765432\r\nJANE SMITH\r\n:71A:OUR\r\n-}";
+
+    #[test]
+    fn test_parse_message_json() {
+        let json = parse_message_json(MT103).unwrap();
+        assert!(json.contains(r#""mt_type":"103""#));
+        assert!(parse_message_json("not a message").is_err());
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let report = validation_report(MT103);
+        assert_eq!(report.message_type, "103");
+        assert!(report.is_valid());
+
+        let report = validation_report(&MT103.replace(":71A:OUR", ":71A:XYZ"));
+        assert_eq!(report.message_type, "103");
+        assert!(!report.is_valid());
+        assert!(serde_json::to_string(&report).unwrap().contains("71A"));
+    }
+}
+
+/// Run with `wasm-pack test --node -- --no-default-features --features wasm`
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_bindings() {
+        let json = parse_to_json(tests::MT103).unwrap();
+        assert!(json.contains(r#""mt_type":"103""#));
+        assert!(parse_to_json("not a message").is_err());
+        assert!(validate(tests::MT103).contains(r#""errors":[]"#));
+    }
+}
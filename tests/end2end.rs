@@ -250,16 +250,15 @@ async fn test_swift_mt_workflow_pipeline() {
                                 );
 
                                 // For MT104, show fields
-                                if message_type == "MT104" && key == "sample_json" {
-                                    if let Some(obj) = value.as_object() {
-                                        if let Some(fields) = obj.get("fields") {
-                                            println!(
-                                                "  MT104 fields: {}",
-                                                serde_json::to_string_pretty(fields)
-                                                    .unwrap_or_default()
-                                            );
-                                        }
-                                    }
+                                if message_type == "MT104"
+                                    && key == "sample_json"
+                                    && let Some(obj) = value.as_object()
+                                    && let Some(fields) = obj.get("fields")
+                                {
+                                    println!(
+                                        "  MT104 fields: {}",
+                                        serde_json::to_string_pretty(fields).unwrap_or_default()
+                                    );
                                 }
                             }
                         } else if key == "sample_mt" {
@@ -1072,5 +1071,5 @@ fn status_symbol(success: bool) -> &'static str {
 }
 
 fn percentage(value: usize, total: usize) -> usize {
-    if total == 0 { 0 } else { (value * 100) / total }
+    (value * 100).checked_div(total).unwrap_or(0)
 }